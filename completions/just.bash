@@ -10,7 +10,7 @@ _just() {
     else
         cur="${COMP_WORDS[COMP_CWORD]}"
         prev="${COMP_WORDS[COMP_CWORD-1]}"
-        words=$COMP_WORDS
+        words=("${COMP_WORDS[@]}")
         cword=$COMP_CWORD
     fi
 
@@ -30,11 +30,26 @@ _just() {
 
     case "${cmd}" in
         just)
-            opts="-E -n -g -f -q -u -v -d -c -e -l -s -h -V --agents-only --alias-style --ceiling --check --chooser --clear-shell-args --color --command-color --cygpath --dotenv-filename --dotenv-path --dry-run --dump-format --explain --global-justfile --highlight --justfile --list-heading --list-prefix --list-submodules --no-aliases --no-deps --no-dotenv --no-highlight --one --quiet --allow-missing --set --shell --shell-arg --shell-command --output-format --tempdir --timestamp --timestamp-format --unsorted --unstable --verbose --working-directory --yes --changelog --choose --command --completions --dump --edit --evaluate --fmt --groups --init --list --man --request --show --summary --usage --variables --help --version [ARGUMENTS]..."
+            opts="-E -n -g -f -q -u -v -d -c -e -l -s -h -V --agents-only --alias-style --cache-directory --ceiling --check --chooser --clear-shell-args --color --command-color --cygpath --dotenv-filename --dotenv-path --dry-run --dump-format --explain --global-justfile --highlight --justfile --last --list-heading --list-prefix --list-submodules --no-aliases --no-deps --no-dotenv --no-highlight --one --quiet --rerun-failed --allow-missing --set --shell --shell-arg --shell-command --output-format --tempdir --timestamp --timestamp-format --unsorted --unstable --verbose --watch --working-directory --workspace-filter --workspace-jobs --yes --changelog --choose --command --complete-parameter --completions --dump --edit --evaluate --fmt --groups --init --list --man --request --show --summary --usage --variables --workspace --help --version [ARGUMENTS]..."
                 if [[ ${cur} == -* ]] ; then
                     COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                     return 0
                 else
+                    if [[ ${cword} -gt 1 ]]; then
+                        local parameter=( $(just --complete-parameter "${words[@]:1:cword-1}" 2> /dev/null) )
+
+                        case "${parameter[0]}" in
+                            file)
+                                COMPREPLY=( $(compgen -f -- "${cur}") )
+                                return 0
+                                ;;
+                            bool|enum)
+                                COMPREPLY=( $(compgen -W "${parameter[*]:1}" -- "${cur}") )
+                                return 0
+                                ;;
+                        esac
+                    fi
+
                     local recipes=$(just --summary 2> /dev/null)
 
                     if echo "${cur}" | \grep -qF '/'; then
@@ -56,6 +71,10 @@ _just() {
                     COMPREPLY=($(compgen -W "left right separate" -- "${cur}"))
                     return 0
                     ;;
+                --cache-directory)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --ceiling)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -140,6 +159,14 @@ _just() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --workspace-filter)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --workspace-jobs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --command)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -148,6 +175,10 @@ _just() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --complete-parameter)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --completions)
                     COMPREPLY=($(compgen -W "bash elvish fish nushell powershell zsh" -- "${cur}"))
                     return 0
@@ -5,16 +5,34 @@ function __fish_just_complete_recipes
         printf "%s\n" (string split " " (just --summary))
 end
 
+function __fish_just_complete_parameter
+        set -l tokens (commandline -opc)
+        if test (count $tokens) -lt 2
+          return
+        end
+        set -l parameter (just --complete-parameter $tokens[2..-1] 2> /dev/null)
+        switch "$parameter[1]"
+          case file
+            __fish_complete_path (commandline -ct)
+          case bool enum
+            printf "%s\n" $parameter[2..-1]
+        end
+end
+
 # don't suggest files right off
 complete -c just -n "__fish_is_first_arg" --no-files
 
 # complete recipes
 complete -c just -a '(__fish_just_complete_recipes)'
 
+# complete typed recipe parameters
+complete -c just -n "not __fish_is_first_arg" -a '(__fish_just_complete_parameter)'
+
 # autogenerated completions
 complete -c just -l alias-style -d 'Set list command alias display style' -r -f -a "left\t''
 right\t''
 separate\t''"
+complete -c just -l cache-directory -d 'Store git imports and records of previous runs in <CACHE-DIRECTORY>.' -r -F
 complete -c just -l ceiling -d 'Do not ascend above <CEILING> directory when searching for a justfile.' -r -F
 complete -c just -l chooser -d 'Override binary invoked by `--choose`' -r
 complete -c just -l color -d 'Print colorful output' -r -f -a "always\t''
@@ -29,7 +47,7 @@ red\t''
 yellow\t''"
 complete -c just -l cygpath -d 'Use binary at <CYGPATH> to convert between unix and Windows paths.' -r -F
 complete -c just -l dotenv-filename -d 'Search for environment file named <DOTENV-FILENAME> instead of `.env`' -r
-complete -c just -s E -l dotenv-path -d 'Load <DOTENV-PATH> as environment file instead of searching for one' -r -F
+complete -c just -s E -l dotenv-path -d 'Load <DOTENV-PATH> as environment file instead of searching for one. May be given multiple times, with later files taking precedence.' -r -F
 complete -c just -l dump-format -d 'Dump justfile as <FORMAT>' -r -f -a "json\t''
 just\t''"
 complete -c just -s f -l justfile -d 'Use <JUSTFILE> as justfile' -r -F
@@ -42,7 +60,10 @@ complete -c just -l output-format -d 'Set output format (default, tap, tap+strea
 complete -c just -l tempdir -d 'Save temporary files to <TEMPDIR>.' -r -F
 complete -c just -l timestamp-format -d 'Timestamp format string' -r
 complete -c just -s d -l working-directory -d 'Use <WORKING-DIRECTORY> as working directory. --justfile must also be set' -r -F
+complete -c just -l workspace-filter -d 'Only run `--workspace` recipes in directories whose path relative to the project root matches <GLOB>' -r
+complete -c just -l workspace-jobs -d 'Run `--workspace` recipes in up to <WORKSPACE-JOBS> directories in parallel' -r
 complete -c just -s c -l command -d 'Run an arbitrary command with the working directory, `.env`, overrides, and exports set' -r
+complete -c just -l complete-parameter -d 'Print the type and completions of the recipe parameter following <ARGUMENTS>. Used by shell completion scripts. May be changed or removed at any time.' -r
 complete -c just -l completions -d 'Print shell completion script for <SHELL>' -r -f -a "bash\t''
 elvish\t''
 fish\t''
@@ -60,6 +81,7 @@ complete -c just -s n -l dry-run -d 'Print what just would do without doing it'
 complete -c just -l explain -d 'Print recipe doc comment before running it'
 complete -c just -s g -l global-justfile -d 'Use global justfile'
 complete -c just -l highlight -d 'Highlight echoed recipe lines in bold'
+complete -c just -l last -d 'Repeat the recipes run by the last invocation'
 complete -c just -l list-submodules -d 'List recipes in submodules'
 complete -c just -l no-aliases -d 'Don\'t show aliases in list'
 complete -c just -l no-deps -d 'Don\'t run recipe dependencies'
@@ -67,12 +89,14 @@ complete -c just -l no-dotenv -d 'Don\'t load `.env` file'
 complete -c just -l no-highlight -d 'Don\'t highlight echoed recipe lines in bold'
 complete -c just -l one -d 'Forbid multiple recipes from being invoked on the command line'
 complete -c just -s q -l quiet -d 'Suppress all output'
+complete -c just -l rerun-failed -d 'Run the recipes which failed in the last invocation'
 complete -c just -l allow-missing -d 'Ignore missing recipe and module errors'
 complete -c just -l shell-command -d 'Invoke <COMMAND> with the shell used to run recipe lines and backticks'
 complete -c just -l timestamp -d 'Print recipe command timestamps'
 complete -c just -s u -l unsorted -d 'Return list and summary entries in source order'
 complete -c just -l unstable -d 'Enable unstable features'
 complete -c just -s v -l verbose -d 'Use verbose output'
+complete -c just -l watch -d 'Re-run recipes when watched files change'
 complete -c just -l yes -d 'Automatically confirm all recipes.'
 complete -c just -l changelog -d 'Print changelog'
 complete -c just -l choose -d 'Select one or more recipes to run using a binary chooser. If `--chooser` is not passed the chooser defaults to the value of $JUST_CHOOSER, falling back to `fzf`'
@@ -85,5 +109,6 @@ complete -c just -l init -d 'Initialize new justfile in project root'
 complete -c just -l man -d 'Print man page'
 complete -c just -l summary -d 'List names of available recipes'
 complete -c just -l variables -d 'List names of variables'
+complete -c just -l workspace -d 'Run recipe in every justfile beneath the project root that defines it, reporting results as TAP'
 complete -c just -s h -l help -d 'Print help'
 complete -c just -s V -l version -d 'Print version'
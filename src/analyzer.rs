@@ -4,6 +4,7 @@ use {super::*, CompileErrorKind::*};
 pub(crate) struct Analyzer<'run, 'src> {
   aliases: Table<'src, Alias<'src, Namepath<'src>>>,
   assignments: Vec<&'run Binding<'src, Expression<'src>>>,
+  functions: Table<'src, UserFunction<'src>>,
   modules: Table<'src, Justfile<'src>>,
   recipes: Vec<&'run Recipe<'src, UnresolvedDependency<'src>>>,
   sets: Table<'src, Set<'src>>,
@@ -13,34 +14,52 @@ pub(crate) struct Analyzer<'run, 'src> {
 
 impl<'run, 'src> Analyzer<'run, 'src> {
   pub(crate) fn analyze(
+    arguments: &[(Name<'src>, StringLiteral<'src>)],
     asts: &'run HashMap<PathBuf, Ast<'src>>,
     config: &Config,
     doc: Option<String>,
+    exported: bool,
     groups: &[StringLiteral<'src>],
     loaded: &[PathBuf],
+    module_path: &str,
     name: Option<Name<'src>>,
     paths: &HashMap<PathBuf, PathBuf>,
     private: bool,
     root: &Path,
   ) -> RunResult<'src, Justfile<'src>> {
     Self::default().justfile(
-      asts, config, doc, groups, loaded, name, paths, root, private,
+      arguments,
+      asts,
+      config,
+      doc,
+      exported,
+      groups,
+      loaded,
+      module_path,
+      name,
+      paths,
+      root,
+      private,
     )
   }
 
   fn justfile(
     mut self,
+    arguments: &[(Name<'src>, StringLiteral<'src>)],
     asts: &'run HashMap<PathBuf, Ast<'src>>,
     config: &Config,
     doc: Option<String>,
+    exported: bool,
     groups: &[StringLiteral<'src>],
     loaded: &[PathBuf],
+    module_path: &str,
     name: Option<Name<'src>>,
     paths: &HashMap<PathBuf, PathBuf>,
     root: &Path,
     private: bool,
   ) -> RunResult<'src, Justfile<'src>> {
     let mut definitions = HashMap::new();
+    let mut function_definitions = HashMap::new();
     let mut imports = HashSet::new();
     let mut unstable_features = BTreeSet::new();
 
@@ -61,6 +80,11 @@ impl<'run, 'src> Analyzer<'run, 'src> {
             self.assignments.push(assignment);
           }
           Item::Comment(_) => (),
+          Item::Function(function) => {
+            Self::define(&mut function_definitions, function.name, "function", false)?;
+            Self::analyze_function(function)?;
+            self.functions.insert(function.clone());
+          }
           Item::Import { absolute, .. } => {
             if let Some(absolute) = absolute {
               if imports.insert(absolute) {
@@ -70,7 +94,9 @@ impl<'run, 'src> Analyzer<'run, 'src> {
           }
           Item::Module {
             absolute,
+            arguments,
             doc,
+            exported,
             groups,
             name,
             private,
@@ -78,12 +104,23 @@ impl<'run, 'src> Analyzer<'run, 'src> {
           } => {
             if let Some(absolute) = absolute {
               Self::define(&mut definitions, *name, "module", false)?;
+              // Module paths are computed here rather than taken from the
+              // module's AST, since the same file may be loaded as more than
+              // one module
+              let module_path = if module_path.is_empty() {
+                name.lexeme().to_owned()
+              } else {
+                format!("{module_path}::{name}")
+              };
               self.modules.insert(Self::analyze(
+                arguments,
                 asts,
                 config,
                 doc.clone(),
+                *exported,
                 groups.as_slice(),
                 loaded,
+                &module_path,
                 Some(*name),
                 paths,
                 *private,
@@ -146,7 +183,47 @@ impl<'run, 'src> Analyzer<'run, 'src> {
       }
     }
 
-    AssignmentResolver::resolve_assignments(&assignments)?;
+    AssignmentResolver::resolve_assignments(&assignments, &self.modules)?;
+
+    for (variable, _value) in arguments {
+      if !assignments.contains_key(variable.lexeme()) {
+        return Err(
+          variable
+            .error(UnknownModuleArgument {
+              module: name.unwrap().lexeme(),
+              variable: variable.lexeme(),
+            })
+            .into(),
+        );
+      }
+    }
+
+    let arguments = arguments
+      .iter()
+      .map(|(variable, value)| (variable.lexeme().to_owned(), value.cooked.clone()))
+      .collect::<BTreeMap<String, String>>();
+
+    FunctionResolver::resolve_functions(
+      &self.functions,
+      &self.modules,
+      assignments
+        .values()
+        .map(|assignment| &assignment.value)
+        .chain(self.sets.values().flat_map(|set| set.value.expressions()))
+        .chain(self.recipes.iter().flat_map(|recipe| recipe.expressions())),
+    )?;
+
+    for expression in self
+      .functions
+      .values()
+      .map(|function| &function.body)
+      .chain(self.sets.values().flat_map(|set| set.value.expressions()))
+      .chain(self.recipes.iter().flat_map(|recipe| recipe.expressions()))
+    {
+      for path in expression.module_variables() {
+        Self::resolve_module_variable(path, &self.modules)?;
+      }
+    }
 
     for set in self.sets.values() {
       for expression in set.value.expressions() {
@@ -159,11 +236,22 @@ impl<'run, 'src> Analyzer<'run, 'src> {
       }
     }
 
-    let settings =
-      Evaluator::evaluate_settings(&assignments, config, name, self.sets, &Scope::root())?;
+    let settings = Evaluator::evaluate_settings(
+      &arguments,
+      &assignments,
+      config,
+      name,
+      self.sets.clone(),
+      &Scope::root(),
+      Settings::default(),
+    )?;
+
+    let (overrides, recipes) = self.recipes.into_iter().partition::<Vec<
+      &Recipe<'src, UnresolvedDependency<'src>>,
+    >, _>(|recipe| recipe.is_override());
 
     let mut deduplicated_recipes = Table::<'src, UnresolvedRecipe<'src>>::default();
-    for recipe in self.recipes {
+    for recipe in recipes {
       Self::define(
         &mut definitions,
         recipe.name,
@@ -179,10 +267,74 @@ impl<'run, 'src> Analyzer<'run, 'src> {
       }
     }
 
+    let mut overridden_recipes = Table::<'src, UnresolvedRecipe<'src>>::default();
+    let mut deduplicated_overrides = Table::<'src, UnresolvedRecipe<'src>>::default();
+    for recipe in overrides {
+      if deduplicated_overrides.contains_key(recipe.name.lexeme()) {
+        if !settings.allow_duplicate_recipes {
+          let original = deduplicated_overrides[recipe.name.lexeme()].name;
+          return Err(
+            recipe
+              .name
+              .error(Redefinition {
+                first: original.line,
+                first_type: "recipe",
+                name: recipe.name.lexeme(),
+                second_type: "recipe",
+              })
+              .into(),
+          );
+        }
+      } else {
+        // An override replaces recipes with the same name, so it is defined as
+        // though duplicates were allowed
+        Self::define(&mut definitions, recipe.name, "recipe", true)?;
+      }
+
+      if deduplicated_overrides
+        .get(recipe.name.lexeme())
+        .is_none_or(|original| recipe.file_depth <= original.file_depth)
+      {
+        deduplicated_overrides.insert(recipe.clone());
+      }
+    }
+
+    for (_name, recipe) in deduplicated_overrides {
+      match deduplicated_recipes.remove(recipe.name.lexeme()) {
+        Some(overridden) if overridden.file_depth > recipe.file_depth => {
+          overridden_recipes.insert(overridden);
+        }
+        _ => {
+          return Err(
+            recipe
+              .name
+              .error(OverrideWithoutImportedRecipe {
+                recipe: recipe.name.lexeme(),
+              })
+              .into(),
+          );
+        }
+      }
+
+      deduplicated_recipes.insert(recipe);
+    }
+
+    // Recipes and modules which other modules cannot use are also not listed
+    if settings.explicit_exports && !module_path.is_empty() {
+      for recipe in deduplicated_recipes.values_mut() {
+        recipe.private |= !recipe.is_exported();
+      }
+
+      for module in self.modules.values_mut() {
+        module.private |= !module.exported;
+      }
+    }
+
     let recipes = RecipeResolver::resolve_recipes(
       &assignments,
-      &ast.module_path,
+      module_path,
       &self.modules,
+      overridden_recipes,
       &settings,
       deduplicated_recipes,
     )?;
@@ -227,25 +379,59 @@ impl<'run, 'src> Analyzer<'run, 'src> {
         })
     });
 
-    Ok(Justfile {
+    let mut justfile = Justfile {
       aliases,
+      arguments,
       assignments,
       default,
       doc: doc.filter(|doc| !doc.is_empty()),
+      exported,
+      functions: self.functions,
       groups: groups.into(),
       loaded: loaded.into(),
-      module_path: ast.module_path.clone(),
+      module_path: module_path.into(),
       modules: self.modules,
       name,
       private,
       recipes,
+      sets: self.sets,
       settings,
       source,
       unexports: self.unexports,
       unstable_features,
       warnings: self.warnings,
       working_directory: ast.working_directory.clone(),
-    })
+    };
+
+    if name.is_none() {
+      Self::inherit_settings(config, &mut justfile)?;
+    }
+
+    Ok(justfile)
+  }
+
+  /// Re-evaluate the settings of submodules with `set inherit-settings` on top
+  /// of their parent's inheritable settings. Modules are analyzed before their
+  /// parent's settings are known, so this is done once, from the root, after
+  /// analysis.
+  fn inherit_settings(config: &Config, justfile: &mut Justfile<'src>) -> RunResult<'src> {
+    for module in justfile.modules.values_mut() {
+      if module.settings.inherit_settings {
+        module.settings = Evaluator::evaluate_settings(
+          &module.arguments,
+          &module.assignments,
+          config,
+          module.name,
+          module.sets.clone(),
+          &Scope::root(),
+          justfile.settings.inheritable(),
+        )?;
+      }
+
+      Self::inherit_settings(config, module)?;
+    }
+
+    Ok(())
   }
 
   fn define(
@@ -276,6 +462,27 @@ impl<'run, 'src> Analyzer<'run, 'src> {
     Ok(())
   }
 
+  fn analyze_function(function: &UserFunction<'src>) -> CompileResult<'src> {
+    if function::get(function.name.lexeme()).is_some() {
+      return Err(function.name.error(FunctionShadowsBuiltin {
+        function: function.name.lexeme(),
+      }));
+    }
+
+    let mut parameters = BTreeSet::new();
+
+    for parameter in &function.parameters {
+      if !parameters.insert(parameter.lexeme()) {
+        return Err(parameter.error(DuplicateFunctionParameter {
+          function: function.name.lexeme(),
+          parameter: parameter.lexeme(),
+        }));
+      }
+    }
+
+    Ok(())
+  }
+
   fn analyze_recipe(recipe: &UnresolvedRecipe<'src>) -> CompileResult<'src> {
     let mut parameters = BTreeSet::new();
     let mut passed_default = false;
@@ -348,7 +555,7 @@ impl<'run, 'src> Analyzer<'run, 'src> {
     recipes: &'a Table<'src, Arc<Recipe<'src>>>,
     alias: Alias<'src, Namepath<'src>>,
   ) -> CompileResult<'src, Alias<'src>> {
-    match Self::resolve_recipe(&alias.target, modules, recipes) {
+    match Self::resolve_recipe(&alias.target, modules, recipes)? {
       Some(target) => Ok(alias.resolve(target)),
       None => Err(alias.name.error(UnknownAliasTarget {
         alias: alias.name.lexeme(),
@@ -357,20 +564,99 @@ impl<'run, 'src> Analyzer<'run, 'src> {
     }
   }
 
-  pub(crate) fn resolve_recipe<'a>(
-    path: &Namepath<'src>,
+  /// Resolve the submodule at `path`, each module along which must be
+  /// exported if its parent has `set explicit-exports`
+  fn resolve_module<'a>(
+    path: &[Name<'src>],
     mut modules: &'a Table<'src, Justfile<'src>>,
-    mut recipes: &'a Table<'src, Arc<Recipe<'src>>>,
-  ) -> Option<Arc<Recipe<'src>>> {
-    let (name, path) = path.split_last();
+  ) -> CompileResult<'src, Option<&'a Justfile<'src>>> {
+    let mut parent: Option<&Justfile> = None;
 
     for name in path {
-      let module = modules.get(name.lexeme())?;
+      let Some(module) = modules.get(name.lexeme()) else {
+        return Ok(None);
+      };
+
+      if let Some(parent) = parent {
+        if parent.settings.explicit_exports && !module.exported {
+          return Err(name.error(PrivateModuleItem {
+            item_type: "Module",
+            module: parent.module_path.clone(),
+            name: name.lexeme(),
+          }));
+        }
+      }
+
       modules = &module.modules;
-      recipes = &module.recipes;
+      parent = Some(module);
     }
 
-    recipes.get(name.lexeme()).cloned()
+    Ok(parent)
+  }
+
+  /// Resolve variable `path` in a submodule, which must be public if the
+  /// submodule has `set explicit-exports`
+  pub(crate) fn resolve_module_variable<'a>(
+    path: &Namepath<'src>,
+    modules: &'a Table<'src, Justfile<'src>>,
+  ) -> CompileResult<'src, &'a Assignment<'src>> {
+    let (name, components) = path.split_last();
+
+    let undefined = || {
+      name.error(UndefinedModuleVariable {
+        variable: path.clone(),
+      })
+    };
+
+    let module = Self::resolve_module(components, modules)?.ok_or_else(undefined)?;
+
+    let assignment = module
+      .assignments
+      .get(name.lexeme())
+      .ok_or_else(undefined)?;
+
+    if module.settings.explicit_exports && !assignment.public {
+      return Err(name.error(PrivateModuleItem {
+        item_type: "Variable",
+        module: module.module_path.clone(),
+        name: name.lexeme(),
+      }));
+    }
+
+    Ok(assignment)
+  }
+
+  /// Resolve recipe `path`, which must be exported if it is in a submodule
+  /// with `set explicit-exports`
+  pub(crate) fn resolve_recipe<'a>(
+    path: &Namepath<'src>,
+    modules: &'a Table<'src, Justfile<'src>>,
+    recipes: &'a Table<'src, Arc<Recipe<'src>>>,
+  ) -> CompileResult<'src, Option<Arc<Recipe<'src>>>> {
+    let (name, components) = path.split_last();
+
+    let (recipes, explicit_exports) = if components.is_empty() {
+      (recipes, false)
+    } else {
+      let Some(module) = Self::resolve_module(components, modules)? else {
+        return Ok(None);
+      };
+      (&module.recipes, module.settings.explicit_exports)
+    };
+
+    let Some(recipe) = recipes.get(name.lexeme()) else {
+      return Ok(None);
+    };
+
+    if explicit_exports && !recipe.is_exported() {
+      return Err(name.error(PrivateModuleItem {
+        item_type: "Recipe",
+        module: recipe.module_path.clone(),
+        name: name.lexeme(),
+      }));
+    }
+
+    Ok(Some(Arc::clone(recipe)))
   }
 }
 
@@ -496,6 +782,36 @@ mod tests {
     kind:   DuplicateVariable{variable: "a"},
   }
 
+  analysis_error! {
+    name:   duplicate_function,
+    input:  "fn f(a) := a\nfn f(b) := b",
+    offset: 16,
+    line:   1,
+    column: 3,
+    width:  1,
+    kind:   Redefinition { first_type: "function", second_type: "function", name: "f", first: 0 },
+  }
+
+  analysis_error! {
+    name:   duplicate_function_parameter,
+    input:  "fn f(a, a) := a",
+    offset: 8,
+    line:   0,
+    column: 8,
+    width:  1,
+    kind:   DuplicateFunctionParameter { function: "f", parameter: "a" },
+  }
+
+  analysis_error! {
+    name:   function_shadows_builtin,
+    input:  "fn arch() := 'a'",
+    offset: 3,
+    line:   0,
+    column: 3,
+    width:  4,
+    kind:   FunctionShadowsBuiltin { function: "arch" },
+  }
+
   analysis_error! {
     name:   extra_whitespace,
     input:  "a:\n blah\n  blarg",
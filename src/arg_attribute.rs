@@ -5,6 +5,7 @@ pub(crate) struct ArgAttribute<'src> {
   pub(crate) long: Option<String>,
   pub(crate) name: Token<'src>,
   pub(crate) pattern: Option<Pattern<'src>>,
+  pub(crate) secret: bool,
   pub(crate) short: Option<char>,
   pub(crate) value: Option<String>,
 }
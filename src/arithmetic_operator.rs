@@ -0,0 +1,45 @@
+use super::*;
+
+/// An arithmetic operator.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub(crate) enum ArithmeticOperator {
+  /// `-`
+  Difference,
+  /// `*`
+  Product,
+  /// `/`, when either operand is an integer
+  Quotient,
+  /// `%`
+  Remainder,
+  /// `+`, when either operand is an integer
+  Sum,
+}
+
+impl ArithmeticOperator {
+  pub(crate) fn apply(self, lhs: i64, rhs: i64) -> Result<i64, String> {
+    if rhs == 0 && matches!(self, Self::Quotient | Self::Remainder) {
+      return Err("Division by zero".into());
+    }
+
+    match self {
+      Self::Difference => lhs.checked_sub(rhs),
+      Self::Product => lhs.checked_mul(rhs),
+      Self::Quotient => lhs.checked_div(rhs),
+      Self::Remainder => lhs.checked_rem(rhs),
+      Self::Sum => lhs.checked_add(rhs),
+    }
+    .ok_or_else(|| "Integer overflow".into())
+  }
+}
+
+impl Display for ArithmeticOperator {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    match self {
+      Self::Difference => write!(f, "-"),
+      Self::Product => write!(f, "*"),
+      Self::Quotient => write!(f, "/"),
+      Self::Remainder => write!(f, "%"),
+      Self::Sum => write!(f, "+"),
+    }
+  }
+}
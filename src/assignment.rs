@@ -9,6 +9,14 @@ impl Display for Assignment<'_> {
       writeln!(f, "[private]")?;
     }
 
+    if self.secret {
+      writeln!(f, "[secret]")?;
+    }
+
+    if self.public {
+      write!(f, "{} ", Keyword::Pub)?;
+    }
+
     if self.export {
       write!(f, "export ")?;
     }
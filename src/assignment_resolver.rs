@@ -3,17 +3,20 @@ use {super::*, CompileErrorKind::*};
 pub(crate) struct AssignmentResolver<'src: 'run, 'run> {
   assignments: &'run Table<'src, Assignment<'src>>,
   evaluated: BTreeSet<&'src str>,
+  modules: &'run Table<'src, Justfile<'src>>,
   stack: Vec<&'src str>,
 }
 
 impl<'src: 'run, 'run> AssignmentResolver<'src, 'run> {
   pub(crate) fn resolve_assignments(
     assignments: &'run Table<'src, Assignment<'src>>,
+    modules: &'run Table<'src, Justfile<'src>>,
   ) -> CompileResult<'src> {
     let mut resolver = Self {
       stack: Vec::new(),
       evaluated: BTreeSet::new(),
       assignments,
+      modules,
     };
 
     for assignment in assignments.values() {
@@ -55,6 +58,13 @@ impl<'src: 'run, 'run> AssignmentResolver<'src, 'run> {
         return Err(variable.error(UndefinedVariable { variable: name }));
       }
     }
+
+    // Submodules cannot refer to variables in their parents, so variables in
+    // submodules, which have already been resolved, cannot be part of a cycle
+    for path in assignment.value.module_variables() {
+      Analyzer::resolve_module_variable(path, self.modules)?;
+    }
+
     self.evaluated.insert(name);
 
     self.stack.pop();
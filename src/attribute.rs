@@ -18,6 +18,7 @@ pub(crate) enum Attribute<'src> {
     long_key: Option<Token<'src>>,
     name: StringLiteral<'src>,
     pattern: Option<Pattern<'src>>,
+    secret: bool,
     short: Option<StringLiteral<'src>>,
     value: Option<StringLiteral<'src>>,
   },
@@ -25,21 +26,35 @@ pub(crate) enum Attribute<'src> {
   Default,
   Doc(Option<StringLiteral<'src>>),
   Env(StringLiteral<'src>, StringLiteral<'src>),
+  EnvPass(Vec<StringLiteral<'src>>),
   ExitMessage,
+  Export,
   Extension(StringLiteral<'src>),
+  Finally(Vec<StringLiteral<'src>>),
   Group(StringLiteral<'src>),
   Linux,
+  Loops,
   Macos,
   Metadata(Vec<StringLiteral<'src>>),
   NoCd,
   NoExitMessage,
   NoQuiet,
   Openbsd,
+  Override,
   Parallel,
   PositionalArguments,
   Private,
+  Render(StringLiteral<'src>, StringLiteral<'src>),
   Script(Option<Interpreter<StringLiteral<'src>>>),
+  Secret,
+  Service {
+    command: Option<StringLiteral<'src>>,
+    file: Option<StringLiteral<'src>>,
+    port: Option<StringLiteral<'src>>,
+    timeout: Option<StringLiteral<'src>>,
+  },
   Unix,
+  Watch(Vec<StringLiteral<'src>>),
   Windows,
   WorkingDirectory(StringLiteral<'src>),
 }
@@ -49,22 +64,27 @@ impl AttributeDiscriminant {
     match self {
       Self::Default
       | Self::ExitMessage
+      | Self::Export
       | Self::Linux
+      | Self::Loops
       | Self::Macos
       | Self::NoCd
       | Self::NoExitMessage
       | Self::NoQuiet
       | Self::Openbsd
+      | Self::Override
       | Self::Parallel
       | Self::PositionalArguments
       | Self::Private
+      | Self::Secret
+      | Self::Service
       | Self::Unix
       | Self::Windows => 0..=0,
       Self::Confirm | Self::Doc => 0..=1,
       Self::Script => 0..=usize::MAX,
       Self::Agents | Self::Arg | Self::Extension | Self::Group | Self::WorkingDirectory => 1..=1,
-      Self::Env => 2..=2,
-      Self::Metadata => 1..=usize::MAX,
+      Self::Env | Self::Render => 2..=2,
+      Self::EnvPass | Self::Finally | Self::Metadata | Self::Watch => 1..=usize::MAX,
     }
   }
 }
@@ -169,6 +189,17 @@ impl<'src> Attribute<'src> {
           .map(|(_key, literal)| Pattern::new(&literal))
           .transpose()?;
 
+        let secret = keyword_arguments
+          .remove("secret")
+          .map(|(key, literal)| {
+            if literal.is_some() {
+              return Err(key.error(CompileErrorKind::AttributeKeyUnexpectedValue { key }));
+            }
+            Ok(())
+          })
+          .transpose()?
+          .is_some();
+
         let value = Self::remove_required(&mut keyword_arguments, "value")?
           .map(|(key, literal)| {
             if long.is_none() && short.is_none() {
@@ -187,6 +218,7 @@ impl<'src> Attribute<'src> {
           long_key,
           name: arg,
           pattern,
+          secret,
           short,
           value,
         }
@@ -198,19 +230,28 @@ impl<'src> Attribute<'src> {
         let [key, value]: [StringLiteral; 2] = arguments.try_into().unwrap();
         Self::Env(key, value)
       }
+      AttributeDiscriminant::EnvPass => Self::EnvPass(arguments),
       AttributeDiscriminant::ExitMessage => Self::ExitMessage,
+      AttributeDiscriminant::Export => Self::Export,
       AttributeDiscriminant::Extension => Self::Extension(arguments.into_iter().next().unwrap()),
+      AttributeDiscriminant::Finally => Self::Finally(arguments),
       AttributeDiscriminant::Group => Self::Group(arguments.into_iter().next().unwrap()),
       AttributeDiscriminant::Linux => Self::Linux,
+      AttributeDiscriminant::Loops => Self::Loops,
       AttributeDiscriminant::Macos => Self::Macos,
       AttributeDiscriminant::Metadata => Self::Metadata(arguments),
       AttributeDiscriminant::NoCd => Self::NoCd,
       AttributeDiscriminant::NoExitMessage => Self::NoExitMessage,
       AttributeDiscriminant::NoQuiet => Self::NoQuiet,
       AttributeDiscriminant::Openbsd => Self::Openbsd,
+      AttributeDiscriminant::Override => Self::Override,
       AttributeDiscriminant::Parallel => Self::Parallel,
       AttributeDiscriminant::PositionalArguments => Self::PositionalArguments,
       AttributeDiscriminant::Private => Self::Private,
+      AttributeDiscriminant::Render => {
+        let [output, template]: [StringLiteral; 2] = arguments.try_into().unwrap();
+        Self::Render(output, template)
+      }
       AttributeDiscriminant::Script => Self::Script({
         let mut arguments = arguments.into_iter();
         arguments.next().map(|command| Interpreter {
@@ -218,7 +259,66 @@ impl<'src> Attribute<'src> {
           arguments: arguments.collect(),
         })
       }),
+      AttributeDiscriminant::Secret => Self::Secret,
+      AttributeDiscriminant::Service => {
+        let command =
+          Self::remove_required(&mut keyword_arguments, "command")?.map(|(_key, literal)| literal);
+
+        let file =
+          Self::remove_required(&mut keyword_arguments, "file")?.map(|(_key, literal)| literal);
+
+        let port = Self::remove_required(&mut keyword_arguments, "port")?
+          .map(|(_key, literal)| {
+            let port = literal
+              .cooked
+              .rsplit_once(':')
+              .map_or(literal.cooked.as_str(), |(_host, port)| port);
+
+            if port.parse::<u16>().is_err() {
+              return Err(literal.token.error(CompileErrorKind::ServicePortInvalid {
+                port: literal.cooked.clone(),
+              }));
+            }
+
+            Ok(literal)
+          })
+          .transpose()?;
+
+        let timeout = Self::remove_required(&mut keyword_arguments, "timeout")?
+          .map(|(_key, literal)| {
+            if literal.cooked.parse::<u64>().is_err() {
+              return Err(
+                literal
+                  .token
+                  .error(CompileErrorKind::ServiceTimeoutInvalid {
+                    timeout: literal.cooked.clone(),
+                  }),
+              );
+            }
+
+            Ok(literal)
+          })
+          .transpose()?;
+
+        Self::Service {
+          command,
+          file,
+          port,
+          timeout,
+        }
+      }
       AttributeDiscriminant::Unix => Self::Unix,
+      AttributeDiscriminant::Watch => {
+        for argument in &arguments {
+          Glob::matcher(&argument.cooked).map_err(|source| {
+            argument
+              .token
+              .error(CompileErrorKind::GlobPattern { source })
+          })?;
+        }
+
+        Self::Watch(arguments)
+      }
       AttributeDiscriminant::Windows => Self::Windows,
       AttributeDiscriminant::WorkingDirectory => {
         Self::WorkingDirectory(arguments.into_iter().next().unwrap())
@@ -262,7 +362,14 @@ impl<'src> Attribute<'src> {
   pub(crate) fn repeatable(&self) -> bool {
     matches!(
       self,
-      Attribute::Arg { .. } | Attribute::Env(_, _) | Attribute::Group(_) | Attribute::Metadata(_),
+      Attribute::Arg { .. }
+        | Attribute::Env(_, _)
+        | Attribute::EnvPass(_)
+        | Attribute::Finally(_)
+        | Attribute::Group(_)
+        | Attribute::Metadata(_)
+        | Attribute::Render(_, _)
+        | Attribute::Watch(_),
     )
   }
 }
@@ -278,6 +385,7 @@ impl Display for Attribute<'_> {
         long_key: _,
         name,
         pattern,
+        secret,
         short,
         value,
       } => {
@@ -295,6 +403,10 @@ impl Display for Attribute<'_> {
           write!(f, ", pattern={}", pattern.token.lexeme())?;
         }
 
+        if *secret {
+          write!(f, ", secret")?;
+        }
+
         if let Some(value) = value {
           write!(f, ", value={value}")?;
         }
@@ -309,16 +421,20 @@ impl Display for Attribute<'_> {
       | Self::Default
       | Self::Doc(None)
       | Self::ExitMessage
+      | Self::Export
       | Self::Linux
+      | Self::Loops
       | Self::Macos
       | Self::NoCd
       | Self::NoExitMessage
       | Self::NoQuiet
       | Self::Openbsd
+      | Self::Override
       | Self::Parallel
       | Self::PositionalArguments
       | Self::Private
       | Self::Script(None)
+      | Self::Secret
       | Self::Unix
       | Self::Windows => {}
       Self::Agents(argument)
@@ -327,8 +443,11 @@ impl Display for Attribute<'_> {
       | Self::Extension(argument)
       | Self::Group(argument)
       | Self::WorkingDirectory(argument) => write!(f, "({argument})")?,
-      Self::Env(key, value) => write!(f, "({key}, {value})")?,
-      Self::Metadata(arguments) => {
+      Self::Env(a, b) | Self::Render(a, b) => write!(f, "({a}, {b})")?,
+      Self::EnvPass(arguments)
+      | Self::Finally(arguments)
+      | Self::Metadata(arguments)
+      | Self::Watch(arguments) => {
         write!(f, "(")?;
         for (i, argument) in arguments.iter().enumerate() {
           if i > 0 {
@@ -339,6 +458,26 @@ impl Display for Attribute<'_> {
         write!(f, ")")?;
       }
       Self::Script(Some(shell)) => write!(f, "({shell})")?,
+      Self::Service {
+        command,
+        file,
+        port,
+        timeout,
+      } => {
+        let arguments = [
+          ("command", command),
+          ("file", file),
+          ("port", port),
+          ("timeout", timeout),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| value.as_ref().map(|value| format!("{key}={value}")))
+        .collect::<Vec<String>>();
+
+        if !arguments.is_empty() {
+          write!(f, "({})", arguments.join(", "))?;
+        }
+      }
     }
 
     Ok(())
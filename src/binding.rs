@@ -1,15 +1,15 @@
 use super::*;
 
 /// A binding of `name` to `value`
-#[derive(Debug, Clone, PartialEq, Serialize)]
-pub(crate) struct Binding<'src, V = String> {
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Binding<'src, V = Value> {
   pub(crate) export: bool,
-  #[serde(skip)]
   pub(crate) file_depth: u32,
   pub(crate) name: Name<'src>,
-  #[serde(skip)]
   pub(crate) prelude: bool,
   pub(crate) private: bool,
+  pub(crate) public: bool,
+  pub(crate) secret: bool,
   pub(crate) value: V,
 }
 
@@ -18,3 +18,23 @@ impl<'src, V> Keyed<'src> for Binding<'src, V> {
     self.name.lexeme()
   }
 }
+
+impl<V: Serialize> Serialize for Binding<'_, V> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    let mut binding = serializer.serialize_struct("Binding", 6)?;
+    binding.serialize_field("export", &self.export)?;
+    binding.serialize_field("name", &self.name)?;
+    binding.serialize_field("private", &self.private)?;
+    binding.serialize_field("public", &self.public)?;
+    binding.serialize_field("secret", &self.secret)?;
+    if self.secret {
+      binding.serialize_field("value", Secrets::MASK)?;
+    } else {
+      binding.serialize_field("value", &self.value)?;
+    }
+    binding.end()
+  }
+}
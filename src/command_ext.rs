@@ -1,6 +1,8 @@
 use super::*;
 
 pub(crate) trait CommandExt {
+  fn clear_env_except(&mut self, names: &[&str]) -> &mut Command;
+
   fn export(
     &mut self,
     settings: &Settings,
@@ -11,6 +13,8 @@ pub(crate) trait CommandExt {
 
   fn export_scope(&mut self, settings: &Settings, scope: &Scope, unexports: &HashSet<String>);
 
+  fn new_process_group(&mut self) -> &mut Command;
+
   fn output_guard(self) -> (io::Result<process::Output>, Option<Signal>);
 
   fn output_guard_forward_all(self) -> (io::Result<process::Output>, Option<Signal>);
@@ -21,6 +25,18 @@ pub(crate) trait CommandExt {
 }
 
 impl CommandExt for Command {
+  fn clear_env_except(&mut self, names: &[&str]) -> &mut Command {
+    self.env_clear();
+
+    for name in names {
+      if let Some(value) = env::var_os(name) {
+        self.env(name, value);
+      }
+    }
+
+    self
+  }
+
   fn export(
     &mut self,
     settings: &Settings,
@@ -50,11 +66,18 @@ impl CommandExt for Command {
 
     for binding in scope.bindings() {
       if binding.export || (settings.export && !binding.prelude) {
-        self.env(binding.name.lexeme(), &binding.value);
+        self.env(binding.name.lexeme(), binding.value.to_string());
       }
     }
   }
 
+  fn new_process_group(&mut self) -> &mut Command {
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(self, 0);
+
+    self
+  }
+
   fn output_guard(self) -> (io::Result<process::Output>, Option<Signal>) {
     SignalHandler::spawn(self, process::Child::wait_with_output)
   }
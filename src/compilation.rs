@@ -4,6 +4,9 @@ use super::*;
 pub(crate) struct Compilation<'src> {
   pub(crate) asts: HashMap<PathBuf, Ast<'src>>,
   pub(crate) justfile: Justfile<'src>,
+  /// The commits that git imports resolved to, if they differ from those in
+  /// the lockfile
+  pub(crate) lockfile: Option<Lockfile>,
   pub(crate) root: PathBuf,
   pub(crate) srcs: HashMap<PathBuf, &'src str>,
 }
@@ -16,4 +19,16 @@ impl<'src> Compilation<'src> {
   pub(crate) fn root_src(&self) -> &'src str {
     self.srcs.get(&self.root).unwrap()
   }
+
+  /// Update the lockfile next to the root justfile if the commits that git
+  /// imports resolved to have changed. This is only done when running
+  /// recipes, so that commands which only read the justfile don't write to
+  /// the project.
+  pub(crate) fn save_lockfile(&self) -> RunResult<'static> {
+    if let Some(lockfile) = &self.lockfile {
+      lockfile.save(&self.root.parent().unwrap().join(Lockfile::FILENAME))?;
+    }
+
+    Ok(())
+  }
 }
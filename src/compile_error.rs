@@ -20,7 +20,9 @@ impl<'src> CompileError<'src> {
 
   pub(crate) fn source(&self) -> Option<&dyn std::error::Error> {
     match &*self.kind {
-      CompileErrorKind::ArgumentPatternRegex { source } => Some(source),
+      CompileErrorKind::ArgumentPatternRegex { source }
+      | CompileErrorKind::MatchArmRegex { source } => Some(source),
+      CompileErrorKind::GlobPattern { source } => Some(source),
       _ => None,
     }
   }
@@ -76,6 +78,17 @@ impl Display for CompileError<'_> {
         )
       }
       BacktickShebang => write!(f, "Backticks may not start with `#!`"),
+      CircularFunctionCall { function, circle } => {
+        if circle.len() == 2 {
+          write!(f, "Function `{function}` calls itself")
+        } else {
+          write!(
+            f,
+            "Function `{function}` has circular call `{}`",
+            circle.join(" -> ")
+          )
+        }
+      }
       CircularRecipeDependency { recipe, circle } => {
         if circle.len() == 2 {
           write!(f, "Recipe `{recipe}` depends on itself")
@@ -141,6 +154,18 @@ impl Display for CompileError<'_> {
         f,
         "Recipe `{recipe}` has duplicate `[default]` attribute, which may only appear once per module",
       ),
+      DuplicateFunctionParameter {
+        function,
+        parameter,
+      } => {
+        write!(
+          f,
+          "Function `{function}` has duplicate parameter `{parameter}`"
+        )
+      }
+      DuplicateGitImportKeyword { keyword } => {
+        write!(f, "Git import has duplicate keyword `{keyword}`")
+      }
       DuplicateOption { recipe, option } => {
         write!(
           f,
@@ -185,6 +210,16 @@ impl Display for CompileError<'_> {
       ExtraneousAttributes { count } => {
         write!(f, "Extraneous {}", Count("attribute", *count))
       }
+      FinallyRecipeParameters { finally, recipe } => write!(
+        f,
+        "Recipe `{recipe}` has finally recipe `{finally}`, which takes required parameters",
+      ),
+      FinallyRecipeUnknown { recipe, unknown } => {
+        write!(
+          f,
+          "Recipe `{recipe}` has unknown finally recipe `{unknown}`"
+        )
+      }
       FunctionArgumentCountMismatch {
         function,
         found,
@@ -195,6 +230,15 @@ impl Display for CompileError<'_> {
         Count("argument", *found),
         expected.display(),
       ),
+      FunctionRunsCommand { function } => write!(
+        f,
+        "Function `{function}` may not run commands with backticks or `shell()`"
+      ),
+      FunctionShadowsBuiltin { function } => {
+        write!(f, "Function `{function}` shadows built-in function")
+      }
+      GitImportMissingRev => write!(f, "Git import requires a `rev` keyword"),
+      GlobPattern { .. } => write!(f, "Failed to parse glob pattern"),
       Include => write!(
         f,
         "The `!include` directive has been stabilized as `import`"
@@ -206,6 +250,7 @@ impl Display for CompileError<'_> {
         ShowWhitespace(expected),
         ShowWhitespace(found)
       ),
+      IntegerLiteralOverflow => write!(f, "Integer literal is too large"),
       Internal { message } => write!(
         f,
         "Internal error, this may indicate a bug in just: {message}\n\
@@ -236,6 +281,14 @@ impl Display for CompileError<'_> {
           _ => character.escape_default().collect(),
         }
       ),
+      LoopsInScriptRecipe { recipe } => write!(
+        f,
+        "Recipe `{recipe}` has a `[loops]` attribute, but loops are not supported in script \
+         recipes"
+      ),
+      MatchArmRegex { .. } => write!(f, "Failed to parse match arm regular expression"),
+      MatchArmUnreachable => write!(f, "Match arm is unreachable because it follows `_`"),
+      MatchWithoutWildcard => write!(f, "Match expression must have a `_` arm"),
       MismatchedClosingDelimiter {
         open,
         open_line,
@@ -266,10 +319,19 @@ impl Display for CompileError<'_> {
       OptionNameEmpty { parameter } => {
         write!(f, "Option name for parameter `{parameter}` is empty")
       }
+      OverrideWithoutImportedRecipe { recipe } => write!(
+        f,
+        "Recipe `{recipe}` has `[override]` attribute but does not override an imported recipe"
+      ),
       ParameterFollowsVariadicParameter { parameter } => {
         write!(f, "Parameter `{parameter}` follows variadic parameter")
       }
       ParsingRecursionDepthExceeded => write!(f, "Parsing recursion depth exceeded"),
+      PrivateModuleItem {
+        item_type,
+        module,
+        name,
+      } => write!(f, "{item_type} `{name}` is private to module `{module}`"),
       Redefinition {
         first,
         first_type,
@@ -295,6 +357,14 @@ impl Display for CompileError<'_> {
           )
         }
       }
+      ServicePortInvalid { port } => write!(
+        f,
+        "Service port `{port}` is not a port number or `HOST:PORT` address"
+      ),
+      ServiceTimeoutInvalid { timeout } => write!(
+        f,
+        "Service timeout `{timeout}` is not a whole number of seconds"
+      ),
       ShellExpansion { err } => write!(f, "Shell expansion failed: {err}"),
       ShortOptionWithMultipleCharacters { parameter } => {
         write!(
@@ -309,6 +379,7 @@ impl Display for CompileError<'_> {
       UndefinedArgAttribute { argument } => {
         write!(f, "Argument attribute for undefined argument `{argument}`")
       }
+      UndefinedModuleVariable { variable } => write!(f, "Variable `{variable}` not defined"),
       UndefinedVariable { variable } => write!(f, "Variable `{variable}` not defined"),
       UnexpectedCharacter { expected } => {
         write!(f, "Expected character {}", List::or_ticked(expected))
@@ -351,6 +422,9 @@ impl Display for CompileError<'_> {
       AttributeKeyMissingValue { key } => {
         write!(f, "Attribute key `{key}` requires value",)
       }
+      AttributeKeyUnexpectedValue { key } => {
+        write!(f, "Attribute key `{key}` does not take a value")
+      }
       UnknownAttributeKeyword { attribute, keyword } => {
         write!(f, "Unknown keyword `{keyword}` for `{attribute}` attribute")
       }
@@ -359,6 +433,19 @@ impl Display for CompileError<'_> {
         write!(f, "Recipe `{recipe}` has unknown dependency `{unknown}`")
       }
       UnknownFunction { function } => write!(f, "Call to unknown function `{function}`"),
+      UnknownGitImportKeyword { keyword } => write!(
+        f,
+        "Unknown git import keyword `{keyword}`, expected `path` or `rev`"
+      ),
+      UnknownModuleArgument { module, variable } => write!(
+        f,
+        "Module `{module}` has no variable `{variable}` to set with an argument"
+      ),
+      UnknownParameterType { name } => write!(
+        f,
+        "Unknown parameter type `{name}`, expected {}",
+        List::or_ticked(ParameterType::NAMES),
+      ),
       UnknownSetting { setting } => write!(f, "Unknown setting `{setting}`"),
       UnknownStartOfToken { start } => {
         write!(f, "Unknown start of token '{start}'")?;
@@ -369,6 +456,7 @@ impl Display for CompileError<'_> {
       }
       UnpairedCarriageReturn => write!(f, "Unpaired carriage return"),
       UnterminatedBacktick => write!(f, "Unterminated backtick"),
+      UnterminatedForLoop => write!(f, "Unterminated `for` loop"),
       UnterminatedInterpolation => write!(f, "Unterminated interpolation"),
       UnterminatedString => write!(f, "Unterminated string"),
       VariadicParameterWithOption => write!(f, "Variadic parameters may not be options"),
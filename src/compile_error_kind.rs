@@ -15,8 +15,15 @@ pub(crate) enum CompileErrorKind<'src> {
   AttributeKeyMissingValue {
     key: Name<'src>,
   },
+  AttributeKeyUnexpectedValue {
+    key: Name<'src>,
+  },
   AttributePositionalFollowsKeyword,
   BacktickShebang,
+  CircularFunctionCall {
+    function: &'src str,
+    circle: Vec<&'src str>,
+  },
   CircularRecipeDependency {
     recipe: &'src str,
     circle: Vec<&'src str>,
@@ -46,6 +53,13 @@ pub(crate) enum CompileErrorKind<'src> {
     variable: String,
     first: usize,
   },
+  DuplicateFunctionParameter {
+    function: &'src str,
+    parameter: &'src str,
+  },
+  DuplicateGitImportKeyword {
+    keyword: &'src str,
+  },
   DuplicateOption {
     recipe: &'src str,
     option: Switch,
@@ -78,16 +92,35 @@ pub(crate) enum CompileErrorKind<'src> {
   ExtraneousAttributes {
     count: usize,
   },
+  FinallyRecipeParameters {
+    finally: &'src str,
+    recipe: &'src str,
+  },
+  FinallyRecipeUnknown {
+    recipe: &'src str,
+    unknown: String,
+  },
   FunctionArgumentCountMismatch {
     function: &'src str,
     found: usize,
     expected: RangeInclusive<usize>,
   },
+  FunctionRunsCommand {
+    function: &'src str,
+  },
+  FunctionShadowsBuiltin {
+    function: &'src str,
+  },
+  GitImportMissingRev,
+  GlobPattern {
+    source: globset::Error,
+  },
   Include,
   InconsistentLeadingWhitespace {
     expected: &'src str,
     found: &'src str,
   },
+  IntegerLiteralOverflow,
   Internal {
     message: String,
   },
@@ -102,6 +135,14 @@ pub(crate) enum CompileErrorKind<'src> {
   InvalidEscapeSequence {
     character: char,
   },
+  LoopsInScriptRecipe {
+    recipe: &'src str,
+  },
+  MatchArmRegex {
+    source: regex::Error,
+  },
+  MatchArmUnreachable,
+  MatchWithoutWildcard,
   MismatchedClosingDelimiter {
     close: Delimiter,
     open: Delimiter,
@@ -119,10 +160,18 @@ pub(crate) enum CompileErrorKind<'src> {
   OptionNameEmpty {
     parameter: String,
   },
+  OverrideWithoutImportedRecipe {
+    recipe: &'src str,
+  },
   ParameterFollowsVariadicParameter {
     parameter: &'src str,
   },
   ParsingRecursionDepthExceeded,
+  PrivateModuleItem {
+    item_type: &'static str,
+    module: String,
+    name: &'src str,
+  },
   Redefinition {
     first: usize,
     first_type: &'static str,
@@ -132,6 +181,12 @@ pub(crate) enum CompileErrorKind<'src> {
   RequiredParameterFollowsDefaultParameter {
     parameter: &'src str,
   },
+  ServicePortInvalid {
+    port: String,
+  },
+  ServiceTimeoutInvalid {
+    timeout: String,
+  },
   ShellExpansion {
     err: shellexpand::LookupError<env::VarError>,
   },
@@ -141,6 +196,9 @@ pub(crate) enum CompileErrorKind<'src> {
   UndefinedArgAttribute {
     argument: String,
   },
+  UndefinedModuleVariable {
+    variable: Namepath<'src>,
+  },
   UndefinedVariable {
     variable: &'src str,
   },
@@ -187,7 +245,17 @@ pub(crate) enum CompileErrorKind<'src> {
     unknown: Namepath<'src>,
   },
   UnknownFunction {
-    function: &'src str,
+    function: Namepath<'src>,
+  },
+  UnknownGitImportKeyword {
+    keyword: &'src str,
+  },
+  UnknownModuleArgument {
+    module: &'src str,
+    variable: &'src str,
+  },
+  UnknownParameterType {
+    name: &'src str,
   },
   UnknownSetting {
     setting: &'src str,
@@ -197,6 +265,7 @@ pub(crate) enum CompileErrorKind<'src> {
   },
   UnpairedCarriageReturn,
   UnterminatedBacktick,
+  UnterminatedForLoop,
   UnterminatedInterpolation,
   UnterminatedString,
   VariadicParameterWithOption,
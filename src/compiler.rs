@@ -13,6 +13,10 @@ impl Compiler {
     let mut paths = HashMap::<PathBuf, PathBuf>::new();
     let mut srcs = HashMap::<PathBuf, &str>::new();
 
+    let lockfile_path = root.parent().unwrap().join(Lockfile::FILENAME);
+    let locked = Lockfile::load(&lockfile_path)?;
+    let mut lockfile = Lockfile::default();
+
     let mut stack = Vec::new();
     stack.push(Source::root(root));
 
@@ -28,7 +32,6 @@ impl Compiler {
       let mut ast = Parser::parse(
         current.file_depth,
         &current.import_offsets,
-        current.namepath.as_ref(),
         &tokens,
         &current.working_directory,
       )?;
@@ -42,17 +45,41 @@ impl Compiler {
             absolute,
             name,
             optional,
-            relative,
+            source,
             ..
           } => {
             let parent = current.path.parent().unwrap();
 
-            let relative = relative
-              .as_ref()
-              .map(|relative| Self::expand_tilde(&relative.cooked))
-              .transpose()?;
-
-            let import = Self::find_module_file(parent, *name, relative.as_deref())?;
+            let import = match source {
+              Some(ImportSource::Git {
+                path,
+                repository,
+                rev,
+              }) => {
+                let checkout = Self::git_checkout(
+                  config,
+                  &locked,
+                  &mut lockfile,
+                  parent,
+                  repository,
+                  rev,
+                  root,
+                )?;
+                let path = path
+                  .as_ref()
+                  .map_or_else(PathBuf::new, |path| path.cooked.clone().into());
+                let import = Self::find_module_file(&checkout, *name, Some(&path))?;
+                if let Some(import) = &import {
+                  Self::check_git_import_path(&checkout, import, repository, rev)?;
+                }
+                import
+              }
+              Some(ImportSource::Path(relative)) => {
+                let relative = Self::expand_tilde(&relative.cooked)?;
+                Self::find_module_file(parent, *name, Some(&relative))?
+              }
+              None => Self::find_module_file(parent, *name, None)?,
+            };
 
             if let Some(import) = import {
               if current.file_path.contains(&import) {
@@ -62,22 +89,53 @@ impl Compiler {
                 });
               }
               *absolute = Some(import.clone());
-              stack.push(current.module(*name, import));
+              let mut module = current.module(import);
+              // Recipes in modules imported from git run in the working
+              // directory of the module that imports them, not the cache
+              if let Some(ImportSource::Git { .. }) = source {
+                module
+                  .working_directory
+                  .clone_from(&current.working_directory);
+              }
+              stack.push(module);
             } else if !*optional {
               return Err(Error::MissingModuleFile { module: *name });
             }
           }
           Item::Import {
-            relative,
             absolute,
             optional,
+            source,
           } => {
-            let import = current
-              .path
-              .parent()
-              .unwrap()
-              .join(Self::expand_tilde(&relative.cooked)?)
-              .lexiclean();
+            let parent = current.path.parent().unwrap();
+
+            let import = match source {
+              ImportSource::Git {
+                path,
+                repository,
+                rev,
+              } => {
+                let checkout = Self::git_checkout(
+                  config,
+                  &locked,
+                  &mut lockfile,
+                  parent,
+                  repository,
+                  rev,
+                  root,
+                )?;
+                let import = checkout
+                  .join(path.as_ref().map_or("justfile", |path| &path.cooked))
+                  .lexiclean();
+                if filesystem::is_file(&import)? {
+                  Self::check_git_import_path(&checkout, &import, repository, rev)?;
+                }
+                import
+              }
+              ImportSource::Path(relative) => parent
+                .join(Self::expand_tilde(&relative.cooked)?)
+                .lexiclean(),
+            };
 
             if filesystem::is_file(&import)? {
               if current.file_path.contains(&import) {
@@ -87,10 +145,10 @@ impl Compiler {
                 });
               }
               *absolute = Some(import.clone());
-              stack.push(current.import(import, relative.token.offset));
+              stack.push(current.import(import, source.token().offset));
             } else if !*optional {
               return Err(Error::MissingImportFile {
-                path: relative.token,
+                path: source.token(),
               });
             }
           }
@@ -101,11 +159,25 @@ impl Compiler {
       asts.insert(current.path, ast.clone());
     }
 
-    let justfile = Analyzer::analyze(&asts, config, None, &[], &loaded, None, &paths, false, root)?;
+    let justfile = Analyzer::analyze(
+      &[],
+      &asts,
+      config,
+      None,
+      false,
+      &[],
+      &loaded,
+      "",
+      None,
+      &paths,
+      false,
+      root,
+    )?;
 
     Ok(Compilation {
       asts,
       justfile,
+      lockfile: (lockfile != locked).then_some(lockfile),
       root: root.into(),
       srcs,
     })
@@ -207,6 +279,93 @@ impl Compiler {
     }
   }
 
+  /// Check that `import`, a file in `checkout` of `repository` at `rev`,
+  /// doesn't escape the checkout, for example with `..` or a symlink
+  fn check_git_import_path<'src>(
+    checkout: &Path,
+    import: &Path,
+    repository: &StringLiteral<'src>,
+    rev: &StringLiteral<'src>,
+  ) -> RunResult<'src> {
+    let error = |message: String| Error::GitImport {
+      message,
+      repository: Box::new(repository.clone()),
+      rev: rev.cooked.clone(),
+    };
+
+    let canonicalize = |path: &Path| {
+      path.canonicalize().map_err(|io_error| {
+        error(format!(
+          "I/O error canonicalizing `{}`: {io_error}",
+          path.display()
+        ))
+      })
+    };
+
+    if !canonicalize(import)?.starts_with(canonicalize(checkout)?) {
+      return Err(error("import path is outside of the repository".into()));
+    }
+
+    Ok(())
+  }
+
+  /// Check out the revision of `repository` referred to by a git import, and
+  /// record the commit it resolved to in `lockfile`
+  fn git_checkout<'src>(
+    config: &Config,
+    locked: &Lockfile,
+    lockfile: &mut Lockfile,
+    parent: &Path,
+    repository: &StringLiteral<'src>,
+    rev: &StringLiteral<'src>,
+    root: &Path,
+  ) -> RunResult<'src, PathBuf> {
+    let cache_directory = config.cache_directory()?;
+
+    // Local repositories are locked under their path relative to the root
+    // justfile, so that the lockfile does not depend on where it is checked
+    // out. Anything else is passed to git as is.
+    let local = parent
+      .join(Self::expand_tilde(&repository.cooked)?)
+      .lexiclean();
+
+    let (url, key) = if local.is_dir() {
+      let base = root.parent().unwrap();
+      let common = local
+        .components()
+        .zip(base.components())
+        .take_while(|(a, b)| a == b)
+        .count();
+      let key = base
+        .components()
+        .skip(common)
+        .map(|_| Component::ParentDir)
+        .chain(local.components().skip(common))
+        .map(|component| component.as_os_str().to_string_lossy().into_owned())
+        .collect::<Vec<String>>()
+        .join("/");
+      (local.to_string_lossy().into_owned(), key)
+    } else {
+      (repository.cooked.clone(), repository.cooked.clone())
+    };
+
+    let checkout = GitCheckout::new(
+      &cache_directory,
+      &url,
+      &rev.cooked,
+      locked.get(&key, &rev.cooked),
+    )
+    .map_err(|message| Error::GitImport {
+      message,
+      repository: Box::new(repository.clone()),
+      rev: rev.cooked.clone(),
+    })?;
+
+    lockfile.insert(&key, &rev.cooked, checkout.commit);
+
+    Ok(checkout.path)
+  }
+
   fn expand_tilde(path: &str) -> RunResult<'static, PathBuf> {
     Ok(if let Some(path) = path.strip_prefix("~/") {
       dirs::home_dir()
@@ -220,18 +379,21 @@ impl Compiler {
   #[cfg(test)]
   pub(crate) fn test_compile(src: &str) -> RunResult<Justfile> {
     let tokens = Lexer::test_lex(src)?;
-    let ast = Parser::parse(0, &[], None, &tokens, &PathBuf::new())?;
+    let ast = Parser::parse(0, &[], &tokens, &PathBuf::new())?;
     let root = PathBuf::from("justfile");
     let mut asts: HashMap<PathBuf, Ast> = HashMap::new();
     asts.insert(root.clone(), ast);
     let mut paths: HashMap<PathBuf, PathBuf> = HashMap::new();
     paths.insert(root.clone(), root.clone());
     Analyzer::analyze(
+      &[],
       &asts,
       &Config::default(),
       None,
+      false,
       &[],
       &[],
+      "",
       None,
       &paths,
       false,
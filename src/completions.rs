@@ -121,12 +121,29 @@ mod tests {
         printf "%s\n" (string split " " (just --summary))
 end
 
+function __fish_just_complete_parameter
+        set -l tokens (commandline -opc)
+        if test (count $tokens) -lt 2
+          return
+        end
+        set -l parameter (just --complete-parameter $tokens[2..-1] 2> /dev/null)
+        switch "$parameter[1]"
+          case file
+            __fish_complete_path (commandline -ct)
+          case bool enum
+            printf "%s\n" $parameter[2..-1]
+        end
+end
+
 # don't suggest files right off
 complete -c just -n "__fish_is_first_arg" --no-files
 
 # complete recipes
 complete -c just -a '(__fish_just_complete_recipes)'
 
+# complete typed recipe parameters
+complete -c just -n "not __fish_is_first_arg" -a '(__fish_just_complete_parameter)'
+
 # autogenerated completions
 "#;
 
@@ -281,6 +298,21 @@ _just "$@""#,
                     COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                     return 0
                 elif [[ ${COMP_CWORD} -eq 1 ]]; then
+                    if [[ ${cword} -gt 1 ]]; then
+                        local parameter=( $(just --complete-parameter "${words[@]:1:cword-1}" 2> /dev/null) )
+
+                        case "${parameter[0]}" in
+                            file)
+                                COMPREPLY=( $(compgen -f -- "${cur}") )
+                                return 0
+                                ;;
+                            bool|enum)
+                                COMPREPLY=( $(compgen -W "${parameter[*]:1}" -- "${cur}") )
+                                return 0
+                                ;;
+                        esac
+                    fi
+
                     local recipes=$(just --summary 2> /dev/null)
 
                     if echo "${cur}" | \grep -qF '/'; then
@@ -311,7 +343,7 @@ _just "$@""#,
     else
         cur="${COMP_WORDS[COMP_CWORD]}"
         prev="${COMP_WORDS[COMP_CWORD-1]}"
-        words=$COMP_WORDS
+        words=("${COMP_WORDS[@]}")
         cword=$COMP_CWORD
     fi
 "#,
@@ -5,6 +5,7 @@ pub(crate) struct Condition<'src> {
   pub(crate) lhs: Box<Expression<'src>>,
   pub(crate) operator: ConditionalOperator,
   pub(crate) rhs: Box<Expression<'src>>,
+  pub(crate) token: Token<'src>,
 }
 
 impl Display for Condition<'_> {
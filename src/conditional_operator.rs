@@ -5,19 +5,41 @@ use super::*;
 pub(crate) enum ConditionalOperator {
   /// `==`
   Equality,
+  /// `>`
+  GreaterThan,
+  /// `>=`
+  GreaterThanOrEqual,
   /// `!=`
   Inequality,
+  /// `<`
+  LessThan,
+  /// `<=`
+  LessThanOrEqual,
   /// `=~`
   RegexMatch,
   /// `!~`
   RegexMismatch,
 }
 
+impl ConditionalOperator {
+  /// True for the integer comparison operators, `<`, `<=`, `>`, and `>=`
+  pub(crate) fn is_comparison(self) -> bool {
+    matches!(
+      self,
+      Self::GreaterThan | Self::GreaterThanOrEqual | Self::LessThan | Self::LessThanOrEqual
+    )
+  }
+}
+
 impl Display for ConditionalOperator {
   fn fmt(&self, f: &mut Formatter) -> fmt::Result {
     match self {
       Self::Equality => write!(f, "=="),
+      Self::GreaterThan => write!(f, ">"),
+      Self::GreaterThanOrEqual => write!(f, ">="),
       Self::Inequality => write!(f, "!="),
+      Self::LessThan => write!(f, "<"),
+      Self::LessThanOrEqual => write!(f, "<="),
       Self::RegexMatch => write!(f, "=~"),
       Self::RegexMismatch => write!(f, "!~"),
     }
@@ -16,18 +16,20 @@ pub(crate) struct Config {
   pub(crate) agents_only: bool,
   pub(crate) alias_style: AliasStyle,
   pub(crate) allow_missing: bool,
+  pub(crate) cache_directory: Option<PathBuf>,
   pub(crate) ceiling: Option<PathBuf>,
   pub(crate) check: bool,
   pub(crate) color: Color,
   pub(crate) command_color: Option<ansi_term::Color>,
   pub(crate) cygpath: PathBuf,
   pub(crate) dotenv_filename: Option<String>,
-  pub(crate) dotenv_path: Option<PathBuf>,
+  pub(crate) dotenv_path: Vec<PathBuf>,
   pub(crate) dry_run: bool,
   pub(crate) dump_format: DumpFormat,
   pub(crate) explain: bool,
   pub(crate) highlight: bool,
   pub(crate) invocation_directory: PathBuf,
+  pub(crate) last: bool,
   pub(crate) list_heading: String,
   pub(crate) list_prefix: String,
   pub(crate) list_submodules: bool,
@@ -37,6 +39,7 @@ pub(crate) struct Config {
   pub(crate) one: bool,
   pub(crate) output_format: Option<OutputFormat>,
   pub(crate) overrides: BTreeMap<String, String>,
+  pub(crate) rerun_failed: bool,
   pub(crate) search_config: SearchConfig,
   pub(crate) shell: Option<String>,
   pub(crate) shell_args: Option<Vec<String>>,
@@ -48,6 +51,7 @@ pub(crate) struct Config {
   pub(crate) unsorted: bool,
   pub(crate) unstable: bool,
   pub(crate) verbosity: Verbosity,
+  pub(crate) watch: bool,
   pub(crate) yes: bool,
 }
 
@@ -55,6 +59,7 @@ mod cmd {
   pub(crate) const CHANGELOG: &str = "CHANGELOG";
   pub(crate) const CHOOSE: &str = "CHOOSE";
   pub(crate) const COMMAND: &str = "COMMAND";
+  pub(crate) const COMPLETE_PARAMETER: &str = "COMPLETE-PARAMETER";
   pub(crate) const COMPLETIONS: &str = "COMPLETIONS";
   pub(crate) const DUMP: &str = "DUMP";
   pub(crate) const EDIT: &str = "EDIT";
@@ -69,11 +74,13 @@ mod cmd {
   pub(crate) const SUMMARY: &str = "SUMMARY";
   pub(crate) const USAGE: &str = "USAGE";
   pub(crate) const VARIABLES: &str = "VARIABLES";
+  pub(crate) const WORKSPACE: &str = "WORKSPACE";
 
   pub(crate) const ALL: &[&str] = &[
     CHANGELOG,
     CHOOSE,
     COMMAND,
+    COMPLETE_PARAMETER,
     COMPLETIONS,
     DUMP,
     EDIT,
@@ -86,6 +93,7 @@ mod cmd {
     SHOW,
     SUMMARY,
     VARIABLES,
+    WORKSPACE,
   ];
 
   pub(crate) const ARGLESS: &[&str] =
@@ -99,6 +107,7 @@ mod arg {
   pub(crate) const ALIAS_STYLE: &str = "ALIAS_STYLE";
   pub(crate) const ALLOW_MISSING: &str = "ALLOW-MISSING";
   pub(crate) const ARGUMENTS: &str = "ARGUMENTS";
+  pub(crate) const CACHE_DIRECTORY: &str = "CACHE-DIRECTORY";
   pub(crate) const CEILING: &str = "CEILING";
   pub(crate) const CHECK: &str = "CHECK";
   pub(crate) const CHOOSER: &str = "CHOOSER";
@@ -114,6 +123,7 @@ mod arg {
   pub(crate) const GLOBAL_JUSTFILE: &str = "GLOBAL-JUSTFILE";
   pub(crate) const HIGHLIGHT: &str = "HIGHLIGHT";
   pub(crate) const JUSTFILE: &str = "JUSTFILE";
+  pub(crate) const LAST: &str = "LAST";
   pub(crate) const LIST_HEADING: &str = "LIST-HEADING";
   pub(crate) const LIST_PREFIX: &str = "LIST-PREFIX";
   pub(crate) const LIST_SUBMODULES: &str = "LIST-SUBMODULES";
@@ -123,6 +133,7 @@ mod arg {
   pub(crate) const NO_HIGHLIGHT: &str = "NO-HIGHLIGHT";
   pub(crate) const ONE: &str = "ONE";
   pub(crate) const QUIET: &str = "QUIET";
+  pub(crate) const RERUN_FAILED: &str = "RERUN-FAILED";
   pub(crate) const SET: &str = "SET";
   pub(crate) const SHELL: &str = "SHELL";
   pub(crate) const SHELL_ARG: &str = "SHELL-ARG";
@@ -134,7 +145,10 @@ mod arg {
   pub(crate) const UNSORTED: &str = "UNSORTED";
   pub(crate) const UNSTABLE: &str = "UNSTABLE";
   pub(crate) const VERBOSE: &str = "VERBOSE";
+  pub(crate) const WATCH: &str = "WATCH";
   pub(crate) const WORKING_DIRECTORY: &str = "WORKING-DIRECTORY";
+  pub(crate) const WORKSPACE_FILTER: &str = "WORKSPACE-FILTER";
+  pub(crate) const WORKSPACE_JOBS: &str = "WORKSPACE-JOBS";
   pub(crate) const YES: &str = "YES";
 }
 
@@ -177,6 +191,14 @@ impl Config {
           .help("Set list command alias display style")
           .conflicts_with(arg::NO_ALIASES),
       )
+      .arg(
+        Arg::new(arg::CACHE_DIRECTORY)
+          .long("cache-directory")
+          .env("JUST_CACHE_DIRECTORY")
+          .action(ArgAction::Set)
+          .value_parser(value_parser!(PathBuf))
+          .help("Store git imports and records of previous runs in <CACHE-DIRECTORY>."),
+      )
       .arg(
         Arg::new(arg::CEILING)
           .long("ceiling")
@@ -246,9 +268,12 @@ impl Config {
         Arg::new(arg::DOTENV_PATH)
           .short('E')
           .long("dotenv-path")
-          .action(ArgAction::Set)
+          .action(ArgAction::Append)
           .value_parser(value_parser!(PathBuf))
-          .help("Load <DOTENV-PATH> as environment file instead of searching for one"),
+          .help(
+            "Load <DOTENV-PATH> as environment file instead of searching for one. May be given \
+            multiple times, with later files taking precedence.",
+          ),
       )
       .arg(
         Arg::new(arg::DRY_RUN)
@@ -302,6 +327,14 @@ impl Config {
           .value_parser(value_parser!(PathBuf))
           .help("Use <JUSTFILE> as justfile"),
       )
+      .arg(
+        Arg::new(arg::LAST)
+          .long("last")
+          .action(ArgAction::SetTrue)
+          .help("Repeat the recipes run by the last invocation")
+          .conflicts_with(arg::RERUN_FAILED)
+          .conflicts_with_all(cmd::ALL),
+      )
       .arg(
         Arg::new(arg::LIST_HEADING)
           .long("list-heading")
@@ -374,6 +407,13 @@ impl Config {
           .help("Suppress all output")
           .conflicts_with(arg::DRY_RUN),
       )
+      .arg(
+        Arg::new(arg::RERUN_FAILED)
+          .long("rerun-failed")
+          .action(ArgAction::SetTrue)
+          .help("Run the recipes which failed in the last invocation")
+          .conflicts_with_all(cmd::ALL),
+      )
       .arg(
         Arg::new(arg::ALLOW_MISSING)
           .long("allow-missing")
@@ -469,6 +509,14 @@ impl Config {
           .action(ArgAction::Count)
           .help("Use verbose output"),
       )
+      .arg(
+        Arg::new(arg::WATCH)
+          .long("watch")
+          .env("JUST_WATCH")
+          .action(ArgAction::SetTrue)
+          .help("Re-run recipes when watched files change")
+          .conflicts_with_all(cmd::ALL),
+      )
       .arg(
         Arg::new(arg::WORKING_DIRECTORY)
           .short('d')
@@ -479,6 +527,26 @@ impl Config {
           .help("Use <WORKING-DIRECTORY> as working directory. --justfile must also be set")
           .requires(arg::JUSTFILE),
       )
+      .arg(
+        Arg::new(arg::WORKSPACE_FILTER)
+          .long("workspace-filter")
+          .action(ArgAction::Append)
+          .value_name("GLOB")
+          .requires(cmd::WORKSPACE)
+          .help(
+            "Only run `--workspace` recipes in directories whose path relative to the project \
+             root matches <GLOB>",
+          ),
+      )
+      .arg(
+        Arg::new(arg::WORKSPACE_JOBS)
+          .long("workspace-jobs")
+          .env("JUST_WORKSPACE_JOBS")
+          .action(ArgAction::Set)
+          .value_parser(value_parser!(NonZeroUsize))
+          .default_value("1")
+          .help("Run `--workspace` recipes in up to <WORKSPACE-JOBS> directories in parallel"),
+      )
       .arg(
         Arg::new(arg::YES)
           .long("yes")
@@ -517,6 +585,21 @@ impl Config {
           )
           .help_heading(cmd::HEADING),
       )
+      .arg(
+        Arg::new(cmd::COMPLETE_PARAMETER)
+          .long("complete-parameter")
+          .num_args(1..)
+          .value_name("ARGUMENTS")
+          .action(ArgAction::Set)
+          .allow_hyphen_values(true)
+          .conflicts_with(arg::ARGUMENTS)
+          .hide(true)
+          .help(
+            "Print the type and completions of the recipe parameter following <ARGUMENTS>. Used \
+            by shell completion scripts. May be changed or removed at any time.",
+          )
+          .help_heading(cmd::HEADING),
+      )
       .arg(
         Arg::new(cmd::COMPLETIONS)
           .long("completions")
@@ -640,6 +723,19 @@ impl Config {
           .help("List names of variables")
           .help_heading(cmd::HEADING),
       )
+      .arg(
+        Arg::new(cmd::WORKSPACE)
+          .long("workspace")
+          .action(ArgAction::SetTrue)
+          .requires(arg::ARGUMENTS)
+          .conflicts_with(arg::JUSTFILE)
+          .conflicts_with(arg::WORKING_DIRECTORY)
+          .help(
+            "Run recipe in every justfile beneath the project root that defines it, reporting \
+             results as TAP",
+          )
+          .help_heading(cmd::HEADING),
+      )
       .group(ArgGroup::new("SUBCOMMAND").args(cmd::ALL))
       .arg(
         Arg::new(arg::ARGUMENTS)
@@ -699,6 +795,16 @@ impl Config {
     }
   }
 
+  /// The directory in which git imports are checked out and runs are
+  /// recorded, `--cache-directory` if given, and otherwise `just` in the
+  /// platform cache directory
+  pub(crate) fn cache_directory(&self) -> RunResult<'static, PathBuf> {
+    Ok(match &self.cache_directory {
+      Some(cache_directory) => self.invocation_directory.join(cache_directory),
+      None => dirs::cache_dir().ok_or(Error::CacheDirectory)?.join("just"),
+    })
+  }
+
   pub(crate) fn timestamp(&self) -> Option<String> {
     self.timestamp.then(|| {
       chrono::Local::now()
@@ -727,6 +833,15 @@ impl Config {
 
     let search_config = Self::search_config(matches, &positional)?;
 
+    for rerun in [arg::LAST, arg::RERUN_FAILED] {
+      if matches.get_flag(rerun) && !positional.arguments.is_empty() {
+        return Err(ConfigError::SubcommandArguments {
+          arguments: positional.arguments,
+          subcommand: rerun,
+        });
+      }
+    }
+
     for subcommand in cmd::ARGLESS {
       if matches.get_flag(subcommand) {
         match (!overrides.is_empty(), !positional.arguments.is_empty()) {
@@ -766,6 +881,10 @@ impl Config {
         binary: arguments.remove(0),
         arguments,
       }
+    } else if let Some(arguments) = matches.get_many::<String>(cmd::COMPLETE_PARAMETER) {
+      Subcommand::CompleteParameter {
+        arguments: arguments.cloned().collect(),
+      }
     } else if let Some(&shell) = matches.get_one::<completions::Shell>(cmd::COMPLETIONS) {
       Subcommand::Completions { shell }
     } else if matches.get_flag(cmd::DUMP) {
@@ -816,6 +935,25 @@ impl Config {
       }
     } else if matches.get_flag(cmd::VARIABLES) {
       Subcommand::Variables
+    } else if matches.get_flag(cmd::WORKSPACE) {
+      let recipe = positional.arguments.first().unwrap();
+
+      if ModulePath::try_from([recipe.as_str()].as_slice()).is_err() {
+        return Err(ConfigError::ModulePath {
+          path: vec![recipe.clone()],
+        });
+      }
+
+      Subcommand::Workspace {
+        arguments: positional.arguments,
+        filters: matches
+          .get_many::<String>(arg::WORKSPACE_FILTER)
+          .map(|filters| filters.cloned().collect())
+          .unwrap_or_default(),
+        jobs: *matches
+          .get_one::<NonZeroUsize>(arg::WORKSPACE_JOBS)
+          .unwrap(),
+      }
     } else {
       Subcommand::Run {
         arguments: positional.arguments,
@@ -832,6 +970,7 @@ impl Config {
         .unwrap()
         .clone(),
       allow_missing: matches.get_flag(arg::ALLOW_MISSING),
+      cache_directory: matches.get_one::<PathBuf>(arg::CACHE_DIRECTORY).cloned(),
       ceiling: matches.get_one::<PathBuf>(arg::CEILING).cloned(),
       check: matches.get_flag(arg::CHECK),
       color: (*matches.get_one::<UseColor>(arg::COLOR).unwrap()).into(),
@@ -843,7 +982,10 @@ impl Config {
       dotenv_filename: matches
         .get_one::<String>(arg::DOTENV_FILENAME)
         .map(Into::into),
-      dotenv_path: matches.get_one::<PathBuf>(arg::DOTENV_PATH).map(Into::into),
+      dotenv_path: matches
+        .get_many::<PathBuf>(arg::DOTENV_PATH)
+        .map(|paths| paths.cloned().collect())
+        .unwrap_or_default(),
       dry_run: matches.get_flag(arg::DRY_RUN),
       dump_format: matches
         .get_one::<DumpFormat>(arg::DUMP_FORMAT)
@@ -852,6 +994,7 @@ impl Config {
       explain,
       highlight: !matches.get_flag(arg::NO_HIGHLIGHT),
       invocation_directory: env::current_dir().context(config_error::CurrentDirContext)?,
+      last: matches.get_flag(arg::LAST),
       list_heading: matches.get_one::<String>(arg::LIST_HEADING).unwrap().into(),
       list_prefix: matches.get_one::<String>(arg::LIST_PREFIX).unwrap().into(),
       list_submodules: matches.get_flag(arg::LIST_SUBMODULES),
@@ -860,6 +1003,7 @@ impl Config {
       no_dependencies: matches.get_flag(arg::NO_DEPS),
       one: matches.get_flag(arg::ONE),
       overrides,
+      rerun_failed: matches.get_flag(arg::RERUN_FAILED),
       search_config,
       shell: matches.get_one::<String>(arg::SHELL).map(Into::into),
       shell_args: if matches.get_flag(arg::CLEAR_SHELL_ARGS) {
@@ -895,6 +1039,7 @@ impl Config {
       } else {
         Verbosity::from_flag_occurrences(matches.get_count(arg::VERBOSE))
       },
+      watch: matches.get_flag(arg::WATCH),
       yes: matches.get_flag(arg::YES),
     })
   }
@@ -0,0 +1,9 @@
+use super::*;
+
+#[derive(Debug, Snafu)]
+pub(crate) enum DotenvError {
+  #[snafu(display("I/O error reading `{}`: {io_error}", path.display()))]
+  Io { io_error: io::Error, path: PathBuf },
+  #[snafu(display("Error parsing line: '{line}', error at line index: {index}"))]
+  Parse { index: usize, line: String },
+}
@@ -0,0 +1,331 @@
+use super::*;
+
+/// Parser for the assignments in an environment file
+///
+/// Values may be unquoted, `'single-quoted'`, in which case they are taken
+/// literally, or `"double-quoted"`, and quoted values may span multiple lines.
+/// Outside of single quotes, `\` escapes the following character, `\n` is a
+/// newline, and `$NAME` and `${NAME}` are replaced by the value of `NAME`.
+pub(crate) struct DotenvParser<'src> {
+  line: usize,
+  next: usize,
+  src: &'src str,
+}
+
+impl<'src> DotenvParser<'src> {
+  pub(crate) fn new(src: &'src str) -> Self {
+    Self {
+      line: 0,
+      next: 0,
+      src: src.strip_prefix('\u{feff}').unwrap_or(src),
+    }
+  }
+
+  /// Parse the next assignment, replacing variables in its value with the
+  /// values returned by `lookup`, or the empty string if `lookup` returns
+  /// `None`. Returns `None` when no assignments remain.
+  pub(crate) fn assignment(
+    &mut self,
+    lookup: impl Fn(&str) -> Option<String>,
+  ) -> Result<Option<(String, String)>, DotenvError> {
+    loop {
+      self.skip_while(char::is_whitespace);
+
+      match self.peek() {
+        None => return Ok(None),
+        Some('#') => self.skip_while(|c| c != '\n'),
+        Some(_) => break,
+      }
+    }
+
+    self.line = self.src[..self.next].rfind('\n').map_or(0, |i| i + 1);
+
+    let mut key = self.key()?;
+
+    self.skip_blanks();
+
+    if key == "export" && self.peek() != Some('=') {
+      key = self.key()?;
+      self.skip_blanks();
+    }
+
+    if !self.accept('=') {
+      return Err(self.error());
+    }
+
+    self.skip_blanks();
+
+    if self.peek() == Some('#') {
+      self.skip_while(|c| c != '\n');
+      return Ok(Some((key, String::new())));
+    }
+
+    let mut value = String::new();
+
+    loop {
+      match self.peek() {
+        None | Some('\n') => break,
+        Some(' ' | '\t' | '\r') => {
+          self.skip_blanks();
+          match self.peek() {
+            None | Some('\n') => break,
+            Some('#') => {
+              self.skip_while(|c| c != '\n');
+              break;
+            }
+            Some(_) => return Err(self.error()),
+          }
+        }
+        Some('\'') => {
+          self.bump();
+          loop {
+            match self.bump() {
+              None => return Err(self.error()),
+              Some('\'') => break,
+              Some(c) => value.push(c),
+            }
+          }
+        }
+        Some('"') => {
+          self.bump();
+          loop {
+            match self.peek() {
+              None => return Err(self.error()),
+              Some('"') => {
+                self.bump();
+                break;
+              }
+              Some(_) => self.character(&lookup, &mut value)?,
+            }
+          }
+        }
+        Some(_) => self.character(&lookup, &mut value)?,
+      }
+    }
+
+    Ok(Some((key, value)))
+  }
+
+  fn accept(&mut self, c: char) -> bool {
+    if self.peek() == Some(c) {
+      self.bump();
+      true
+    } else {
+      false
+    }
+  }
+
+  fn bump(&mut self) -> Option<char> {
+    let c = self.peek()?;
+    self.next += c.len_utf8();
+    Some(c)
+  }
+
+  /// Parse an escape sequence, variable, or literal character outside of
+  /// single quotes
+  fn character(
+    &mut self,
+    lookup: impl Fn(&str) -> Option<String>,
+    value: &mut String,
+  ) -> Result<(), DotenvError> {
+    match self.bump() {
+      Some('\\') => match self.bump() {
+        Some(c @ ('\\' | '\'' | '"' | '$' | ' ')) => value.push(c),
+        Some('n') => value.push('\n'),
+        _ => return Err(self.error()),
+      },
+      Some('$') => {
+        let name = if self.accept('{') {
+          let start = self.next;
+          self.skip_while(|c| c != '}' && c != '\n');
+          let name = &self.src[start..self.next];
+          if !self.accept('}') {
+            return Err(self.error());
+          }
+          name
+        } else {
+          let start = self.next;
+          self.skip_while(|c| c.is_ascii_alphanumeric() || c == '_');
+          &self.src[start..self.next]
+        };
+
+        if name.is_empty() {
+          value.push('$');
+        } else if let Some(replacement) = lookup(name) {
+          value.push_str(&replacement);
+        }
+      }
+      Some(c) => value.push(c),
+      None => {}
+    }
+
+    Ok(())
+  }
+
+  fn error(&self) -> DotenvError {
+    let end = self.src[self.line..]
+      .find('\n')
+      .map_or(self.src.len(), |i| self.line + i);
+
+    DotenvError::Parse {
+      index: self.next.saturating_sub(self.line),
+      line: self.src[self.line..end].into(),
+    }
+  }
+
+  fn key(&mut self) -> Result<String, DotenvError> {
+    if !self
+      .peek()
+      .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+    {
+      return Err(self.error());
+    }
+
+    let start = self.next;
+
+    self.skip_while(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+
+    Ok(self.src[start..self.next].into())
+  }
+
+  fn peek(&self) -> Option<char> {
+    self.src[self.next..].chars().next()
+  }
+
+  fn skip_blanks(&mut self) {
+    self.skip_while(|c| c == ' ' || c == '\t' || c == '\r');
+  }
+
+  fn skip_while(&mut self, f: impl Fn(char) -> bool) {
+    while self.peek().is_some_and(&f) {
+      self.bump();
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[track_caller]
+  fn case(src: &str, expected: &[(&str, &str)]) {
+    let mut parser = DotenvParser::new(src);
+
+    let mut assignments = Vec::new();
+
+    while let Some(assignment) = parser
+      .assignment(|name| (name == "FOO").then(|| "foo".into()))
+      .unwrap()
+    {
+      assignments.push(assignment);
+    }
+
+    assert_eq!(
+      assignments,
+      expected
+        .iter()
+        .map(|(key, value)| ((*key).into(), (*value).into()))
+        .collect::<Vec<(String, String)>>(),
+    );
+  }
+
+  #[track_caller]
+  fn error(src: &str, line: &str, index: usize) {
+    let mut parser = DotenvParser::new(src);
+
+    loop {
+      match parser.assignment(|_| None) {
+        Ok(Some(_)) => {}
+        Err(DotenvError::Parse {
+          index: actual_index,
+          line: actual_line,
+        }) => {
+          assert_eq!(actual_line, line);
+          assert_eq!(actual_index, index);
+          break;
+        }
+        result => panic!("expected parse error, got {result:?}"),
+      }
+    }
+  }
+
+  #[test]
+  fn unquoted() {
+    case("A=a\nB = b \n", &[("A", "a"), ("B", "b")]);
+    case("A=", &[("A", "")]);
+    case("A=a#b", &[("A", "a#b")]);
+    case("A=a # comment", &[("A", "a")]);
+  }
+
+  #[test]
+  fn comments_and_blank_lines() {
+    case("# A=a\n\n  \nB=b\n# C=c", &[("B", "b")]);
+    case("A=# comment", &[("A", "")]);
+  }
+
+  #[test]
+  fn export() {
+    case("export A=a", &[("A", "a")]);
+    case("export \t A = a", &[("A", "a")]);
+    case("export=a", &[("export", "a")]);
+    case("export = a", &[("export", "a")]);
+    error("export A", "export A", 8);
+    error("export 1=a", "export 1=a", 7);
+  }
+
+  #[test]
+  fn newline_escapes() {
+    case(r#"A="a\nb""#, &[("A", "a\nb")]);
+    case(r"A='a\nb'", &[("A", r"a\nb")]);
+    case(r"A=a\nb", &[("A", "a\nb")]);
+  }
+
+  #[test]
+  fn inline_comments() {
+    case("A=a # b\nB=b", &[("A", "a"), ("B", "b")]);
+    case("A='a # b' # c", &[("A", "a # b")]);
+    case("A=\"a\" # c", &[("A", "a")]);
+  }
+
+  #[test]
+  fn unterminated_quotes() {
+    error("A='a\nB=b", "A='a", 8);
+    error("A=\"a\nB=b", "A=\"a", 8);
+    error("A=\"a\\\"", "A=\"a\\\"", 6);
+  }
+
+  #[test]
+  fn quoted() {
+    case(r"A='$FOO \n'", &[("A", r"$FOO \n")]);
+    case(r#"A="$FOO \" \n""#, &[("A", "foo \" \n")]);
+    case("A=\"a\nb\"\nB='c\nd'", &[("A", "a\nb"), ("B", "c\nd")]);
+    case(r#"A=a'b'"c""#, &[("A", "abc")]);
+  }
+
+  #[test]
+  fn substitution() {
+    case("A=$FOO", &[("A", "foo")]);
+    case("A=${FOO}bar", &[("A", "foobar")]);
+    case("A=$FOO.bar", &[("A", "foo.bar")]);
+    case("A=$BAR", &[("A", "")]);
+    case("A=a$", &[("A", "a$")]);
+    case(r"A=\$FOO", &[("A", "$FOO")]);
+  }
+
+  #[test]
+  fn byte_order_mark() {
+    case("\u{feff}A=a", &[("A", "a")]);
+  }
+
+  #[test]
+  fn errors() {
+    error("A", "A", 1);
+    error("1=a", "1=a", 0);
+    error("A=a b", "A=a b", 4);
+    error("A='a", "A='a", 4);
+    error("A=\"a", "A=\"a", 4);
+    error("A=${a", "A=${a", 5);
+    error(r"A=\q", r"A=\q", 4);
+    error("B=b\nA", "A", 1);
+  }
+}
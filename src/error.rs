@@ -15,6 +15,12 @@ pub(crate) enum Error<'src> {
     pattern: Box<Pattern<'src>>,
     recipe: &'src str,
   },
+  ArgumentTypeMismatch {
+    argument: String,
+    parameter: &'src str,
+    recipe: &'src str,
+    ty: Box<ParameterType<'src>>,
+  },
   Assert {
     message: String,
     name: Name<'src>,
@@ -23,6 +29,7 @@ pub(crate) enum Error<'src> {
     token: Token<'src>,
     output_error: OutputError,
   },
+  CacheDirectory,
   ChooserInvoke {
     shell_binary: String,
     shell_arguments: String,
@@ -79,7 +86,7 @@ pub(crate) enum Error<'src> {
     min_arguments: usize,
   },
   Dotenv {
-    dotenv_error: dotenvy::Error,
+    dotenv_error: DotenvError,
   },
   DotenvRequired,
   DumpJson {
@@ -127,7 +134,16 @@ pub(crate) enum Error<'src> {
   GetConfirmation {
     io_error: io::Error,
   },
+  GitImport {
+    message: String,
+    repository: Box<StringLiteral<'src>>,
+    rev: String,
+  },
   Homedir,
+  Index {
+    message: String,
+    token: Token<'src>,
+  },
   InitExists {
     justfile: PathBuf,
   },
@@ -141,10 +157,22 @@ pub(crate) enum Error<'src> {
     recipe: &'src str,
     io_error: io::Error,
   },
+  LastRunLoad {
+    path: PathBuf,
+    io_error: io::Error,
+  },
+  LastRunParse {
+    path: PathBuf,
+    source: serde_json::Error,
+  },
   Load {
     path: PathBuf,
     io_error: io::Error,
   },
+  LockfileParse {
+    path: PathBuf,
+    source: serde_json::Error,
+  },
   MissingImportFile {
     path: Token<'src>,
   },
@@ -164,6 +192,10 @@ pub(crate) enum Error<'src> {
   NotConfirmed {
     recipe: &'src str,
   },
+  Operator {
+    message: String,
+    operator: Token<'src>,
+  },
   OptionMissingValue {
     recipe: &'src str,
     option: Switch,
@@ -177,6 +209,11 @@ pub(crate) enum Error<'src> {
   RegexCompile {
     source: regex::Error,
   },
+  RenderIo {
+    io_error: io::Error,
+    path: PathBuf,
+    recipe: &'src str,
+  },
   RuntimeDirIo {
     io_error: io::Error,
     path: PathBuf,
@@ -189,6 +226,13 @@ pub(crate) enum Error<'src> {
   Search {
     search_error: SearchError,
   },
+  ServiceExited {
+    recipe: &'src str,
+  },
+  ServiceTimeout {
+    recipe: &'src str,
+    timeout: u64,
+  },
   Shebang {
     argument: Option<String>,
     command: String,
@@ -232,6 +276,9 @@ pub(crate) enum Error<'src> {
     recipe: &'src str,
     io_error: io::Error,
   },
+  Template {
+    message: String,
+  },
   Unknown {
     recipe: &'src str,
     line_number: Option<usize>,
@@ -253,6 +300,10 @@ pub(crate) enum Error<'src> {
   UnstableFeature {
     unstable_feature: UnstableFeature,
   },
+  WorkspaceUnknownRecipe {
+    recipe: String,
+    root: PathBuf,
+  },
   WriteJustfile {
     justfile: PathBuf,
     io_error: io::Error,
@@ -290,11 +341,13 @@ impl<'src> Error<'src> {
         Some(module.token)
       }
       Self::Assert { name, .. } => Some(**name),
-      Self::Backtick { token, .. } => Some(*token),
+      Self::Backtick { token, .. } | Self::Index { token, .. } => Some(*token),
       Self::Compile { compile_error } => Some(compile_error.context()),
       Self::Const { const_error } => Some(const_error.context()),
       Self::FunctionCall { function, .. } => Some(function.token),
+      Self::GitImport { repository, .. } => Some(repository.token),
       Self::MissingImportFile { path } => Some(*path),
+      Self::Operator { operator, .. } => Some(*operator),
       _ => None,
     }
   }
@@ -341,8 +394,8 @@ impl<'src> From<ConstError<'src>> for Error<'src> {
   }
 }
 
-impl From<dotenvy::Error> for Error<'_> {
-  fn from(dotenv_error: dotenvy::Error) -> Self {
+impl From<DotenvError> for Error<'_> {
+  fn from(dotenv_error: DotenvError) -> Self {
     Self::Dotenv { dotenv_error }
   }
 }
@@ -384,6 +437,18 @@ impl ColorDisplay for Error<'_> {
           pattern.original(),
         )?;
       }
+      ArgumentTypeMismatch {
+        argument,
+        parameter,
+        recipe,
+        ty,
+      } => {
+        write!(
+          f,
+          "Argument `{argument}` passed to recipe `{recipe}` parameter `{parameter}` is not {}",
+          ty.expected(),
+        )?;
+      }
       Assert { message, .. } => {
         write!(f, "Assert failed: {message}")?;
       }
@@ -414,6 +479,9 @@ impl ColorDisplay for Error<'_> {
           "Backtick succeeded but stdout was not utf8: {utf8_error}",
         )?,
       },
+      CacheDirectory => {
+        write!(f, "Failed to get cache directory")?;
+      }
       ChooserInvoke {
         shell_binary,
         shell_arguments,
@@ -584,9 +652,21 @@ impl ColorDisplay for Error<'_> {
       GetConfirmation { io_error } => {
         write!(f, "Failed to read confirmation from stdin: {io_error}")?;
       }
+      GitImport {
+        message,
+        repository,
+        rev,
+      } => {
+        let repository = &repository.cooked;
+        write!(
+          f,
+          "Failed to import revision `{rev}` of git repository `{repository}`: {message}"
+        )?;
+      }
       Homedir => {
         write!(f, "Failed to get homedir")?;
       }
+      Index { message, .. } => write!(f, "{message}")?,
       InitExists { justfile } => {
         write!(f, "Justfile `{}` already exists", justfile.display())?;
       }
@@ -616,6 +696,18 @@ impl ColorDisplay for Error<'_> {
           ),
         }?;
       }
+      LastRunLoad { io_error, path } => {
+        let path = path.display();
+        if io_error.kind() == io::ErrorKind::NotFound {
+          write!(f, "No previous run recorded at `{path}`")?;
+        } else {
+          write!(f, "Failed to read previous run from `{path}`: {io_error}")?;
+        }
+      }
+      LastRunParse { path, source } => {
+        let path = path.display();
+        write!(f, "Failed to parse previous run at `{path}`: {source}")?;
+      }
       Load { io_error, path } => {
         write!(
           f,
@@ -623,6 +715,13 @@ impl ColorDisplay for Error<'_> {
           path.display()
         )?;
       }
+      LockfileParse { path, source } => {
+        write!(
+          f,
+          "Failed to parse lockfile at `{}`: {source}",
+          path.display()
+        )?;
+      }
       MissingImportFile { .. } => write!(f, "Could not find source file for import.")?,
       MissingModuleFile { module } => {
         write!(f, "Could not find source file for module `{module}`.")?;
@@ -642,6 +741,10 @@ impl ColorDisplay for Error<'_> {
       NotConfirmed { recipe } => {
         write!(f, "Recipe `{recipe}` was not confirmed")?;
       }
+      Operator { message, operator } => {
+        let operator = operator.lexeme();
+        write!(f, "Operator `{operator}` failed: {message}")?;
+      }
       OptionMissingValue { recipe, option } => {
         write!(f, "Recipe `{recipe}` option `{option}` missing value")?;
       }
@@ -676,6 +779,17 @@ impl ColorDisplay for Error<'_> {
         }
       }
       RegexCompile { source } => write!(f, "{source}")?,
+      RenderIo {
+        io_error,
+        path,
+        recipe,
+      } => {
+        write!(
+          f,
+          "Recipe `{recipe}` could not render template, I/O error at `{}`: {io_error}",
+          path.display(),
+        )?;
+      }
       RuntimeDirIo { io_error, path } => {
         write!(
           f,
@@ -712,6 +826,16 @@ impl ColorDisplay for Error<'_> {
           )?;
         }
       }
+      ServiceExited { recipe } => {
+        write!(f, "Service `{recipe}` exited before becoming ready")?;
+      }
+      ServiceTimeout { recipe, timeout } => {
+        write!(
+          f,
+          "Service `{recipe}` was not ready after {timeout} {}",
+          if *timeout == 1 { "second" } else { "seconds" },
+        )?;
+      }
       Signal {
         recipe,
         line_number,
@@ -768,6 +892,7 @@ impl ColorDisplay for Error<'_> {
           directory or write a file to that directory: {io_error}",
         )?;
       }
+      Template { message } => write!(f, "{message}")?,
       Unknown {
         recipe,
         line_number,
@@ -807,6 +932,10 @@ impl ColorDisplay for Error<'_> {
           "{unstable_feature} Invoke `just` with `--unstable`, set the `JUST_UNSTABLE` environment variable, or add `set unstable` to your `justfile` to enable unstable features.",
         )?;
       }
+      WorkspaceUnknownRecipe { recipe, root } => {
+        let root = root.display();
+        write!(f, "No justfile beneath `{root}` contains recipe `{recipe}`")?;
+      }
       WriteJustfile { justfile, io_error } => {
         let justfile = justfile.display();
         write!(f, "Failed to write justfile to `{justfile}`: {io_error}")?;
@@ -5,6 +5,7 @@ pub(crate) struct Evaluator<'src: 'run, 'run> {
   context: Option<ExecutionContext<'src, 'run>>,
   env: BTreeMap<String, String>,
   is_dependency: bool,
+  locals: BTreeMap<&'src str, Value>,
   non_const_assignments: Table<'src, Name<'src>>,
   scope: Scope<'src, 'run>,
 }
@@ -17,38 +18,56 @@ impl<'src, 'run> Evaluator<'src, 'run> {
     self.context.as_ref().ok_or(const_error)
   }
 
+  /// Bind `overrides`, the `--set` values for the root module or the
+  /// arguments of a submodule, in place of the assignments they override
+  pub(crate) fn bind_overrides(
+    assignments: &Table<'src, Assignment<'src>>,
+    overrides: &BTreeMap<String, String>,
+    scope: &mut Scope<'src, 'run>,
+  ) -> RunResult<'src> {
+    let mut unknown_overrides = Vec::new();
+
+    for (name, value) in overrides {
+      if let Some(assignment) = assignments.get(name) {
+        scope.bind(Binding {
+          export: assignment.export,
+          file_depth: 0,
+          name: assignment.name,
+          prelude: false,
+          private: assignment.private,
+          public: assignment.public,
+          secret: assignment.secret,
+          value: value.clone().into(),
+        });
+      } else {
+        unknown_overrides.push(name.clone());
+      }
+    }
+
+    if !unknown_overrides.is_empty() {
+      return Err(Error::UnknownOverrides {
+        overrides: unknown_overrides,
+      });
+    }
+
+    Ok(())
+  }
+
   pub(crate) fn evaluate_settings(
+    arguments: &BTreeMap<String, String>,
     assignments: &'run Table<'src, Assignment<'src>>,
     config: &Config,
     name: Option<Name>,
     sets: Table<'src, Set<'src>>,
     scope: &'run Scope<'src, 'run>,
+    settings: Settings,
   ) -> RunResult<'src, Settings> {
     let mut scope = scope.child();
 
     if name.is_none() {
-      let mut unknown_overrides = Vec::new();
-
-      for (name, value) in &config.overrides {
-        if let Some(assignment) = assignments.get(name) {
-          scope.bind(Binding {
-            export: assignment.export,
-            file_depth: 0,
-            name: assignment.name,
-            prelude: false,
-            private: assignment.private,
-            value: value.clone(),
-          });
-        } else {
-          unknown_overrides.push(name.clone());
-        }
-      }
-
-      if !unknown_overrides.is_empty() {
-        return Err(Error::UnknownOverrides {
-          overrides: unknown_overrides,
-        });
-      }
+      Self::bind_overrides(assignments, &config.overrides, &mut scope)?;
+    } else {
+      Self::bind_overrides(assignments, arguments, &mut scope)?;
     }
 
     let mut evaluator = Self {
@@ -56,6 +75,7 @@ impl<'src, 'run> Evaluator<'src, 'run> {
       context: None,
       env: BTreeMap::new(),
       is_dependency: false,
+      locals: BTreeMap::new(),
       non_const_assignments: Table::new(),
       scope,
     };
@@ -68,12 +88,16 @@ impl<'src, 'run> Evaluator<'src, 'run> {
       }
     }
 
-    evaluator.evaluate_sets(sets)
+    evaluator.evaluate_sets(sets, settings)
   }
 
-  fn evaluate_sets(&mut self, sets: Table<'src, Set<'src>>) -> RunResult<'src, Settings> {
-    let mut settings = Settings::default();
-
+  /// Apply `sets` to `settings`, which are either the defaults or, for modules
+  /// with `set inherit-settings`, the parent module's settings
+  fn evaluate_sets(
+    &mut self,
+    sets: Table<'src, Set<'src>>,
+    mut settings: Settings,
+  ) -> RunResult<'src, Settings> {
     for (_name, set) in sets {
       match set.value {
         Setting::AllowDuplicateRecipes(value) => {
@@ -89,7 +113,12 @@ impl<'src, 'run> Evaluator<'src, 'run> {
           settings.dotenv_load = value;
         }
         Setting::DotenvPath(value) => {
-          settings.dotenv_path = Some(self.evaluate_expression(&value)?.into());
+          settings.dotenv_path = self
+            .evaluate_value(&value)?
+            .into_elements()
+            .into_iter()
+            .map(|path| path.to_string().into())
+            .collect();
         }
         Setting::DotenvOverride(value) => {
           settings.dotenv_override = value;
@@ -97,6 +126,12 @@ impl<'src, 'run> Evaluator<'src, 'run> {
         Setting::DotenvRequired(value) => {
           settings.dotenv_required = value;
         }
+        Setting::EnvClear(value) => {
+          settings.env_clear = value;
+        }
+        Setting::ExplicitExports(value) => {
+          settings.explicit_exports = value;
+        }
         Setting::Export(value) => {
           settings.export = value;
         }
@@ -106,6 +141,12 @@ impl<'src, 'run> Evaluator<'src, 'run> {
         Setting::IgnoreComments(value) => {
           settings.ignore_comments = value;
         }
+        Setting::InheritSettings(value) => {
+          settings.inherit_settings = value;
+        }
+        Setting::Lazy(value) => {
+          settings.lazy = value;
+        }
         Setting::NoExitMessage(value) => {
           settings.no_exit_message = value;
         }
@@ -120,6 +161,9 @@ impl<'src, 'run> Evaluator<'src, 'run> {
               })?,
           );
         }
+        Setting::ParallelAssignments(value) => {
+          settings.parallel_assignments = value;
+        }
         Setting::PositionalArguments(value) => {
           settings.positional_arguments = value;
         }
@@ -169,10 +213,12 @@ impl<'src, 'run> Evaluator<'src, 'run> {
 
   pub(crate) fn evaluate_assignments(
     config: &'run Config,
+    deferred: &'run ModuleScopes<'src, 'run>,
     dotenv: &'run BTreeMap<String, String>,
     module: &'run Justfile<'src>,
     parent: &'run Scope<'src, 'run>,
     search: &'run Search,
+    secrets: &'run Secrets,
   ) -> RunResult<'src, Scope<'src, 'run>>
   where
     'src: 'run,
@@ -181,34 +227,44 @@ impl<'src, 'run> Evaluator<'src, 'run> {
       config,
       dotenv,
       module,
+      scopes: deferred,
       search,
+      secrets,
     };
 
-    let mut scope = parent.child();
-
-    if !module.is_submodule() {
-      let mut unknown_overrides = Vec::new();
-
-      for (name, value) in &config.overrides {
-        if let Some(assignment) = module.assignments.get(name) {
-          scope.bind(Binding {
-            export: assignment.export,
-            file_depth: 0,
-            name: assignment.name,
-            prelude: false,
-            private: assignment.private,
-            value: value.clone(),
-          });
-        } else {
-          unknown_overrides.push(name.clone());
+    let lazy = module.settings.lazy && !matches!(config.subcommand, Subcommand::Evaluate { .. });
+
+    let mut scope = if lazy {
+      parent.deferred_child()
+    } else {
+      parent.child()
+    };
+
+    if module.is_submodule() {
+      Self::bind_overrides(&module.assignments, &module.arguments, &mut scope)?;
+
+      if let Some((_module, deferred, _dotenv)) = deferred.get(&module.module_path) {
+        for assignment in module.assignments.values() {
+          if let Some(value) = deferred.deferred_value(assignment.name.lexeme()) {
+            scope.bind(Binding {
+              export: assignment.export,
+              file_depth: 0,
+              name: assignment.name,
+              prelude: false,
+              private: assignment.private,
+              public: assignment.public,
+              secret: assignment.secret,
+              value,
+            });
+          }
         }
       }
+    } else {
+      Self::bind_overrides(&module.assignments, &config.overrides, &mut scope)?;
+    }
 
-      if !unknown_overrides.is_empty() {
-        return Err(Error::UnknownOverrides {
-          overrides: unknown_overrides,
-        });
-      }
+    for binding in scope.bindings().filter(|binding| binding.secret) {
+      secrets.register_value(&binding.value);
     }
 
     let mut evaluator = Self {
@@ -216,28 +272,210 @@ impl<'src, 'run> Evaluator<'src, 'run> {
       context: Some(context),
       env: BTreeMap::new(),
       is_dependency: false,
+      locals: BTreeMap::new(),
       non_const_assignments: Table::new(),
       scope,
     };
 
-    for assignment in module.assignments.values() {
-      evaluator.evaluate_assignment(assignment)?;
+    let targets = module
+      .assignments
+      .values()
+      .filter(|assignment| !lazy || assignment.export || module.settings.export)
+      .collect::<Vec<&Assignment>>();
+
+    if module.settings.parallel_assignments {
+      evaluator.evaluate_assignments_concurrently(targets)?;
+    } else {
+      for assignment in targets {
+        evaluator.evaluate_assignment(assignment)?;
+      }
     }
 
     Ok(evaluator.scope)
   }
 
-  fn evaluate_assignment(&mut self, assignment: &Assignment<'src>) -> RunResult<'src, &str> {
+  /// Evaluate `targets` and the assignments they depend on, concurrently
+  /// evaluating assignments whose dependencies have all been evaluated.
+  /// If evaluation fails, return the error that evaluating `targets` one after
+  /// another, in source order, would have encountered first.
+  ///
+  /// Variables referred to by templates passed to `render()` and `template()`
+  /// are only known once the template is rendered, so they aren't treated as
+  /// dependencies, and an assignment which is referred to only by a template
+  /// may be evaluated both by the template and on its own.
+  fn evaluate_assignments_concurrently(
+    &mut self,
+    mut targets: Vec<&'run Assignment<'src>>,
+  ) -> RunResult<'src> {
+    let assignments = self.assignments.unwrap();
+
+    targets.sort_by_key(|assignment| assignment.name.offset);
+
+    let mut pending = BTreeMap::new();
+    let mut stack = targets.clone();
+
+    while let Some(assignment) = stack.pop() {
+      let name = assignment.name.lexeme();
+
+      if self.scope.bound(name) || pending.contains_key(name) {
+        continue;
+      }
+
+      pending.insert(name, assignment);
+
+      stack.extend(
+        Self::dependencies(assignments, assignment)
+          .into_iter()
+          .map(|name| &assignments[name]),
+      );
+    }
+
+    let mut errors = BTreeMap::new();
+
+    loop {
+      let ready = pending
+        .values()
+        .copied()
+        .filter(|assignment| {
+          Self::dependencies(assignments, assignment)
+            .into_iter()
+            .all(|name| self.scope.bound(name))
+        })
+        .collect::<Vec<&Assignment>>();
+
+      if ready.is_empty() {
+        break;
+      }
+
+      let results = if let [assignment] = ready.as_slice() {
+        vec![self.evaluate_value(&assignment.value)]
+      } else {
+        thread::scope(|thread_scope| {
+          let handles = ready
+            .iter()
+            .map(|assignment| {
+              let mut evaluator = Self {
+                assignments: self.assignments,
+                context: self.context,
+                env: BTreeMap::new(),
+                is_dependency: self.is_dependency,
+                locals: BTreeMap::new(),
+                non_const_assignments: Table::new(),
+                scope: self.scope.clone(),
+              };
+              thread_scope.spawn(move || evaluator.evaluate_value(&assignment.value))
+            })
+            .collect::<Vec<_>>();
+
+          handles
+            .into_iter()
+            .map(|handle| {
+              handle
+                .join()
+                .map_err(|_| Error::internal("assignment evaluation thread panicked"))?
+            })
+            .collect()
+        })
+      };
+
+      for (assignment, result) in ready.into_iter().zip(results) {
+        let name = assignment.name.lexeme();
+
+        pending.remove(name);
+
+        match result {
+          Ok(value) => {
+            if assignment.secret {
+              if let Some(context) = self.context {
+                context.secrets.register_value(&value);
+              }
+            }
+            self.scope.bind(Binding {
+              export: assignment.export,
+              file_depth: 0,
+              name: assignment.name,
+              prelude: false,
+              private: assignment.private,
+              public: assignment.public,
+              secret: assignment.secret,
+              value,
+            });
+          }
+          Err(error) => {
+            errors.insert(name, error);
+          }
+        }
+      }
+    }
+
+    if errors.is_empty() {
+      return Ok(());
+    }
+
+    for assignment in targets {
+      if let Some(error) = Self::first_error(assignments, &mut errors, assignment.name.lexeme()) {
+        return Err(error);
+      }
+    }
+
+    Ok(())
+  }
+
+  /// The assignments in `assignments` which `assignment` refers to
+  fn dependencies(
+    assignments: &Table<'src, Assignment<'src>>,
+    assignment: &Assignment<'src>,
+  ) -> Vec<&'src str> {
+    assignment
+      .value
+      .variables()
+      .map(|variable| variable.lexeme())
+      .filter(|name| assignments.contains_key(name))
+      .collect()
+  }
+
+  /// The first error encountered when evaluating `name`, either by `name`
+  /// itself or by one of its dependencies
+  fn first_error(
+    assignments: &Table<'src, Assignment<'src>>,
+    errors: &mut BTreeMap<&'src str, Error<'src>>,
+    name: &'src str,
+  ) -> Option<Error<'src>> {
+    if let Some(error) = errors.remove(name) {
+      return Some(error);
+    }
+
+    Self::dependencies(assignments, &assignments[name])
+      .into_iter()
+      .find_map(|dependency| Self::first_error(assignments, errors, dependency))
+  }
+
+  fn evaluate_assignment(&mut self, assignment: &Assignment<'src>) -> RunResult<'src, &Value> {
     let name = assignment.name.lexeme();
 
     if !self.scope.bound(name) {
-      let value = self.evaluate_expression(&assignment.value)?;
+      let value = if let Some(value) = self
+        .scope
+        .deferring()
+        .and_then(|scope| scope.deferred_value(name))
+      {
+        value
+      } else {
+        self.evaluate_value(&assignment.value)?
+      };
+      if assignment.secret {
+        if let Some(context) = self.context {
+          context.secrets.register_value(&value);
+        }
+      }
       self.scope.bind(Binding {
         export: assignment.export,
         file_depth: 0,
         name: assignment.name,
         prelude: false,
         private: assignment.private,
+        public: assignment.public,
+        secret: assignment.secret,
         value,
       });
     }
@@ -245,6 +483,45 @@ impl<'src, 'run> Evaluator<'src, 'run> {
     Ok(self.scope.value(name).unwrap())
   }
 
+  /// Evaluate the assignment `name` of a module with `set lazy`, in the
+  /// module's scope, memoizing its value and the values of any assignments it
+  /// depends on
+  fn evaluate_deferred_assignment(&self, name: &str) -> RunResult<'src, Option<Value>> {
+    let Some(context) = self.context else {
+      return Ok(None);
+    };
+
+    let Some(assignment) = context.module.assignments.get(name) else {
+      return Ok(None);
+    };
+
+    let Some(scope) = self.scope.parent().and_then(Scope::deferring) else {
+      return Ok(None);
+    };
+
+    if let Some(value) = scope.deferred_value(name) {
+      return Ok(Some(value));
+    }
+
+    let mut evaluator = Self {
+      assignments: Some(&context.module.assignments),
+      context: Some(context),
+      env: BTreeMap::new(),
+      is_dependency: self.is_dependency,
+      locals: BTreeMap::new(),
+      non_const_assignments: Table::new(),
+      scope: scope.child(),
+    };
+
+    let value = evaluator.evaluate_assignment(assignment)?.clone();
+
+    for binding in evaluator.scope.bindings() {
+      scope.defer(binding.name.lexeme(), binding.value.clone());
+    }
+
+    Ok(Some(value))
+  }
+
   fn function_context(&self, thunk: &Thunk<'src>) -> RunResult<'src, function::Context> {
     Ok(function::Context {
       execution_context: self.context(ConstError::FunctionCall(thunk.name()))?,
@@ -258,13 +535,27 @@ impl<'src, 'run> Evaluator<'src, 'run> {
     &mut self,
     expression: &Expression<'src>,
   ) -> RunResult<'src, String> {
+    Ok(self.evaluate_value(expression)?.to_string())
+  }
+
+  pub(crate) fn evaluate_value(&mut self, expression: &Expression<'src>) -> RunResult<'src, Value> {
     match expression {
       Expression::And { lhs, rhs } => {
-        let lhs = self.evaluate_expression(lhs)?;
+        let lhs = self.evaluate_value(lhs)?;
         if lhs.is_empty() {
-          return Ok(String::new());
+          return Ok(String::new().into());
         }
-        self.evaluate_expression(rhs)
+        self.evaluate_value(rhs)
+      }
+      Expression::Arithmetic {
+        lhs,
+        operator,
+        rhs,
+        token,
+      } => {
+        let lhs = self.evaluate_value(lhs)?;
+        let rhs = self.evaluate_value(rhs)?;
+        Self::evaluate_arithmetic(*operator, *token, &lhs, &rhs)
       }
       Expression::Assert {
         condition,
@@ -272,7 +563,7 @@ impl<'src, 'run> Evaluator<'src, 'run> {
         name,
       } => {
         if self.evaluate_condition(condition)? {
-          Ok(String::new())
+          Ok(String::new().into())
         } else {
           Err(Error::Assert {
             message: self.evaluate_expression(error)?,
@@ -284,23 +575,26 @@ impl<'src, 'run> Evaluator<'src, 'run> {
         let context = self.context(ConstError::Backtick(*token))?;
 
         if context.config.dry_run {
-          return Ok(format!("`{contents}`"));
+          return Ok(format!("`{contents}`").into());
         }
 
-        Self::run_command(context, &self.env, &self.scope, contents, &[]).map_err(|output_error| {
-          Error::Backtick {
+        Self::run_command(context, &self.env, &self.scope, contents, &[])
+          .map(Value::from)
+          .map_err(|output_error| Error::Backtick {
             token: *token,
             output_error,
-          }
-        })
+          })
       }
+      Expression::Call {
+        thunk: Thunk::User { args, path },
+      } => self.evaluate_user_function(path, args),
       Expression::Call { thunk } => {
         use Thunk::*;
         match thunk {
-          Nullary { function, .. } => function(self.function_context(thunk)?),
+          Nullary { function, .. } => function(self.function_context(thunk)?).map(Value::from),
           Unary { function, arg, .. } => {
             let arg = self.evaluate_expression(arg)?;
-            function(self.function_context(thunk)?, &arg)
+            function(self.function_context(thunk)?, &arg).map(Value::from)
           }
           UnaryOpt {
             function,
@@ -312,7 +606,7 @@ impl<'src, 'run> Evaluator<'src, 'run> {
               Some(b) => Some(self.evaluate_expression(b)?),
               None => None,
             };
-            function(self.function_context(thunk)?, &a, b.as_deref())
+            function(self.function_context(thunk)?, &a, b.as_deref()).map(Value::from)
           }
           UnaryPlus {
             function,
@@ -324,7 +618,7 @@ impl<'src, 'run> Evaluator<'src, 'run> {
             for arg in rest {
               rest_evaluated.push(self.evaluate_expression(arg)?);
             }
-            function(self.function_context(thunk)?, &a, &rest_evaluated)
+            function(self.function_context(thunk)?, &a, &rest_evaluated).map(Value::from)
           }
           Binary {
             function,
@@ -333,7 +627,7 @@ impl<'src, 'run> Evaluator<'src, 'run> {
           } => {
             let a = self.evaluate_expression(a)?;
             let b = self.evaluate_expression(b)?;
-            function(self.function_context(thunk)?, &a, &b)
+            function(self.function_context(thunk)?, &a, &b).map(Value::from)
           }
           BinaryPlus {
             function,
@@ -346,7 +640,7 @@ impl<'src, 'run> Evaluator<'src, 'run> {
             for arg in rest {
               rest_evaluated.push(self.evaluate_expression(arg)?);
             }
-            function(self.function_context(thunk)?, &a, &b, &rest_evaluated)
+            function(self.function_context(thunk)?, &a, &b, &rest_evaluated).map(Value::from)
           }
           Ternary {
             function,
@@ -356,18 +650,53 @@ impl<'src, 'run> Evaluator<'src, 'run> {
             let a = self.evaluate_expression(a)?;
             let b = self.evaluate_expression(b)?;
             let c = self.evaluate_expression(c)?;
-            function(self.function_context(thunk)?, &a, &b, &c)
+            function(self.function_context(thunk)?, &a, &b, &c).map(Value::from)
+          }
+          UnaryValue { function, arg, .. } => {
+            let arg = self.evaluate_value(arg)?;
+            function(self.function_context(thunk)?, &arg)
+          }
+          UnaryOptValue {
+            function,
+            args: (a, b),
+            ..
+          } => {
+            let a = self.evaluate_value(a)?;
+            let b = match b.as_ref() {
+              Some(b) => Some(self.evaluate_value(b)?),
+              None => None,
+            };
+            function(self.function_context(thunk)?, &a, b.as_ref())
+          }
+          BinaryValue {
+            function,
+            args: [a, b],
+            ..
+          } => {
+            let a = self.evaluate_value(a)?;
+            let b = self.evaluate_value(b)?;
+            function(self.function_context(thunk)?, &a, &b)
           }
+          User { .. } => unreachable!(),
         }
         .map_err(|message| Error::FunctionCall {
           function: thunk.name(),
           message,
         })
       }
-      Expression::Concatenation { lhs, rhs } => {
-        let lhs = self.evaluate_expression(lhs)?;
-        let rhs = self.evaluate_expression(rhs)?;
-        Ok(lhs + &rhs)
+      Expression::Concatenation { lhs, rhs, token } => {
+        let lhs = self.evaluate_value(lhs)?;
+        let rhs = self.evaluate_value(rhs)?;
+        match (lhs, rhs) {
+          (Value::List(mut lhs), Value::List(rhs)) => {
+            lhs.extend(rhs);
+            Ok(Value::List(lhs))
+          }
+          (lhs, rhs) if Self::is_arithmetic(&lhs, &rhs) => {
+            Self::evaluate_arithmetic(ArithmeticOperator::Sum, *token, &lhs, &rhs)
+          }
+          (lhs, rhs) => Ok(format!("{lhs}{rhs}").into()),
+        }
       }
       Expression::Conditional {
         condition,
@@ -375,11 +704,32 @@ impl<'src, 'run> Evaluator<'src, 'run> {
         otherwise,
       } => {
         if self.evaluate_condition(condition)? {
-          self.evaluate_expression(then)
+          self.evaluate_value(then)
         } else {
-          self.evaluate_expression(otherwise)
+          self.evaluate_value(otherwise)
         }
       }
+      Expression::Field { name, value } => {
+        let value = self.evaluate_value(value)?;
+        Self::evaluate_index(value, &name.lexeme().into(), name.token)
+      }
+      Expression::Filter {
+        condition,
+        list,
+        name,
+      } => {
+        let mut filtered = Vec::new();
+
+        for element in self.evaluate_value(list)?.into_elements() {
+          if self.with_bindings(&[(*name, element.clone())], |evaluator| {
+            evaluator.evaluate_condition(condition)
+          })? {
+            filtered.push(element);
+          }
+        }
+
+        Ok(Value::List(filtered))
+      }
       Expression::FormatString { start, expressions } => {
         let mut value = start.cooked.clone();
 
@@ -389,40 +739,99 @@ impl<'src, 'run> Evaluator<'src, 'run> {
         }
 
         if start.kind.indented {
-          Ok(unindent(&value))
+          Ok(unindent(&value).into())
         } else {
-          Ok(value)
+          Ok(value.into())
         }
       }
-      Expression::Group { contents } => self.evaluate_expression(contents),
-      Expression::Join { lhs: None, rhs } => Ok("/".to_string() + &self.evaluate_expression(rhs)?),
+      Expression::Group { contents } => self.evaluate_value(contents),
+      Expression::Index {
+        index,
+        token,
+        value,
+      } => {
+        let value = self.evaluate_value(value)?;
+        let index = self.evaluate_value(index)?;
+        Self::evaluate_index(value, &index, *token)
+      }
+      Expression::Integer { value, .. } => Ok(Value::Integer(*value)),
+      Expression::Join { lhs: None, rhs, .. } => {
+        Ok(("/".to_string() + &self.evaluate_expression(rhs)?).into())
+      }
       Expression::Join {
         lhs: Some(lhs),
         rhs,
+        token,
       } => {
-        let lhs = self.evaluate_expression(lhs)?;
-        let rhs = self.evaluate_expression(rhs)?;
-        Ok(lhs + "/" + &rhs)
+        let lhs = self.evaluate_value(lhs)?;
+        let rhs = self.evaluate_value(rhs)?;
+        match (lhs, rhs) {
+          (lhs, rhs) if Self::is_arithmetic(&lhs, &rhs) => {
+            Self::evaluate_arithmetic(ArithmeticOperator::Quotient, *token, &lhs, &rhs)
+          }
+          (lhs, rhs) => Ok(format!("{lhs}/{rhs}").into()),
+        }
+      }
+      Expression::List { elements } => {
+        let mut values = Vec::new();
+        for element in elements {
+          values.push(self.evaluate_value(element)?);
+        }
+        Ok(Value::List(values))
       }
+      Expression::For { body, list, name } | Expression::Map { body, list, name } => {
+        let mut mapped = Vec::new();
+
+        for element in self.evaluate_value(list)?.into_elements() {
+          mapped.push(self.with_bindings(&[(*name, element)], |evaluator| {
+            evaluator.evaluate_value(body)
+          })?);
+        }
+
+        Ok(Value::List(mapped))
+      }
+      Expression::Match { arms, value } => {
+        let value = self.evaluate_expression(value)?;
+
+        for arm in arms {
+          if let Some(bindings) = arm.pattern.is_match(&value) {
+            return self.with_bindings(&bindings, |evaluator| evaluator.evaluate_value(&arm.body));
+          }
+        }
+
+        Err(Error::internal(format!(
+          "match expression has no arm matching `{value}`"
+        )))
+      }
+      Expression::ModuleVariable { path } => self.evaluate_module_variable(path),
       Expression::Or { lhs, rhs } => {
-        let lhs = self.evaluate_expression(lhs)?;
+        let lhs = self.evaluate_value(lhs)?;
         if !lhs.is_empty() {
           return Ok(lhs);
         }
-        self.evaluate_expression(rhs)
+        self.evaluate_value(rhs)
       }
-      Expression::StringLiteral { string_literal } => Ok(string_literal.cooked.clone()),
+      Expression::StringLiteral { string_literal } => Ok(string_literal.cooked.clone().into()),
       Expression::Variable { name, .. } => {
         let variable = name.lexeme();
-        if let Some(value) = self.scope.value(variable) {
-          Ok(value.to_owned())
+        if let Some(value) = self.locals.get(variable) {
+          Ok(value.clone())
+        } else if let Some(value) = self.scope.value(variable) {
+          Ok(value.clone())
         } else if self.non_const_assignments.contains_key(name.lexeme()) {
           Err(ConstError::Variable(*name).into())
         } else if let Some(assignment) = self
           .assignments
           .and_then(|assignments| assignments.get(variable))
         {
-          Ok(self.evaluate_assignment(assignment)?.to_owned())
+          // Assignments don't see the bindings of enclosing lambdas, loops,
+          // and match arms
+          let locals = mem::take(&mut self.locals);
+          let value = self.evaluate_assignment(assignment).cloned();
+          self.locals = locals;
+          value
+        } else if let Some(value) = self.evaluate_deferred_assignment(variable)? {
+          Ok(value)
         } else {
           Err(Error::internal(format!(
             "attempted to evaluate undefined variable `{variable}`"
@@ -432,12 +841,217 @@ impl<'src, 'run> Evaluator<'src, 'run> {
     }
   }
 
+  /// Evaluate `f` with each name in `bindings` bound to its value, visible
+  /// only to expressions evaluated by `f` and not to the assignments they
+  /// refer to, restoring any previous bindings afterwards
+  pub(crate) fn with_bindings<T>(
+    &mut self,
+    bindings: &[(Name<'src>, Value)],
+    f: impl FnOnce(&mut Self) -> RunResult<'src, T>,
+  ) -> RunResult<'src, T> {
+    let mut previous = Vec::new();
+
+    for (name, value) in bindings {
+      previous.push((
+        name.lexeme(),
+        self.locals.insert(name.lexeme(), value.clone()),
+      ));
+    }
+
+    let result = f(self);
+
+    for (name, previous) in previous.into_iter().rev() {
+      if let Some(previous) = previous {
+        self.locals.insert(name, previous);
+      } else {
+        self.locals.remove(name);
+      }
+    }
+
+    result
+  }
+
+  /// Evaluate the body of the user-defined function at `path`, in a scope
+  /// containing only its parameters and constants
+  fn evaluate_user_function(
+    &mut self,
+    path: &Namepath<'src>,
+    args: &[Expression<'src>],
+  ) -> RunResult<'src, Value> {
+    let context = *self.context(ConstError::FunctionCall(*path.last()))?;
+
+    let Some((module, function)) = context.module.function(path) else {
+      return Err(Error::internal(format!(
+        "attempted to call undefined function `{path}`"
+      )));
+    };
+
+    let mut scope = Scope::root();
+
+    for (parameter, arg) in function.parameters.iter().zip(args) {
+      scope.bind(Binding {
+        export: false,
+        file_depth: 0,
+        name: *parameter,
+        prelude: false,
+        private: false,
+        public: false,
+        secret: false,
+        value: self.evaluate_value(arg)?,
+      });
+    }
+
+    let context = ExecutionContext { module, ..context };
+
+    Evaluator::new(&context, BTreeMap::new(), self.is_dependency, &scope)
+      .evaluate_value(&function.body)
+  }
+
+  /// Evaluate variable `path` in the scope of the submodule which defines it,
+  /// memoizing its value and the values of any assignments it depends on, so
+  /// that each is evaluated at most once
+  fn evaluate_module_variable(&self, path: &Namepath<'src>) -> RunResult<'src, Value> {
+    let context = *self.context(ConstError::Variable(*path.last()))?;
+
+    let (name, components) = path.split_last();
+
+    let mut module = context.module;
+
+    for component in components {
+      module = module
+        .modules
+        .get(component.lexeme())
+        .ok_or_else(|| Error::UnknownSubmodule {
+          path: format!("{}::{}", module.module_path, component.lexeme())
+            .trim_start_matches("::")
+            .into(),
+        })?;
+    }
+
+    let Some(assignment) = module.assignments.get(name.lexeme()) else {
+      return Err(Error::EvalUnknownVariable {
+        suggestion: module.suggest_variable(name.lexeme()),
+        variable: path.to_string(),
+      });
+    };
+
+    let Some((module, scope, dotenv)) = context.scopes.get(&module.module_path) else {
+      return Err(Error::internal(format!(
+        "failed to retrieve scope for module `{}`",
+        module.module_path,
+      )));
+    };
+
+    if scope.bound(name.lexeme()) {
+      return Ok(scope.value(name.lexeme()).unwrap().clone());
+    }
+
+    if let Some(value) = scope.deferred_value(name.lexeme()) {
+      return Ok(value);
+    }
+
+    let mut evaluator = Evaluator {
+      assignments: Some(&module.assignments),
+      context: Some(ExecutionContext {
+        dotenv,
+        module,
+        ..context
+      }),
+      env: BTreeMap::new(),
+      is_dependency: self.is_dependency,
+      locals: BTreeMap::new(),
+      non_const_assignments: Table::new(),
+      scope: scope.child(),
+    };
+
+    let value = evaluator.evaluate_assignment(assignment)?.clone();
+
+    for binding in evaluator.scope.bindings() {
+      scope.defer(binding.name.lexeme(), binding.value.clone());
+    }
+
+    Ok(value)
+  }
+
+  fn evaluate_arithmetic(
+    operator: ArithmeticOperator,
+    token: Token<'src>,
+    lhs: &Value,
+    rhs: &Value,
+  ) -> RunResult<'src, Value> {
+    operator
+      .apply(Self::integer(token, lhs)?, Self::integer(token, rhs)?)
+      .map(Value::Integer)
+      .map_err(|message| Error::Operator {
+        message,
+        operator: token,
+      })
+  }
+
+  /// The entry of map `value` with key `index`, or the element of list
+  /// `value` at position `index`
+  fn evaluate_index(value: Value, index: &Value, token: Token<'src>) -> RunResult<'src, Value> {
+    let message = match value {
+      Value::List(mut elements) => match index.integer() {
+        Some(i) => match usize::try_from(i) {
+          Ok(i) if i < elements.len() => return Ok(elements.swap_remove(i)),
+          _ => format!(
+            "Index {i} out of range for list of length {}",
+            elements.len()
+          ),
+        },
+        None => format!("List index `{index}` is not an integer"),
+      },
+      Value::Map(mut entries) => {
+        let key = index.to_string();
+        match entries.remove(&key) {
+          Some(value) => return Ok(value),
+          None => format!("Key `{key}` not found"),
+        }
+      }
+      Value::Integer(_) | Value::String(_) => {
+        format!("Cannot index `{value}`, which is not a list or map")
+      }
+    };
+
+    Err(Error::Index { message, token })
+  }
+
+  /// The operand `value` of `operator` as an integer
+  /// Whether `+` or `/` should add or divide `lhs` and `rhs`, rather than
+  /// concatenate or join them, which they do if one is an integer and the
+  /// other is an integer or a string which contains one
+  fn is_arithmetic(lhs: &Value, rhs: &Value) -> bool {
+    (matches!(lhs, Value::Integer(_)) || matches!(rhs, Value::Integer(_)))
+      && lhs.integer().is_some()
+      && rhs.integer().is_some()
+  }
+
+  fn integer(operator: Token<'src>, value: &Value) -> RunResult<'src, i64> {
+    value.integer().ok_or_else(|| Error::Operator {
+      message: format!("Expected integer but found `{value}`"),
+      operator,
+    })
+  }
+
   fn evaluate_condition(&mut self, condition: &Condition<'src>) -> RunResult<'src, bool> {
-    let lhs_value = self.evaluate_expression(&condition.lhs)?;
-    let rhs_value = self.evaluate_expression(&condition.rhs)?;
+    let lhs_value = self.evaluate_value(&condition.lhs)?;
+    let rhs_value = self.evaluate_value(&condition.rhs)?;
+    let ordering = || -> RunResult<'src, Ordering> {
+      Ok(
+        Self::integer(condition.token, &lhs_value)?
+          .cmp(&Self::integer(condition.token, &rhs_value)?),
+      )
+    };
+    let lhs_value = lhs_value.to_string();
+    let rhs_value = rhs_value.to_string();
     let condition = match condition.operator {
       ConditionalOperator::Equality => lhs_value == rhs_value,
+      ConditionalOperator::GreaterThan => ordering()?.is_gt(),
+      ConditionalOperator::GreaterThanOrEqual => ordering()?.is_ge(),
       ConditionalOperator::Inequality => lhs_value != rhs_value,
+      ConditionalOperator::LessThan => ordering()?.is_lt(),
+      ConditionalOperator::LessThanOrEqual => ordering()?.is_le(),
       ConditionalOperator::RegexMatch => Regex::new(&rhs_value)
         .map_err(|source| Error::RegexCompile { source })?
         .is_match(&lhs_value),
@@ -457,6 +1071,10 @@ impl<'src, 'run> Evaluator<'src, 'run> {
   ) -> Result<String, OutputError> {
     let mut cmd = context.module.settings.shell_command(context.config);
 
+    if context.module.settings.env_clear {
+      cmd.clear_env_except(&[]);
+    }
+
     cmd
       .arg(command)
       .args(args)
@@ -509,6 +1127,25 @@ impl<'src, 'run> Evaluator<'src, 'run> {
     Ok(evaluated)
   }
 
+  /// Evaluate `template`. Templates rendered while evaluating assignments may
+  /// refer to assignments which have not yet been evaluated, so evaluate them
+  /// on demand, unless the module defers evaluating assignments, in which case
+  /// they are already evaluated on demand.
+  pub(crate) fn evaluate_template(
+    context: &ExecutionContext<'src, 'run>,
+    is_dependency: bool,
+    scope: &'run Scope<'src, 'run>,
+    template: &Template<'src>,
+  ) -> RunResult<'src, String> {
+    let mut evaluator = Self::new(context, BTreeMap::new(), is_dependency, scope);
+
+    if scope.deferring().is_none() {
+      evaluator.assignments = Some(&context.module.assignments);
+    }
+
+    evaluator.evaluate_line(&template.line, false)
+  }
+
   pub(crate) fn evaluate_parameters(
     arguments: &[Vec<String>],
     context: &ExecutionContext<'src, 'run>,
@@ -564,6 +1201,11 @@ impl<'src, 'run> Evaluator<'src, 'run> {
 
       for value in &values {
         parameter.check_pattern_match(recipe, value)?;
+        parameter.check_type(recipe, value, &context.config.invocation_directory)?;
+
+        if parameter.secret {
+          context.secrets.register(value);
+        }
       }
 
       evaluator.scope.bind(Binding {
@@ -572,7 +1214,13 @@ impl<'src, 'run> Evaluator<'src, 'run> {
         name: parameter.name,
         prelude: false,
         private: false,
-        value: values.join(" "),
+        public: false,
+        secret: parameter.secret,
+        value: if parameter.kind.is_variadic() {
+          Value::List(values.into_iter().map(Value::from).collect())
+        } else {
+          values.join(" ").into()
+        },
       });
     }
 
@@ -590,6 +1238,7 @@ impl<'src, 'run> Evaluator<'src, 'run> {
       context: Some(*context),
       env,
       is_dependency,
+      locals: BTreeMap::new(),
       non_const_assignments: Table::new(),
       scope: scope.child(),
     }
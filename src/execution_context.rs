@@ -5,7 +5,9 @@ pub(crate) struct ExecutionContext<'src: 'run, 'run> {
   pub(crate) config: &'run Config,
   pub(crate) dotenv: &'run BTreeMap<String, String>,
   pub(crate) module: &'run Justfile<'src>,
+  pub(crate) scopes: &'run ModuleScopes<'src, 'run>,
   pub(crate) search: &'run Search,
+  pub(crate) secrets: &'run Secrets,
 }
 
 impl<'src: 'run, 'run> ExecutionContext<'src, 'run> {
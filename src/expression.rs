@@ -10,6 +10,13 @@ use super::*;
 pub(crate) enum Expression<'src> {
   /// `lhs && rhs`
   And { lhs: Box<Self>, rhs: Box<Self> },
+  /// `lhs - rhs`, `lhs * rhs`, or `lhs % rhs`
+  Arithmetic {
+    lhs: Box<Self>,
+    operator: ArithmeticOperator,
+    rhs: Box<Self>,
+    token: Token<'src>,
+  },
   /// `assert(condition, error)`
   Assert {
     name: Name<'src>,
@@ -24,13 +31,31 @@ pub(crate) enum Expression<'src> {
   /// `name(arguments)`
   Call { thunk: Thunk<'src> },
   /// `lhs + rhs`
-  Concatenation { lhs: Box<Self>, rhs: Box<Self> },
+  Concatenation {
+    lhs: Box<Self>,
+    rhs: Box<Self>,
+    token: Token<'src>,
+  },
   /// `if condition { then } else { otherwise }`
   Conditional {
     condition: Condition<'src>,
     then: Box<Self>,
     otherwise: Box<Self>,
   },
+  /// `value.name`
+  Field { name: Name<'src>, value: Box<Self> },
+  /// `filter(list, name => condition)`
+  Filter {
+    condition: Condition<'src>,
+    list: Box<Self>,
+    name: Name<'src>,
+  },
+  /// `for name in list { body }`
+  For {
+    body: Box<Self>,
+    list: Box<Self>,
+    name: Name<'src>,
+  },
   // `f"format string"`
   FormatString {
     start: StringLiteral<'src>,
@@ -38,11 +63,35 @@ pub(crate) enum Expression<'src> {
   },
   /// `(contents)`
   Group { contents: Box<Self> },
+  /// `value[index]`
+  Index {
+    index: Box<Self>,
+    token: Token<'src>,
+    value: Box<Self>,
+  },
+  /// `123`
+  Integer { token: Token<'src>, value: i64 },
   /// `lhs / rhs`
   Join {
     lhs: Option<Box<Self>>,
     rhs: Box<Self>,
+    token: Token<'src>,
+  },
+  /// `[element, …]`
+  List { elements: Vec<Self> },
+  /// `map(list, name => body)`
+  Map {
+    body: Box<Self>,
+    list: Box<Self>,
+    name: Name<'src>,
+  },
+  /// `match value { pattern => body, … }`
+  Match {
+    arms: Vec<MatchArm<'src>>,
+    value: Box<Self>,
   },
+  /// `module::variable`
+  ModuleVariable { path: Namepath<'src> },
   /// `lhs || rhs`
   Or { lhs: Box<Self>, rhs: Box<Self> },
   /// `"string_literal"` or `'string_literal'`
@@ -52,6 +101,16 @@ pub(crate) enum Expression<'src> {
 }
 
 impl<'src> Expression<'src> {
+  pub(crate) fn calls<'expression>(&'expression self) -> Calls<'expression, 'src> {
+    Calls::new(self)
+  }
+
+  pub(crate) fn module_variables<'expression>(
+    &'expression self,
+  ) -> ModuleVariables<'expression, 'src> {
+    ModuleVariables::new(self)
+  }
+
   pub(crate) fn variables<'expression>(&'expression self) -> Variables<'expression, 'src> {
     Variables::new(self)
   }
@@ -61,12 +120,17 @@ impl Display for Expression<'_> {
   fn fmt(&self, f: &mut Formatter) -> fmt::Result {
     match self {
       Self::And { lhs, rhs } => write!(f, "{lhs} && {rhs}"),
+      Self::Arithmetic {
+        lhs, operator, rhs, ..
+      } => write!(f, "{lhs} {operator} {rhs}"),
       Self::Assert {
         condition, error, ..
       } => write!(f, "assert({condition}, {error})"),
-      Self::Backtick { token, .. } => write!(f, "{}", token.lexeme()),
+      Self::Backtick { token, .. } | Self::Integer { token, .. } => {
+        write!(f, "{}", token.lexeme())
+      }
       Self::Call { thunk } => write!(f, "{thunk}"),
-      Self::Concatenation { lhs, rhs } => write!(f, "{lhs} + {rhs}"),
+      Self::Concatenation { lhs, rhs, .. } => write!(f, "{lhs} + {rhs}"),
       Self::Conditional {
         condition,
         then,
@@ -78,6 +142,15 @@ impl Display for Expression<'_> {
           write!(f, "if {condition} {{ {then} }} else {{ {otherwise} }}")
         }
       }
+      Self::Field { name, value } => write!(f, "{value}.{}", name.lexeme()),
+      Self::Filter {
+        condition,
+        list,
+        name,
+      } => write!(f, "filter({list}, {} => {condition})", name.lexeme()),
+      Self::For { body, list, name } => {
+        write!(f, "for {} in {list} {{ {body} }}", name.lexeme())
+      }
       Self::FormatString { start, expressions } => {
         write!(f, "{start}")?;
 
@@ -88,11 +161,35 @@ impl Display for Expression<'_> {
         Ok(())
       }
       Self::Group { contents } => write!(f, "({contents})"),
-      Self::Join { lhs: None, rhs } => write!(f, "/ {rhs}"),
+      Self::Index { index, value, .. } => write!(f, "{value}[{index}]"),
+      Self::Join { lhs: None, rhs, .. } => write!(f, "/ {rhs}"),
       Self::Join {
         lhs: Some(lhs),
         rhs,
+        ..
       } => write!(f, "{lhs} / {rhs}"),
+      Self::List { elements } => {
+        write!(f, "[")?;
+        for (i, element) in elements.iter().enumerate() {
+          if i > 0 {
+            write!(f, ", ")?;
+          }
+          write!(f, "{element}")?;
+        }
+        write!(f, "]")
+      }
+      Self::Map { body, list, name } => write!(f, "map({list}, {} => {body})", name.lexeme()),
+      Self::Match { arms, value } => {
+        write!(f, "match {value} {{ ")?;
+        for (i, arm) in arms.iter().enumerate() {
+          if i > 0 {
+            write!(f, ", ")?;
+          }
+          write!(f, "{arm}")?;
+        }
+        write!(f, " }}")
+      }
+      Self::ModuleVariable { path } => write!(f, "{path}"),
       Self::Or { lhs, rhs } => write!(f, "{lhs} || {rhs}"),
       Self::StringLiteral { string_literal } => write!(f, "{string_literal}"),
       Self::Variable { name } => write!(f, "{}", name.lexeme()),
@@ -113,6 +210,15 @@ impl Serialize for Expression<'_> {
         seq.serialize_element(rhs)?;
         seq.end()
       }
+      Self::Arithmetic {
+        lhs, operator, rhs, ..
+      } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element(&operator.to_string())?;
+        seq.serialize_element(lhs)?;
+        seq.serialize_element(rhs)?;
+        seq.end()
+      }
       Self::Assert {
         condition, error, ..
       } => {
@@ -129,7 +235,7 @@ impl Serialize for Expression<'_> {
         seq.end()
       }
       Self::Call { thunk } => thunk.serialize(serializer),
-      Self::Concatenation { lhs, rhs } => {
+      Self::Concatenation { lhs, rhs, .. } => {
         let mut seq = serializer.serialize_seq(None)?;
         seq.serialize_element("concatenate")?;
         seq.serialize_element(lhs)?;
@@ -148,6 +254,33 @@ impl Serialize for Expression<'_> {
         seq.serialize_element(otherwise)?;
         seq.end()
       }
+      Self::Field { name, value } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("index")?;
+        seq.serialize_element(value)?;
+        seq.serialize_element(name)?;
+        seq.end()
+      }
+      Self::Filter {
+        condition,
+        list,
+        name,
+      } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("filter")?;
+        seq.serialize_element(list)?;
+        seq.serialize_element(name)?;
+        seq.serialize_element(condition)?;
+        seq.end()
+      }
+      Self::For { body, list, name } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("for")?;
+        seq.serialize_element(list)?;
+        seq.serialize_element(name)?;
+        seq.serialize_element(body)?;
+        seq.end()
+      }
       Self::FormatString { start, expressions } => {
         let mut seq = serializer.serialize_seq(None)?;
         seq.serialize_element("format")?;
@@ -159,13 +292,52 @@ impl Serialize for Expression<'_> {
         seq.end()
       }
       Self::Group { contents } => contents.serialize(serializer),
-      Self::Join { lhs, rhs } => {
+      Self::Index { index, value, .. } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("index")?;
+        seq.serialize_element(value)?;
+        seq.serialize_element(index)?;
+        seq.end()
+      }
+      Self::Integer { value, .. } => serializer.serialize_i64(*value),
+      Self::Join { lhs, rhs, .. } => {
         let mut seq = serializer.serialize_seq(None)?;
         seq.serialize_element("join")?;
         seq.serialize_element(lhs)?;
         seq.serialize_element(rhs)?;
         seq.end()
       }
+      Self::List { elements } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("list")?;
+        for element in elements {
+          seq.serialize_element(element)?;
+        }
+        seq.end()
+      }
+      Self::Map { body, list, name } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("map")?;
+        seq.serialize_element(list)?;
+        seq.serialize_element(name)?;
+        seq.serialize_element(body)?;
+        seq.end()
+      }
+      Self::Match { arms, value } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("match")?;
+        seq.serialize_element(value)?;
+        for arm in arms {
+          seq.serialize_element(arm)?;
+        }
+        seq.end()
+      }
+      Self::ModuleVariable { path } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("variable")?;
+        seq.serialize_element(path)?;
+        seq.end()
+      }
       Self::Or { lhs, rhs } => {
         let mut seq = serializer.serialize_seq(None)?;
         seq.serialize_element("or")?;
@@ -18,6 +18,9 @@ pub(crate) enum Function {
   Binary(fn(Context, &str, &str) -> FunctionResult),
   BinaryPlus(fn(Context, &str, &str, &[String]) -> FunctionResult),
   Ternary(fn(Context, &str, &str, &str) -> FunctionResult),
+  UnaryValue(fn(Context, &Value) -> ValueResult),
+  UnaryOptValue(fn(Context, &Value, Option<&Value>) -> ValueResult),
+  BinaryValue(fn(Context, &Value, &Value) -> ValueResult),
 }
 
 pub(crate) struct Context<'src: 'run, 'run> {
@@ -42,6 +45,8 @@ pub(crate) fn get(name: &str) -> Option<Function> {
     "arch" => Nullary(arch),
     "blake3" => Unary(blake3),
     "blake3_file" => Unary(blake3_file),
+    "blake3_glob" => UnaryOptValue(blake3_glob),
+    "bool" => Unary(bool),
     "cache_directory" => Nullary(|_| dir("cache", dirs::cache_dir)),
     "canonicalize" => Unary(canonicalize),
     "capitalize" => Unary(capitalize),
@@ -62,16 +67,22 @@ pub(crate) fn get(name: &str) -> Option<Function> {
     "extension" => Unary(extension),
     "file_name" => Unary(file_name),
     "file_stem" => Unary(file_stem),
+    "glob" => UnaryOptValue(glob),
     "home_directory" => Nullary(|_| dir("home", dirs::home_dir)),
+    "int" => UnaryValue(int),
     "invocation_directory" => Nullary(invocation_directory),
     "invocation_directory_native" => Nullary(invocation_directory_native),
     "is_dependency" => Nullary(is_dependency),
     "join" => BinaryPlus(join),
+    "join_list" => BinaryValue(join_list),
+    "json_encode" => UnaryValue(json_encode),
+    "json_parse" => UnaryValue(json_parse),
     "just_executable" => Nullary(just_executable),
     "just_pid" => Nullary(just_pid),
     "justfile" => Nullary(justfile),
     "justfile_directory" => Nullary(justfile_directory),
     "kebabcase" => Unary(kebabcase),
+    "len" => UnaryValue(len),
     "lowercamelcase" => Unary(lowercamelcase),
     "lowercase" => Unary(lowercase),
     "module_directory" => Nullary(module_directory),
@@ -82,11 +93,13 @@ pub(crate) fn get(name: &str) -> Option<Function> {
     "parent_directory" => Unary(parent_directory),
     "path_exists" => Unary(path_exists),
     "prepend" => Binary(prepend),
-    "quote" => Unary(quote),
+    "quote" => UnaryValue(quote),
     "read" => Unary(read),
+    "render" => Unary(render),
     "replace" => Ternary(replace),
     "replace_regex" => Ternary(replace_regex),
     "require" => Unary(require),
+    "secret" => Unary(secret),
     "semver_matches" => Binary(semver_matches),
     "sha256" => Unary(sha256),
     "sha256_file" => Unary(sha256_file),
@@ -96,8 +109,11 @@ pub(crate) fn get(name: &str) -> Option<Function> {
     "snakecase" => Unary(snakecase),
     "source_directory" => Nullary(source_directory),
     "source_file" => Nullary(source_file),
+    "split" => BinaryValue(split),
     "style" => Unary(style),
+    "template" => Unary(template),
     "titlecase" => Unary(titlecase),
+    "toml_parse" => UnaryValue(toml_parse),
     "trim" => Unary(trim),
     "trim_end" => Unary(trim_end),
     "trim_end_match" => Binary(trim_end_match),
@@ -108,6 +124,7 @@ pub(crate) fn get(name: &str) -> Option<Function> {
     "uppercamelcase" => Unary(uppercamelcase),
     "uppercase" => Unary(uppercase),
     "uuid" => Nullary(uuid),
+    "walk" => UnaryOptValue(walk),
     "which" => Unary(which),
     "without_extension" => Unary(without_extension),
     _ => return None,
@@ -119,10 +136,10 @@ impl Function {
   pub(crate) fn argc(&self) -> RangeInclusive<usize> {
     match *self {
       Nullary(_) => 0..=0,
-      Unary(_) => 1..=1,
-      UnaryOpt(_) => 1..=2,
+      Unary(_) | UnaryValue(_) => 1..=1,
+      UnaryOpt(_) | UnaryOptValue(_) => 1..=2,
       UnaryPlus(_) => 1..=usize::MAX,
-      Binary(_) => 2..=2,
+      Binary(_) | BinaryValue(_) => 2..=2,
       BinaryPlus(_) => 2..=usize::MAX,
       Ternary(_) => 3..=3,
     }
@@ -170,6 +187,37 @@ fn blake3_file(context: Context, path: &str) -> FunctionResult {
   Ok(hasher.finalize().to_string())
 }
 
+fn blake3_glob(context: Context, pattern: &Value, gitignore: Option<&Value>) -> ValueResult {
+  let working_directory = context.execution_context.working_directory();
+
+  let glob = Glob::new(&working_directory, &pattern.to_string()).map_err(|err| err.to_string())?;
+
+  let mut hasher = blake3::Hasher::new();
+
+  for (path, relative) in glob_paths(&working_directory, &glob, gitignore)? {
+    let mut file = blake3::Hasher::new();
+    file
+      .update_mmap_rayon(&path)
+      .map_err(|err| format!("Failed to hash `{}`: {err}", path.display()))?;
+    hasher.update(relative.as_bytes());
+    hasher.update(&[0]);
+    hasher.update(file.finalize().as_bytes());
+  }
+
+  Ok(hasher.finalize().to_string().into())
+}
+
+fn bool(_context: Context, s: &str) -> FunctionResult {
+  match s.trim() {
+    "true" => Ok("true".into()),
+    "false" => Ok("false".into()),
+    s => s
+      .parse::<i64>()
+      .map(|integer| (integer != 0).to_string())
+      .map_err(|_| format!("`{s}` is not a boolean or an integer")),
+  }
+}
+
 fn canonicalize(context: Context, path: &str) -> FunctionResult {
   let canonical = std::fs::canonicalize(context.execution_context.working_directory().join(path))
     .map_err(|err| format!("I/O error canonicalizing path: {err}"))?;
@@ -326,6 +374,54 @@ fn file_stem(_context: Context, path: &str) -> FunctionResult {
     .ok_or_else(|| format!("Could not extract file stem from `{path}`"))
 }
 
+fn glob(context: Context, pattern: &Value, gitignore: Option<&Value>) -> ValueResult {
+  let working_directory = context.execution_context.working_directory();
+
+  let glob = Glob::new(&working_directory, &pattern.to_string()).map_err(|err| err.to_string())?;
+
+  Ok(Value::List(
+    glob_paths(&working_directory, &glob, gitignore)?
+      .into_iter()
+      .map(|(_path, relative)| Value::from(relative))
+      .collect(),
+  ))
+}
+
+/// Files matching `glob`, along with their paths relative to `directory`,
+/// joined with `/`
+fn glob_paths(
+  directory: &Path,
+  glob: &Glob,
+  gitignore: Option<&Value>,
+) -> Result<Vec<(PathBuf, String)>, String> {
+  let gitignore = match gitignore.map(ToString::to_string).as_deref() {
+    None | Some("false") => false,
+    Some("true") => true,
+    Some(s) => return Err(format!("`{s}` is not a boolean")),
+  };
+
+  let paths = glob
+    .paths(gitignore)
+    .map_err(|err| format!("Error reading `{}`: {err}", glob.base().display()))?;
+
+  Ok(
+    paths
+      .into_iter()
+      .map(|path| {
+        let relative = match path.strip_prefix(directory) {
+          Ok(relative) => relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<Cow<str>>>()
+            .join("/"),
+          Err(_) => path.to_string_lossy().into_owned(),
+        };
+        (path, relative)
+      })
+      .collect(),
+  )
+}
+
 fn invocation_directory(context: Context) -> FunctionResult {
   Platform::convert_native_path(
     context.execution_context.config,
@@ -375,6 +471,19 @@ fn join(_context: Context, base: &str, with: &str, and: &[String]) -> FunctionRe
   Ok(result.to_string())
 }
 
+fn join_list(_context: Context, list: &Value, separator: &Value) -> ValueResult {
+  Ok(
+    list
+      .clone()
+      .into_elements()
+      .iter()
+      .map(ToString::to_string)
+      .collect::<Vec<String>>()
+      .join(&separator.to_string())
+      .into(),
+  )
+}
+
 fn just_executable(_context: Context) -> FunctionResult {
   let exe_path =
     env::current_exe().map_err(|e| format!("Error getting current executable: {e}"))?;
@@ -430,10 +539,40 @@ fn justfile_directory(context: Context) -> FunctionResult {
     })
 }
 
+fn int(_context: Context, value: &Value) -> ValueResult {
+  value
+    .to_string()
+    .trim()
+    .parse()
+    .map(Value::Integer)
+    .map_err(|_| format!("`{value}` is not an integer"))
+}
+
+fn json_encode(_context: Context, value: &Value) -> ValueResult {
+  Ok(value.to_json().to_string().into())
+}
+
+fn json_parse(_context: Context, json: &Value) -> ValueResult {
+  serde_json::from_str(&json.to_string())
+    .map(Value::from_json)
+    .map_err(|err| format!("Invalid JSON: {err}"))
+}
+
 fn kebabcase(_context: Context, s: &str) -> FunctionResult {
   Ok(s.to_kebab_case())
 }
 
+fn len(_context: Context, value: &Value) -> ValueResult {
+  let len = match value {
+    Value::Integer(integer) => integer.to_string().len(),
+    Value::List(elements) => elements.len(),
+    Value::Map(entries) => entries.len(),
+    Value::String(string) => string.chars().count(),
+  };
+
+  Ok(len.to_string().into())
+}
+
 fn lowercamelcase(_context: Context, s: &str) -> FunctionResult {
   Ok(s.to_lower_camel_case())
 }
@@ -493,8 +632,23 @@ fn path_exists(context: Context, path: &str) -> FunctionResult {
   )
 }
 
-fn quote(_context: Context, s: &str) -> FunctionResult {
-  Ok(format!("'{}'", s.replace('\'', "'\\''")))
+fn quote(_context: Context, value: &Value) -> ValueResult {
+  fn quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+  }
+
+  match value {
+    Value::List(elements) => Ok(
+      elements
+        .iter()
+        .map(|element| quote(&element.to_string()))
+        .collect::<Vec<String>>()
+        .join(" ")
+        .into(),
+    ),
+    Value::Integer(_) | Value::Map(_) => Ok(quote(&value.to_string()).into()),
+    Value::String(s) => Ok(quote(s).into()),
+  }
 }
 
 fn read(context: Context, filename: &str) -> FunctionResult {
@@ -502,6 +656,16 @@ fn read(context: Context, filename: &str) -> FunctionResult {
     .map_err(|err| format!("I/O error reading `{filename}`: {err}"))
 }
 
+fn render(context: Context, text: &str) -> FunctionResult {
+  Template::render(
+    context.execution_context,
+    context.scope,
+    context.is_dependency,
+    Path::new("render"),
+    text,
+  )
+}
+
 fn replace(_context: Context, s: &str, from: &str, to: &str) -> FunctionResult {
   Ok(s.replace(from, to))
 }
@@ -519,6 +683,11 @@ fn replace_regex(_context: Context, s: &str, regex: &str, replacement: &str) ->
   )
 }
 
+fn secret(context: Context, s: &str) -> FunctionResult {
+  context.execution_context.secrets.register(s);
+  Ok(s.into())
+}
+
 fn sha256(_context: Context, s: &str) -> FunctionResult {
   use sha2::{Digest, Sha256};
   let mut hasher = Sha256::new();
@@ -604,6 +773,21 @@ fn source_file(context: Context) -> FunctionResult {
     })
 }
 
+fn split(_context: Context, s: &Value, separator: &Value) -> ValueResult {
+  let s = s.to_string();
+  let separator = separator.to_string();
+
+  if separator.is_empty() {
+    return Err("Separator may not be empty".into());
+  }
+
+  if s.is_empty() {
+    return Ok(Value::List(Vec::new()));
+  }
+
+  Ok(Value::List(s.split(&separator).map(Value::from).collect()))
+}
+
 fn style(context: Context, s: &str) -> FunctionResult {
   match s {
     "command" => Ok(
@@ -618,10 +802,31 @@ fn style(context: Context, s: &str) -> FunctionResult {
   }
 }
 
+fn template(context: Context, path: &str) -> FunctionResult {
+  let text = fs::read_to_string(context.execution_context.working_directory().join(path))
+    .map_err(|err| format!("I/O error reading `{path}`: {err}"))?;
+
+  Template::render(
+    context.execution_context,
+    context.scope,
+    context.is_dependency,
+    Path::new(path),
+    &text,
+  )
+}
+
 fn titlecase(_context: Context, s: &str) -> FunctionResult {
   Ok(s.to_title_case())
 }
 
+fn toml_parse(_context: Context, toml: &Value) -> ValueResult {
+  toml
+    .to_string()
+    .parse::<toml::Table>()
+    .map(|table| Value::from_toml(toml::Value::Table(table)))
+    .map_err(|err| format!("Invalid TOML: {}", err.message()))
+}
+
 fn trim(_context: Context, s: &str) -> FunctionResult {
   Ok(s.trim().to_owned())
 }
@@ -662,6 +867,24 @@ fn uuid(_context: Context) -> FunctionResult {
   Ok(uuid::Uuid::new_v4().to_string())
 }
 
+fn walk(context: Context, directory: &Value, gitignore: Option<&Value>) -> ValueResult {
+  let working_directory = context.execution_context.working_directory();
+
+  let glob = Glob::new(&working_directory.join(directory.to_string()), "**")
+    .map_err(|err| err.to_string())?;
+
+  if !glob.base().is_dir() {
+    return Err(format!("`{directory}` is not a directory"));
+  }
+
+  Ok(Value::List(
+    glob_paths(&working_directory, &glob, gitignore)?
+      .into_iter()
+      .map(|(_path, relative)| Value::from(relative))
+      .collect(),
+  ))
+}
+
 fn which(context: Context, name: &str) -> FunctionResult {
   Ok(crate::which(context, name)?.unwrap_or_default())
 }
@@ -0,0 +1,214 @@
+use {super::*, CompileErrorKind::*};
+
+pub(crate) struct FunctionResolver<'src: 'run, 'run> {
+  functions: &'run Table<'src, UserFunction<'src>>,
+  modules: &'run Table<'src, Justfile<'src>>,
+  resolved: BTreeSet<&'src str>,
+  stack: Vec<&'src str>,
+}
+
+impl<'src: 'run, 'run> FunctionResolver<'src, 'run> {
+  pub(crate) fn resolve_functions(
+    functions: &'run Table<'src, UserFunction<'src>>,
+    modules: &'run Table<'src, Justfile<'src>>,
+    expressions: impl IntoIterator<Item = &'run Expression<'src>>,
+  ) -> CompileResult<'src> {
+    let mut resolver = Self {
+      functions,
+      modules,
+      resolved: BTreeSet::new(),
+      stack: Vec::new(),
+    };
+
+    for function in functions.values() {
+      resolver.resolve_function(function)?;
+    }
+
+    for expression in expressions {
+      for (path, arguments) in expression.calls() {
+        resolver.resolve_call(path, arguments)?;
+      }
+    }
+
+    Ok(())
+  }
+
+  fn resolve_function(&mut self, function: &'run UserFunction<'src>) -> CompileResult<'src> {
+    let name = function.name.lexeme();
+
+    if self.resolved.contains(name) {
+      return Ok(());
+    }
+
+    self.stack.push(name);
+
+    for variable in function.body.variables() {
+      let lexeme = variable.lexeme();
+
+      if !function
+        .parameters
+        .iter()
+        .any(|parameter| parameter.lexeme() == lexeme)
+        && !constants().contains_key(lexeme)
+      {
+        return Err(variable.error(UndefinedVariable { variable: lexeme }));
+      }
+    }
+
+    for (path, arguments) in function.body.calls() {
+      let callee = self.resolve_call(path, arguments)?;
+
+      if path.components() > 1 {
+        continue;
+      }
+
+      let name = callee.name.lexeme();
+
+      if self.stack.contains(&name) {
+        self.stack.push(name);
+        return Err(callee.name.error(CircularFunctionCall {
+          function: name,
+          circle: self.stack.clone(),
+        }));
+      }
+
+      self.resolve_function(callee)?;
+    }
+
+    self.resolved.insert(name);
+
+    self.stack.pop();
+
+    Ok(())
+  }
+
+  fn resolve_call(
+    &self,
+    path: &Namepath<'src>,
+    arguments: &[Expression<'src>],
+  ) -> CompileResult<'src, &'run UserFunction<'src>> {
+    let (name, modules) = path.split_last();
+
+    let mut functions = self.functions;
+    let mut submodules = self.modules;
+
+    for module in modules {
+      let Some(module) = submodules.get(module.lexeme()) else {
+        return Err(name.error(UnknownFunction {
+          function: path.clone(),
+        }));
+      };
+      functions = &module.functions;
+      submodules = &module.modules;
+    }
+
+    let Some(function) = functions.get(name.lexeme()) else {
+      return Err(name.error(UnknownFunction {
+        function: path.clone(),
+      }));
+    };
+
+    if function.parameters.len() != arguments.len() {
+      return Err(name.error(FunctionArgumentCountMismatch {
+        function: name.lexeme(),
+        found: arguments.len(),
+        expected: function.parameters.len()..=function.parameters.len(),
+      }));
+    }
+
+    Ok(function)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn namepath(
+    src: &'static str,
+    offset: usize,
+    line: usize,
+    column: usize,
+    length: usize,
+  ) -> Namepath<'static> {
+    Namepath::from(Name::from_identifier(Token {
+      column,
+      kind: TokenKind::Identifier,
+      length,
+      line,
+      offset,
+      path: Path::new("justfile"),
+      src,
+    }))
+  }
+
+  analysis_error! {
+    name:   unknown_function,
+    input:  "a := foo()",
+    offset: 5,
+    line:   0,
+    column: 5,
+    width:  3,
+    kind:   UnknownFunction{function: namepath("a := foo()", 5, 0, 5, 3)},
+  }
+
+  analysis_error! {
+    name:   unknown_function_in_interpolation,
+    input:  "a:\n echo {{bar()}}",
+    offset: 11,
+    line:   1,
+    column: 8,
+    width:  3,
+    kind:   UnknownFunction{function: namepath("a:\n echo {{bar()}}", 11, 1, 8, 3)},
+  }
+
+  analysis_error! {
+    name:   unknown_function_in_default,
+    input:  "a f=baz():",
+    offset: 4,
+    line:   0,
+    column: 4,
+    width:  3,
+    kind:   UnknownFunction{function: namepath("a f=baz():", 4, 0, 4, 3)},
+  }
+
+  analysis_error! {
+    name:   function_argument_count_mismatch,
+    input:  "fn f(a, b) := a + b\nx := f('c')",
+    offset: 25,
+    line:   1,
+    column: 5,
+    width:  1,
+    kind:   FunctionArgumentCountMismatch{function: "f", found: 1, expected: 2..=2},
+  }
+
+  analysis_error! {
+    name:   self_call,
+    input:  "fn f(a) := f(a)",
+    offset: 3,
+    line:   0,
+    column: 3,
+    width:  1,
+    kind:   CircularFunctionCall{function: "f", circle: vec!["f", "f"]},
+  }
+
+  analysis_error! {
+    name:   circular_call,
+    input:  "fn f(a) := g(a)\nfn g(a) := f(a)",
+    offset: 3,
+    line:   0,
+    column: 3,
+    width:  1,
+    kind:   CircularFunctionCall{function: "f", circle: vec!["f", "g", "f"]},
+  }
+
+  analysis_error! {
+    name:   function_body_variable_undefined,
+    input:  "x := 'y'\nfn f(a) := x",
+    offset: 20,
+    line:   1,
+    column: 11,
+    width:  1,
+    kind:   UndefinedVariable{variable: "x"},
+  }
+}
@@ -0,0 +1,155 @@
+use super::*;
+
+/// A revision of a git repository, checked out into the cache directory
+pub(crate) struct GitCheckout {
+  pub(crate) commit: String,
+  pub(crate) path: PathBuf,
+}
+
+impl GitCheckout {
+  /// Check out `rev` of `repository`, or `locked`, the commit `rev` was
+  /// previously resolved to, if any. Checkouts are stored under their commit
+  /// hash, so a locked commit which has already been checked out does not
+  /// require running git.
+  pub(crate) fn new(
+    cache_directory: &Path,
+    repository: &str,
+    rev: &str,
+    locked: Option<&str>,
+  ) -> Result<Self, String> {
+    // Arguments beginning with `-` would be interpreted by git as options,
+    // some of which, like `--upload-pack`, run arbitrary commands
+    if repository.starts_with('-') {
+      return Err("repository may not begin with `-`".into());
+    }
+
+    if rev.starts_with('-') {
+      return Err("revision may not begin with `-`".into());
+    }
+
+    // Locked commits are read from `justfile.lock`, and are used as paths
+    // in the cache directory, so anything which isn't a commit hash, like
+    // `../..`, is rejected
+    if let Some(commit) = locked {
+      if !Self::is_commit_hash(commit) {
+        return Err(format!("locked commit `{commit}` is not a commit hash"));
+      }
+    }
+
+    let directory = cache_directory.join("git");
+
+    if let Some(commit) = locked {
+      let path = directory.join(commit);
+      if path.is_dir() {
+        return Ok(Self {
+          commit: commit.into(),
+          path,
+        });
+      }
+    }
+
+    fs::create_dir_all(&directory).map_err(|io_error| {
+      format!(
+        "I/O error creating cache directory `{}`: {io_error}",
+        directory.display(),
+      )
+    })?;
+
+    let tempdir = tempfile::Builder::new()
+      .prefix(".checkout-")
+      .tempdir_in(&directory)
+      .map_err(|io_error| {
+        format!(
+          "I/O error creating temporary directory in `{}`: {io_error}",
+          directory.display(),
+        )
+      })?;
+
+    Self::git(tempdir.path(), &["init", "--quiet"])?;
+
+    // Branches are fetched into local branches so they can be referred to
+    // by name. The current branch of the new repository is unborn, so
+    // updating it is harmless.
+    Self::git(
+      tempdir.path(),
+      &[
+        "fetch",
+        "--quiet",
+        "--tags",
+        "--update-head-ok",
+        "--",
+        repository,
+        "+refs/heads/*:refs/heads/*",
+      ],
+    )?;
+
+    let revision = locked.unwrap_or(rev);
+
+    let commit = Self::git(
+      tempdir.path(),
+      &[
+        "rev-parse",
+        "--verify",
+        "--quiet",
+        &format!("{revision}^{{commit}}"),
+      ],
+    )
+    .map_err(|_| format!("revision `{revision}` not found"))?;
+
+    Self::git(
+      tempdir.path(),
+      &["checkout", "--quiet", "--detach", &commit],
+    )?;
+
+    let git_directory = tempdir.path().join(".git");
+    fs::remove_dir_all(&git_directory).map_err(|io_error| {
+      format!(
+        "I/O error removing `{}`: {io_error}",
+        git_directory.display()
+      )
+    })?;
+
+    let path = directory.join(&commit);
+
+    // Another invocation may have checked out the same commit in the meantime,
+    // in which case this checkout is discarded
+    if !path.is_dir() {
+      fs::rename(tempdir.keep(), &path).map_err(|io_error| {
+        format!(
+          "I/O error moving checkout to `{}`: {io_error}",
+          path.display()
+        )
+      })?;
+    }
+
+    Ok(Self { commit, path })
+  }
+
+  /// Whether `commit` is a full SHA-1 or SHA-256 commit hash
+  fn is_commit_hash(commit: &str) -> bool {
+    matches!(commit.len(), 40 | 64)
+      && commit
+        .bytes()
+        .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(&byte))
+  }
+
+  /// Run git in `directory`, returning its trimmed stdout, or its trimmed
+  /// stderr on failure
+  fn git(directory: &Path, arguments: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+      .arg("-C")
+      .arg(directory)
+      .args(arguments)
+      .env_remove("GIT_DIR")
+      .env_remove("GIT_INDEX_FILE")
+      .env_remove("GIT_WORK_TREE")
+      .output()
+      .map_err(|io_error| format!("failed to run git: {io_error}"))?;
+
+    if output.status.success() {
+      Ok(String::from_utf8_lossy(&output.stdout).trim().into())
+    } else {
+      Err(String::from_utf8_lossy(&output.stderr).trim().into())
+    }
+  }
+}
@@ -0,0 +1,184 @@
+use super::*;
+
+/// A glob pattern, matched against paths relative to `directory`
+///
+/// `*`, `?` and `[…]` match within a single path component, `**` matches
+/// across components, and `{a,b}` matches either alternative.
+#[derive(Debug, Clone)]
+pub(crate) struct Glob {
+  base: PathBuf,
+  directory: PathBuf,
+  matcher: GlobMatcher,
+}
+
+impl Glob {
+  /// Directory containing all paths which may match
+  pub(crate) fn base(&self) -> &Path {
+    &self.base
+  }
+
+  pub(crate) fn is_match(&self, path: &Path) -> bool {
+    path
+      .strip_prefix(&self.directory)
+      .is_ok_and(|relative| self.matcher.is_match(relative))
+  }
+
+  /// Compile `pattern` into a matcher in which only `**` matches `/`
+  pub(crate) fn matcher(pattern: &str) -> Result<GlobMatcher, globset::Error> {
+    Ok(
+      GlobBuilder::new(pattern)
+        .literal_separator(true)
+        .build()?
+        .compile_matcher(),
+    )
+  }
+
+  pub(crate) fn new(directory: &Path, pattern: &str) -> Result<Self, globset::Error> {
+    let matcher = Self::matcher(pattern)?;
+
+    let literal = pattern
+      .split('/')
+      .take_while(|component| !component.contains(['*', '?', '[', '{', '\\']))
+      .collect::<Vec<&str>>();
+
+    let base = if literal.len() == pattern.split('/').count() {
+      directory.join(pattern).parent().unwrap_or(directory).into()
+    } else {
+      directory.join(literal.join("/"))
+    };
+
+    Ok(Self {
+      base,
+      directory: directory.into(),
+      matcher,
+    })
+  }
+
+  /// Files matching this glob, in sorted order. `.git` directories are
+  /// skipped, as are files ignored by the repository's `.gitignore` files if
+  /// `gitignore` is true.
+  pub(crate) fn paths(&self, gitignore: bool) -> Result<Vec<PathBuf>, ignore::Error> {
+    let mut paths = Vec::new();
+
+    if !self.base.is_dir() {
+      return Ok(paths);
+    }
+
+    for entry in Self::walker(&self.base, gitignore) {
+      let entry = entry?;
+
+      if entry
+        .file_type()
+        .is_some_and(|file_type| !file_type.is_dir())
+        && entry.path().is_file()
+        && self.is_match(entry.path())
+      {
+        paths.push(entry.into_path());
+      }
+    }
+
+    paths.sort();
+
+    Ok(paths)
+  }
+
+  /// Walk `directory`, skipping `.git` directories and, if `gitignore` is
+  /// true, paths ignored by the `.gitignore` files of the repository
+  /// containing it
+  pub(crate) fn walker(directory: &Path, gitignore: bool) -> ignore::Walk {
+    WalkBuilder::new(directory)
+      .standard_filters(false)
+      .git_ignore(gitignore)
+      .parents(true)
+      .require_git(true)
+      .filter_entry(|entry| entry.file_name() != ".git")
+      .sort_by_file_name(Ord::cmp)
+      .build()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[track_caller]
+  fn case(pattern: &str, path: &str, matches: bool) {
+    let glob = Glob::new(Path::new("/root"), pattern).unwrap();
+    assert_eq!(
+      glob.is_match(&Path::new("/root").join(path)),
+      matches,
+      "`{pattern}` matching `{path}`",
+    );
+  }
+
+  #[test]
+  fn literal() {
+    case("foo", "foo", true);
+    case("foo", "bar", false);
+    case("foo.rs", "fooxrs", false);
+  }
+
+  #[test]
+  fn star() {
+    case("*.rs", "foo.rs", true);
+    case("*.rs", "src/foo.rs", false);
+    case("src/*", "src/foo.rs", true);
+  }
+
+  #[test]
+  fn double_star() {
+    case("src/**", "src/foo.rs", true);
+    case("src/**", "src/a/b/foo.rs", true);
+    case("**/*.rs", "foo.rs", true);
+    case("**/*.rs", "src/a/foo.rs", true);
+    case("**/*.rs", "src/a/foo.md", false);
+    case("src/**/test/*.rs", "src/test/foo.rs", true);
+    case("src/**/test/*.rs", "src/a/b/test/foo.rs", true);
+    case("src/**/test/*.rs", "src/a/b/foo.rs", false);
+  }
+
+  #[test]
+  fn question_mark() {
+    case("fo?", "foo", true);
+    case("fo?", "fo/", false);
+  }
+
+  #[test]
+  fn character_class() {
+    case("[ab].rs", "a.rs", true);
+    case("[ab].rs", "c.rs", false);
+    case("[!ab].rs", "c.rs", true);
+    case("file[0-9]", "file7", true);
+  }
+
+  #[test]
+  fn alternatives() {
+    case("*.{rs,toml}", "Cargo.toml", true);
+    case("*.{rs,toml}", "main.rs", true);
+    case("*.{rs,toml}", "README.md", false);
+    case("{a,{b,c}}.rs", "c.rs", true);
+    case("{a,{b,c}}.rs", "d.rs", false);
+  }
+
+  #[test]
+  fn invalid() {
+    assert!(Glob::new(Path::new("/root"), "{").is_err());
+    assert!(Glob::new(Path::new("/root"), "[a").is_err());
+  }
+
+  #[test]
+  fn base() {
+    #[track_caller]
+    fn case(pattern: &str, base: &str) {
+      assert_eq!(
+        Glob::new(Path::new("/root"), pattern).unwrap().base(),
+        Path::new(base),
+      );
+    }
+
+    case("src/**/*.rs", "/root/src");
+    case("*.rs", "/root");
+    case("src/main.rs", "/root/src");
+    case("src/[ab]/main.rs", "/root/src");
+  }
+}
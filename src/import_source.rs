@@ -0,0 +1,72 @@
+use super::*;
+
+/// The source file of an `import` or `mod` statement
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+pub(crate) enum ImportSource<'src> {
+  /// A file in `repository` at revision `rev`, checked out into the cache
+  Git {
+    path: Option<StringLiteral<'src>>,
+    repository: StringLiteral<'src>,
+    rev: StringLiteral<'src>,
+  },
+  /// A path relative to the importing file
+  Path(StringLiteral<'src>),
+}
+
+impl<'src> ImportSource<'src> {
+  /// The token to report errors finding the source file on
+  pub(crate) fn token(&self) -> Token<'src> {
+    match self {
+      Self::Git {
+        path, repository, ..
+      } => path.as_ref().unwrap_or(repository).token,
+      Self::Path(path) => path.token,
+    }
+  }
+
+  #[cfg(test)]
+  pub(crate) fn tree(&self) -> Tree<'src> {
+    match self {
+      Self::Git {
+        path,
+        repository,
+        rev,
+        ..
+      } => {
+        let mut tree = Tree::atom(Keyword::Git.lexeme())
+          .push(format!("{repository}"))
+          .push(Tree::atom("rev").push(format!("{rev}")));
+
+        if let Some(path) = path {
+          tree.push_mut(Tree::atom("path").push(format!("{path}")));
+        }
+
+        tree
+      }
+      Self::Path(path) => Tree::atom(format!("{path}")),
+    }
+  }
+}
+
+impl Display for ImportSource<'_> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    match self {
+      Self::Git {
+        path,
+        repository,
+        rev,
+        ..
+      } => {
+        write!(f, "{}({repository}, rev = {rev}", Keyword::Git)?;
+
+        if let Some(path) = path {
+          write!(f, ", path = {path}")?;
+        }
+
+        write!(f, ")")
+      }
+      Self::Path(path) => write!(f, "{path}"),
+    }
+  }
+}
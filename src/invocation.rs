@@ -3,5 +3,6 @@ use super::*;
 #[derive(Debug, PartialEq)]
 pub(crate) struct Invocation<'src, 'run> {
   pub(crate) arguments: Vec<Vec<String>>,
+  pub(crate) command_line: &'run [&'run str],
   pub(crate) recipe: &'run Recipe<'src>,
 }
@@ -48,6 +48,8 @@ impl<'src: 'run, 'run> InvocationParser<'src, 'run> {
   }
 
   fn parse_invocation(&mut self) -> RunResult<'src, Invocation<'src, 'run>> {
+    let start = self.next;
+
     let recipe = if let Some(next) = self.next() {
       if next.contains(':') {
         let module_path =
@@ -243,7 +245,11 @@ impl<'src: 'run, 'run> InvocationParser<'src, 'run> {
 
     self.next += i;
 
-    Ok(Invocation { arguments, recipe })
+    Ok(Invocation {
+      arguments,
+      command_line: &self.arguments[start..self.next],
+      recipe,
+    })
   }
 
   fn resolve_recipe(
@@ -343,6 +349,18 @@ mod tests {
     assert_eq!(invocations[0].arguments, vec![vec![String::from("baz")]]);
   }
 
+  #[test]
+  fn command_line() {
+    let justfile = testing::compile("foo bar:\nbaz:");
+
+    let invocations =
+      InvocationParser::parse_invocations(&justfile, &["foo", "qux", "baz"]).unwrap();
+
+    assert_eq!(invocations.len(), 2);
+    assert_eq!(invocations[0].command_line, ["foo", "qux"]);
+    assert_eq!(invocations[1].command_line, ["baz"]);
+  }
+
   #[test]
   fn single_argument_count_mismatch() {
     let justfile = testing::compile("foo bar:");
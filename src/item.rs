@@ -6,19 +6,22 @@ pub(crate) enum Item<'src> {
   Alias(Alias<'src, Namepath<'src>>),
   Assignment(Assignment<'src>),
   Comment(&'src str),
+  Function(UserFunction<'src>),
   Import {
     absolute: Option<PathBuf>,
     optional: bool,
-    relative: StringLiteral<'src>,
+    source: ImportSource<'src>,
   },
   Module {
     absolute: Option<PathBuf>,
+    arguments: Vec<(Name<'src>, StringLiteral<'src>)>,
     doc: Option<String>,
+    exported: bool,
     groups: Vec<StringLiteral<'src>>,
     name: Name<'src>,
     optional: bool,
     private: bool,
-    relative: Option<StringLiteral<'src>>,
+    source: Option<ImportSource<'src>>,
   },
   Recipe(UnresolvedRecipe<'src>),
   Set(Set<'src>),
@@ -33,8 +36,9 @@ impl Display for Item<'_> {
       Self::Alias(alias) => write!(f, "{alias}"),
       Self::Assignment(assignment) => write!(f, "{assignment}"),
       Self::Comment(comment) => write!(f, "{comment}"),
+      Self::Function(function) => write!(f, "{function}"),
       Self::Import {
-        relative, optional, ..
+        optional, source, ..
       } => {
         write!(f, "import")?;
 
@@ -42,20 +46,26 @@ impl Display for Item<'_> {
           write!(f, "?")?;
         }
 
-        write!(f, " {relative}")
+        write!(f, " {source}")
       }
       Self::Module {
+        arguments,
         doc,
+        exported,
         groups,
         name,
         optional,
-        relative,
+        source,
         ..
       } => {
         if let Some(doc) = doc {
           writeln!(f, "# {doc}")?;
         }
 
+        if *exported {
+          writeln!(f, "[export]")?;
+        }
+
         for group in groups {
           writeln!(f, "[group: {group}]")?;
         }
@@ -68,8 +78,19 @@ impl Display for Item<'_> {
 
         write!(f, " {name}")?;
 
-        if let Some(path) = relative {
-          write!(f, " {path}")?;
+        if let Some(source) = source {
+          write!(f, " {source}")?;
+        }
+
+        if !arguments.is_empty() {
+          write!(f, " (")?;
+          for (i, (name, value)) in arguments.iter().enumerate() {
+            if i > 0 {
+              write!(f, ", ")?;
+            }
+            write!(f, "{name} := {value}")?;
+          }
+          write!(f, ")")?;
         }
 
         Ok(())
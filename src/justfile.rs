@@ -19,10 +19,15 @@ impl TapTally {
 #[derive(Debug, PartialEq, Serialize)]
 pub(crate) struct Justfile<'src> {
   pub(crate) aliases: Table<'src, Alias<'src>>,
+  #[serde(skip)]
+  pub(crate) arguments: BTreeMap<String, String>,
   pub(crate) assignments: Table<'src, Assignment<'src>>,
   #[serde(rename = "first", serialize_with = "keyed::serialize_option")]
   pub(crate) default: Option<Arc<Recipe<'src>>>,
   pub(crate) doc: Option<String>,
+  #[serde(skip)]
+  pub(crate) exported: bool,
+  pub(crate) functions: Table<'src, UserFunction<'src>>,
   pub(crate) groups: Vec<StringLiteral<'src>>,
   #[serde(skip)]
   pub(crate) loaded: Vec<PathBuf>,
@@ -34,6 +39,8 @@ pub(crate) struct Justfile<'src> {
   #[serde(skip)]
   pub(crate) private: bool,
   pub(crate) recipes: Table<'src, Arc<Recipe<'src>>>,
+  #[serde(skip)]
+  pub(crate) sets: Table<'src, Set<'src>>,
   pub(crate) settings: Settings,
   pub(crate) source: PathBuf,
   pub(crate) unexports: HashSet<String>,
@@ -56,6 +63,19 @@ impl<'src> Justfile<'src> {
       .map(|(_distance, suggestion)| suggestion)
   }
 
+  /// The user-defined function at `path`, and the module which defines it
+  pub(crate) fn function(&self, path: &Namepath<'src>) -> Option<(&Self, &UserFunction<'src>)> {
+    let (name, path) = path.split_last();
+
+    let mut module = self;
+
+    for name in path {
+      module = module.modules.get(name.lexeme())?;
+    }
+
+    Some((module, module.functions.get(name.lexeme())?))
+  }
+
   pub(crate) fn suggest_recipe(&self, input: &str) -> Option<Suggestion<'src>> {
     Self::find_suggestion(
       input,
@@ -94,20 +114,53 @@ impl<'src> Justfile<'src> {
     &'run self,
     arena: &'run Arena<Scope<'src, 'run>>,
     config: &'run Config,
-    dotenv: &'run BTreeMap<String, String>,
+    deferred: &'run ModuleScopes<'src, 'run>,
+    dotenvs: &'run BTreeMap<String, BTreeMap<String, String>>,
     root: &'run Scope<'src, 'run>,
-    scopes: &mut BTreeMap<String, (&'run Self, &'run Scope<'src, 'run>)>,
+    scopes: &mut ModuleScopes<'src, 'run>,
     search: &'run Search,
+    secrets: &'run Secrets,
   ) -> RunResult<'src> {
-    let scope = Evaluator::evaluate_assignments(config, dotenv, self, root, search)?;
+    let dotenv = &dotenvs[&self.module_path];
+
+    let scope =
+      Evaluator::evaluate_assignments(config, deferred, dotenv, self, root, search, secrets)?;
 
     let scope = arena.alloc(scope);
-    scopes.insert(self.module_path.clone(), (self, scope));
+    scopes.insert(self, scope, dotenv);
+
+    for module in self.modules.values() {
+      module.evaluate_scopes(
+        arena, config, deferred, dotenvs, scope, scopes, search, secrets,
+      )?;
+    }
+
+    Ok(())
+  }
+
+  /// Load the environment files of this module and its submodules, each on
+  /// top of the values loaded by its parent, recording in `sources` the file
+  /// which set each of this module's values
+  fn load_dotenvs(
+    &self,
+    config: &Config,
+    dotenvs: &mut BTreeMap<String, BTreeMap<String, String>>,
+    parent: &BTreeMap<String, String>,
+    search: &Search,
+    sources: &mut BTreeMap<String, PathBuf>,
+  ) -> RunResult<'src> {
+    let dotenv = if config.load_dotenv {
+      load_dotenv(config, self, search, parent.clone(), sources)?
+    } else {
+      BTreeMap::new()
+    };
 
     for module in self.modules.values() {
-      module.evaluate_scopes(arena, config, dotenv, scope, scopes, search)?;
+      module.load_dotenvs(config, dotenvs, &dotenv, search, &mut sources.clone())?;
     }
 
+    dotenvs.insert(self.module_path.clone(), dotenv);
+
     Ok(())
   }
 
@@ -115,6 +168,7 @@ impl<'src> Justfile<'src> {
     &self,
     config: &Config,
     search: &Search,
+    secrets: &Secrets,
     arguments: &[String],
   ) -> RunResult<'src> {
     let unknown_overrides = config
@@ -130,18 +184,26 @@ impl<'src> Justfile<'src> {
       });
     }
 
-    let dotenv = if config.load_dotenv {
-      load_dotenv(config, &self.settings, &search.working_directory)?
-    } else {
-      BTreeMap::new()
-    };
+    let mut dotenvs = BTreeMap::new();
+    let mut sources = BTreeMap::new();
+    self.load_dotenvs(config, &mut dotenvs, &BTreeMap::new(), search, &mut sources)?;
 
     let root = Scope::root();
     let arena = Arena::new();
-    let mut scopes = BTreeMap::new();
-    self.evaluate_scopes(&arena, config, &dotenv, &root, &mut scopes, search)?;
+    let deferred = ModuleScopes::deferred(&arena, &dotenvs, self, &root)?;
+    let mut scopes = ModuleScopes::default();
+    self.evaluate_scopes(
+      &arena,
+      config,
+      &deferred,
+      &dotenvs,
+      &root,
+      &mut scopes,
+      search,
+      secrets,
+    )?;
 
-    let scope = scopes.get(&self.module_path).unwrap().1;
+    let (_module, scope, dotenv) = scopes.get(&self.module_path).unwrap();
 
     match &config.subcommand {
       Subcommand::Command {
@@ -161,7 +223,7 @@ impl<'src> Justfile<'src> {
 
         let scope = scope.child();
 
-        command.export(&self.settings, &dotenv, &scope, &self.unexports);
+        command.export(&self.settings, dotenv, &scope, &self.unexports);
 
         let (result, caught) = command.status_guard();
 
@@ -187,8 +249,17 @@ impl<'src> Justfile<'src> {
       }
       Subcommand::Evaluate { variable, .. } => {
         if let Some(variable) = variable {
-          if let Some(value) = scope.value(variable) {
-            print!("{value}");
+          let value = if let Some((module_path, name)) = variable.rsplit_once("::") {
+            scopes
+              .get(module_path)
+              .filter(|(module, _scope, _dotenv)| module.assignments.contains_key(name))
+              .and_then(|(_module, scope, _dotenv)| scope.value(name))
+          } else {
+            scope.value(variable)
+          };
+
+          if let Some(value) = value {
+            print!("{}", secrets.mask(&value.to_string()));
           } else {
             return Err(Error::EvalUnknownVariable {
               suggestion: self.suggest_variable(variable),
@@ -204,10 +275,14 @@ impl<'src> Justfile<'src> {
                 "{0:1$} := \"{2}\"",
                 binding.name.lexeme(),
                 width,
-                binding.value
+                secrets.mask(&binding.value.to_string())
               );
             }
           }
+
+          for (key, path) in &sources {
+            println!("# `{key}` loaded from `{}`", path.display());
+          }
         }
 
         return Ok(());
@@ -230,31 +305,102 @@ impl<'src> Justfile<'src> {
       .or(self.settings.output_format)
       .unwrap_or_default();
 
-    if output_format.is_tap() {
-      return Self::run_tap(config, &dotenv, &scopes, search, invocations, output_format);
-    }
+    let mut outcomes = Vec::new();
 
-    let ran = Ran::default();
-    for invocation in invocations {
-      Self::run_recipe(
-        &invocation.arguments,
+    let result = if output_format.is_tap() {
+      Self::run_tap(
         config,
-        &dotenv,
-        false,
-        &ran,
-        invocation.recipe,
         &scopes,
         search,
-        None,
-        OutputFormat::Default,
-      )?;
+        secrets,
+        &invocations,
+        output_format,
+        &mut outcomes,
+      )
+    } else {
+      let ran = Ran::default();
+
+      thread::scope(|thread_scope| {
+        let services = Services::new(thread_scope);
+
+        let result = invocations.iter().try_for_each(|invocation| {
+          let result = Self::run_recipe(
+            &invocation.arguments,
+            config,
+            false,
+            &ran,
+            invocation.recipe,
+            &scopes,
+            search,
+            secrets,
+            &services,
+            None,
+            OutputFormat::Default,
+          );
+          outcomes.push(result.is_ok());
+          result
+        });
+
+        result.and(services.stop())
+      })
+    };
+
+    if !config.dry_run {
+      if let Err(error) = LastRun::new(&invocations, &outcomes).save(config, search) {
+        if !config.verbosity.quiet() {
+          eprintln!(
+            "warning: Failed to record run: {}",
+            error.color_display(Color::never()),
+          );
+        }
+      }
     }
 
-    Ok(())
+    result
+  }
+
+  /// Globs from `[watch]` attributes on the recipes that `arguments` would
+  /// run, including their dependencies
+  pub(crate) fn watch_globs(
+    &self,
+    config: &Config,
+    arguments: &[String],
+  ) -> RunResult<'src, Vec<Glob>> {
+    let arguments = arguments.iter().map(String::as_str).collect::<Vec<&str>>();
+
+    let invocations = InvocationParser::parse_invocations(self, &arguments)?;
+
+    let mut stack = invocations
+      .iter()
+      .map(|invocation| invocation.recipe)
+      .collect::<Vec<&Recipe>>();
+
+    let mut seen = BTreeSet::new();
+    let mut globs = Vec::new();
+
+    while let Some(recipe) = stack.pop() {
+      if !seen.insert(recipe.namepath()) {
+        continue;
+      }
+
+      globs.extend(recipe.watch_globs());
+
+      if !config.no_dependencies {
+        stack.extend(
+          recipe
+            .dependencies
+            .iter()
+            .map(|dependency| dependency.recipe.as_ref()),
+        );
+      }
+    }
+
+    Ok(globs)
   }
 
   fn count_recipes(
     recipe: &Recipe<'src>,
+    scopes: &ModuleScopes<'src, '_>,
     seen: &mut BTreeSet<String>,
     no_dependencies: bool,
   ) -> usize {
@@ -266,7 +412,23 @@ impl<'src> Justfile<'src> {
 
     if !no_dependencies {
       for dep in &recipe.dependencies {
-        count += Self::count_recipes(&dep.recipe, seen, no_dependencies);
+        count += Self::count_recipes(&dep.recipe, scopes, seen, no_dependencies);
+      }
+    }
+
+    let (module, _scope, _dotenv) = scopes
+      .get(&recipe.module_path)
+      .expect("failed to retrieve scope for module");
+
+    // Finally recipes run after every recipe that names them, regardless of
+    // whether they have already run
+    for name in recipe.finally() {
+      if let Some(finally) = module.get_recipe(name) {
+        let seen_before = seen.remove(finally.namepath());
+        count += Self::count_recipes(finally, scopes, seen, no_dependencies);
+        if !seen_before {
+          seen.remove(finally.namepath());
+        }
       }
     }
 
@@ -275,18 +437,20 @@ impl<'src> Justfile<'src> {
 
   fn run_tap(
     config: &Config,
-    dotenv: &BTreeMap<String, String>,
-    scopes: &BTreeMap<String, (&Self, &Scope<'src, '_>)>,
+    scopes: &ModuleScopes<'src, '_>,
     search: &Search,
-    invocations: Vec<Invocation<'src, '_>>,
+    secrets: &Secrets,
+    invocations: &[Invocation<'src, '_>],
     output_format: OutputFormat,
+    outcomes: &mut Vec<bool>,
   ) -> RunResult<'src> {
     let color = config.color.stdout().active();
 
     let mut seen = BTreeSet::<String>::new();
     let mut plan_count = 0;
-    for invocation in &invocations {
-      plan_count += Self::count_recipes(invocation.recipe, &mut seen, config.no_dependencies);
+    for invocation in invocations {
+      plan_count +=
+        Self::count_recipes(invocation.recipe, scopes, &mut seen, config.no_dependencies);
     }
 
     {
@@ -304,20 +468,30 @@ impl<'src> Justfile<'src> {
     let tap_tally = Mutex::new(TapTally::new(color));
     let ran = Ran::default();
 
-    for invocation in &invocations {
-      let _ = Self::run_recipe(
-        &invocation.arguments,
-        config,
-        dotenv,
-        false,
-        &ran,
-        invocation.recipe,
-        scopes,
-        search,
-        Some(&tap_tally),
-        output_format,
-      );
-    }
+    let stopped = thread::scope(|thread_scope| {
+      let services = Services::new(thread_scope);
+
+      for invocation in invocations {
+        let result = Self::run_recipe(
+          &invocation.arguments,
+          config,
+          false,
+          &ran,
+          invocation.recipe,
+          scopes,
+          search,
+          secrets,
+          &services,
+          Some(&tap_tally),
+          output_format,
+        );
+        outcomes.push(result.is_ok());
+      }
+
+      services.stop()
+    });
+
+    stopped?;
 
     let tap = tap_tally.into_inner().unwrap();
 
@@ -363,16 +537,17 @@ impl<'src> Justfile<'src> {
     self.name.map(|name| name.lexeme()).unwrap_or_default()
   }
 
-  fn run_recipe(
+  fn run_recipe<'run>(
     arguments: &[Vec<String>],
-    config: &Config,
-    dotenv: &BTreeMap<String, String>,
+    config: &'run Config,
     is_dependency: bool,
     ran: &Ran,
-    recipe: &Recipe<'src>,
-    scopes: &BTreeMap<String, (&Self, &Scope<'src, '_>)>,
-    search: &Search,
-    tap: Option<&Mutex<TapTally>>,
+    recipe: &'run Recipe<'src>,
+    scopes: &'run ModuleScopes<'src, 'run>,
+    search: &'run Search,
+    secrets: &'run Secrets,
+    services: &Services<'_, 'run, 'src>,
+    tap: Option<&'run Mutex<TapTally>>,
     output_format: OutputFormat,
   ) -> RunResult<'src> {
     {
@@ -387,6 +562,37 @@ impl<'src> Justfile<'src> {
       *guard = true;
     }
 
+    Self::run_recipe_unchecked(
+      arguments,
+      config,
+      is_dependency,
+      ran,
+      recipe,
+      scopes,
+      search,
+      secrets,
+      services,
+      tap,
+      output_format,
+    )
+  }
+
+  /// Run `recipe` without checking whether it has already run. `[finally]`
+  /// recipes are run this way, so that they run after every recipe that
+  /// names them.
+  fn run_recipe_unchecked<'run>(
+    arguments: &[Vec<String>],
+    config: &'run Config,
+    is_dependency: bool,
+    ran: &Ran,
+    recipe: &'run Recipe<'src>,
+    scopes: &'run ModuleScopes<'src, 'run>,
+    search: &'run Search,
+    secrets: &'run Secrets,
+    services: &Services<'_, 'run, 'src>,
+    tap: Option<&'run Mutex<TapTally>>,
+    output_format: OutputFormat,
+  ) -> RunResult<'src> {
     if !config.yes && !recipe.confirm()? {
       return Err(Error::NotConfirmed {
         recipe: recipe.name(),
@@ -408,15 +614,106 @@ impl<'src> Justfile<'src> {
       }
     }
 
-    let (module, scope) = scopes
-      .get(recipe.module_path())
+    if recipe.finally().next().is_none() {
+      return Self::run_recipe_inner(
+        arguments,
+        config,
+        is_dependency,
+        ran,
+        recipe,
+        scopes,
+        search,
+        secrets,
+        services,
+        tap,
+        output_format,
+      );
+    }
+
+    SignalHandler::instance().defer();
+
+    let result = Self::run_recipe_inner(
+      arguments,
+      config,
+      is_dependency,
+      ran,
+      recipe,
+      scopes,
+      search,
+      secrets,
+      services,
+      tap,
+      output_format,
+    );
+
+    // Commands run by finally recipes shouldn't be considered interrupted
+    // because the recipe was
+    let caught = SignalHandler::instance().replace_caught(None);
+
+    let (module, _scope, _dotenv) = scopes
+      .get(&recipe.module_path)
+      .expect("failed to retrieve scope for module");
+
+    let mut finally_result = Ok(());
+
+    for name in recipe.finally() {
+      let finally = module
+        .get_recipe(name)
+        .expect("finally recipes are resolved during analysis");
+
+      let result = Self::run_recipe_unchecked(
+        &[],
+        config,
+        true,
+        ran,
+        finally,
+        scopes,
+        search,
+        secrets,
+        services,
+        tap,
+        output_format,
+      );
+
+      if finally_result.is_ok() {
+        finally_result = result;
+      }
+    }
+
+    {
+      let mut signal_handler = SignalHandler::instance();
+      signal_handler.undefer();
+      let caught = caught.or(signal_handler.caught());
+      signal_handler.replace_caught(caught);
+    }
+
+    result.and(finally_result)
+  }
+
+  fn run_recipe_inner<'run>(
+    arguments: &[Vec<String>],
+    config: &'run Config,
+    is_dependency: bool,
+    ran: &Ran,
+    recipe: &'run Recipe<'src>,
+    scopes: &'run ModuleScopes<'src, 'run>,
+    search: &'run Search,
+    secrets: &'run Secrets,
+    services: &Services<'_, 'run, 'src>,
+    tap: Option<&'run Mutex<TapTally>>,
+    output_format: OutputFormat,
+  ) -> RunResult<'src> {
+    let (module, scope, dotenv) = scopes
+      .get(&recipe.module_path)
       .expect("failed to retrieve scope for module");
 
     let context = ExecutionContext {
       config,
       dotenv,
       module,
+      scopes,
       search,
+      secrets,
     };
 
     let (outer, positional) = Evaluator::evaluate_parameters(
@@ -436,12 +733,13 @@ impl<'src> Justfile<'src> {
       config,
       &context,
       recipe.priors(),
-      dotenv,
       &mut evaluator,
       ran,
       recipe,
       scopes,
       search,
+      secrets,
+      services,
       tap,
       output_format,
     ) {
@@ -457,7 +755,11 @@ impl<'src> Justfile<'src> {
           name: recipe.name().into(),
           ok: false,
           directive: comment,
-          error_message: Some(format!("{}", dep_error.color_display(Color::never()))),
+          error_message: Some(
+            secrets
+              .mask(&dep_error.color_display(Color::never()).to_string())
+              .into_owned(),
+          ),
           exit_code: dep_error.code(),
           output: None,
           suppress_yaml: false,
@@ -476,6 +778,74 @@ impl<'src> Justfile<'src> {
       return Err(dep_error);
     }
 
+    if recipe.is_service() && !config.dry_run {
+      let outer = outer.clone();
+
+      // Service output can't be streamed, since other recipes run alongside
+      let output_format = if output_format == OutputFormat::TapStreamedOutput {
+        OutputFormat::Tap
+      } else {
+        output_format
+      };
+
+      services.start(
+        recipe,
+        || recipe.service_ready(&context, &scope),
+        move |stopping| {
+          Self::run_body(
+            &context,
+            is_dependency,
+            output_format,
+            &positional,
+            recipe,
+            &outer.child(),
+            Some(stopping),
+            tap,
+          )
+        },
+      )?;
+    } else {
+      Self::run_body(
+        &context,
+        is_dependency,
+        output_format,
+        &positional,
+        recipe,
+        &scope,
+        None,
+        tap,
+      )?;
+    }
+
+    Self::run_dependencies(
+      config,
+      &context,
+      recipe.subsequents(),
+      &mut evaluator,
+      &Ran::default(),
+      recipe,
+      scopes,
+      search,
+      secrets,
+      services,
+      tap,
+      output_format,
+    )?;
+
+    Ok(())
+  }
+
+  /// Run a recipe's body, and report the result if TAP output is enabled
+  fn run_body(
+    context: &ExecutionContext<'src, '_>,
+    is_dependency: bool,
+    output_format: OutputFormat,
+    positional: &[String],
+    recipe: &Recipe<'src>,
+    scope: &Scope<'src, '_>,
+    stopping: Option<&AtomicBool>,
+    tap: Option<&Mutex<TapTally>>,
+  ) -> RunResult<'src> {
     let tap_output_buf = tap.as_ref().map(|_| Mutex::new(Vec::<u8>::new()));
 
     // For streamed TAP mode, pre-increment the counter so the Output Block
@@ -491,15 +861,23 @@ impl<'src> Justfile<'src> {
     };
 
     let run_result = recipe.run(
-      &context,
-      &scope,
-      &positional,
+      context,
+      scope,
+      positional,
       is_dependency,
       tap_output_buf.as_ref(),
       output_format,
       tap_test_number,
     );
 
+    // Services are terminated once they are no longer needed, which is not
+    // a failure
+    let run_result = if stopping.is_some_and(|stopping| stopping.load(atomic::Ordering::Relaxed)) {
+      Ok(())
+    } else {
+      run_result
+    };
+
     if let Some(tap) = tap {
       let mut tap = tap.lock().unwrap();
       if tap_test_number.is_none() {
@@ -518,6 +896,7 @@ impl<'src> Justfile<'src> {
             .collect::<Vec<_>>()
             .join("\n")
         })
+        .map(|output| context.secrets.mask(&output).into_owned())
         .filter(|s| !s.is_empty());
 
       let is_subtest = output.as_ref().is_some_and(|o| {
@@ -526,8 +905,9 @@ impl<'src> Justfile<'src> {
           .is_some_and(|l| l.trim() == "TAP version 14")
       });
 
-      let quiet =
-        recipe.quiet || (module.settings.quiet && !recipe.no_quiet()) || config.verbosity.quiet();
+      let quiet = recipe.quiet
+        || (context.module.settings.quiet && !recipe.no_quiet())
+        || context.config.verbosity.quiet();
 
       let comment = recipe.doc().map(Into::into);
 
@@ -566,7 +946,12 @@ impl<'src> Justfile<'src> {
               name: recipe.name().into(),
               ok: false,
               directive: comment,
-              error_message: Some(format!("{}", error.color_display(Color::never()))),
+              error_message: Some(
+                context
+                  .secrets
+                  .mask(&error.color_display(Color::never()).to_string())
+                  .into_owned(),
+              ),
               exit_code: error.code(),
               output: subtest_output,
               suppress_yaml: quiet,
@@ -594,7 +979,7 @@ impl<'src> Justfile<'src> {
             output,
             suppress_yaml: quiet
               || (output_format == OutputFormat::TapStreamedOutput
-                && !config.verbosity.loquacious()),
+                && !context.config.verbosity.loquacious()),
           },
           Err(ref error) => {
             tap.failures += 1;
@@ -603,7 +988,12 @@ impl<'src> Justfile<'src> {
               name: recipe.name().into(),
               ok: false,
               directive: comment,
-              error_message: Some(format!("{}", error.color_display(Color::never()))),
+              error_message: Some(
+                context
+                  .secrets
+                  .mask(&error.color_display(Color::never()).to_string())
+                  .into_owned(),
+              ),
               exit_code: error.code(),
               output,
               suppress_yaml: quiet,
@@ -628,34 +1018,21 @@ impl<'src> Justfile<'src> {
       run_result?;
     }
 
-    Self::run_dependencies(
-      config,
-      &context,
-      recipe.subsequents(),
-      dotenv,
-      &mut evaluator,
-      &Ran::default(),
-      recipe,
-      scopes,
-      search,
-      tap,
-      output_format,
-    )?;
-
     Ok(())
   }
 
   fn run_dependencies<'run>(
-    config: &Config,
-    context: &ExecutionContext<'src, 'run>,
-    dependencies: &[Dependency<'src>],
-    dotenv: &BTreeMap<String, String>,
-    evaluator: &mut Evaluator<'src, 'run>,
+    config: &'run Config,
+    context: &ExecutionContext<'src, '_>,
+    dependencies: &'run [Dependency<'src>],
+    evaluator: &mut Evaluator<'src, '_>,
     ran: &Ran,
     recipe: &Recipe<'src>,
-    scopes: &BTreeMap<String, (&Self, &Scope<'src, 'run>)>,
-    search: &Search,
-    tap: Option<&Mutex<TapTally>>,
+    scopes: &'run ModuleScopes<'src, 'run>,
+    search: &'run Search,
+    secrets: &'run Secrets,
+    services: &Services<'_, 'run, 'src>,
+    tap: Option<&'run Mutex<TapTally>>,
     output_format: OutputFormat,
   ) -> RunResult<'src> {
     if context.config.no_dependencies {
@@ -683,12 +1060,13 @@ impl<'src> Justfile<'src> {
             Self::run_recipe(
               &arguments,
               config,
-              dotenv,
               true,
               ran,
               recipe,
               scopes,
               search,
+              secrets,
+              services,
               tap,
               output_format,
             )
@@ -706,12 +1084,13 @@ impl<'src> Justfile<'src> {
         Self::run_recipe(
           &arguments,
           config,
-          dotenv,
           true,
           ran,
           recipe,
           scopes,
           search,
+          secrets,
+          services,
           tap,
           output_format,
         )?;
@@ -13,23 +13,39 @@ pub(crate) enum Keyword {
   DotenvPath,
   DotenvRequired,
   Else,
+  EnvClear,
+  ExplicitExports,
   Export,
   F,
   Fallback,
   False,
+  Filter,
+  Fn,
+  For,
+  Git,
   If,
   IgnoreComments,
   Import,
+  In,
+  InheritSettings,
+  Lazy,
+  Map,
+  Match,
   Mod,
   NoExitMessage,
   OutputFormat,
+  ParallelAssignments,
   PositionalArguments,
+  Pub,
   Quiet,
   ScriptInterpreter,
   Set,
   Shell,
+  Super,
   Tempdir,
   True,
+  #[strum(serialize = "_")]
+  Underscore,
   Unexport,
   Unstable,
   WindowsPowershell,
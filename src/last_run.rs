@@ -0,0 +1,93 @@
+use super::*;
+
+/// The recipes invoked by the most recent run of a justfile, and whether each
+/// succeeded, as recorded for `--last` and `--rerun-failed`
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) struct LastRun {
+  invocations: Vec<LastRunInvocation>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct LastRunInvocation {
+  command_line: Vec<String>,
+  ok: bool,
+  recipe: String,
+}
+
+impl LastRun {
+  /// Record `invocations`, of which those with an entry in `outcomes` were run.
+  /// Invocations which were not run, because a previous invocation failed, are
+  /// recorded as having failed.
+  pub(crate) fn new(invocations: &[Invocation], outcomes: &[bool]) -> Self {
+    Self {
+      invocations: invocations
+        .iter()
+        .enumerate()
+        .map(|(i, invocation)| LastRunInvocation {
+          command_line: invocation
+            .command_line
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+          ok: outcomes.get(i).copied().unwrap_or_default(),
+          recipe: invocation.recipe.namepath().into(),
+        })
+        .collect(),
+    }
+  }
+
+  /// Path of the file in which runs of the justfile found by `search` are
+  /// recorded. Runs are recorded in the cache directory, under the hash of the
+  /// justfile's path, so that recording them doesn't write to the project.
+  pub(crate) fn path(config: &Config, search: &Search) -> RunResult<'static, PathBuf> {
+    let hash = blake3::hash(search.justfile.as_os_str().as_encoded_bytes());
+
+    Ok(
+      config
+        .cache_directory()?
+        .join("last-run")
+        .join(format!("{}.json", hash.to_hex())),
+    )
+  }
+
+  pub(crate) fn load(config: &Config, search: &Search) -> RunResult<'static, Self> {
+    let path = Self::path(config, search)?;
+
+    let json = fs::read_to_string(&path).map_err(|io_error| Error::LastRunLoad {
+      path: path.clone(),
+      io_error,
+    })?;
+
+    serde_json::from_str(&json).map_err(|source| Error::LastRunParse { path, source })
+  }
+
+  pub(crate) fn save(&self, config: &Config, search: &Search) -> RunResult<'static> {
+    let path = Self::path(config, search)?;
+
+    // Serializing only fails for maps with non-string keys and types whose
+    // `Serialize` implementations return errors, and runs contain neither
+    let mut json = serde_json::to_string_pretty(self).unwrap();
+    json.push('\n');
+
+    // `path` is a file in the `last-run` directory
+    fs::create_dir_all(path.parent().unwrap())
+      .and_then(|()| fs::write(&path, json))
+      .map_err(|io_error| Error::FilesystemIo { io_error, path })
+  }
+
+  /// Command-line arguments which repeat every invocation or, if `failed` is
+  /// true, only those which failed
+  pub(crate) fn arguments(&self, failed: bool) -> Vec<String> {
+    self
+      .invocations
+      .iter()
+      .filter(|invocation| !failed || !invocation.ok)
+      .flat_map(|invocation| invocation.command_line.iter().cloned())
+      .collect()
+  }
+
+  /// Whether any invocation failed
+  pub(crate) fn failed(&self) -> bool {
+    self.invocations.iter().any(|invocation| !invocation.ok)
+  }
+}
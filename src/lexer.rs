@@ -27,6 +27,8 @@ pub(crate) struct Lexer<'src> {
   recipe_body_pending: bool,
   /// Source text
   src: &'src str,
+  /// Lexing a template, which has no indentation
+  template: bool,
   /// Current token end
   token_end: Position,
   /// Current token start
@@ -45,6 +47,19 @@ impl<'src> Lexer<'src> {
     Self::new(path, src).tokenize()
   }
 
+  /// Lex `src` as a template, consisting of text and interpolations
+  pub(crate) fn lex_template(
+    path: &'src Path,
+    src: &'src str,
+  ) -> CompileResult<'src, Vec<Token<'src>>> {
+    Self {
+      recipe_body: true,
+      template: true,
+      ..Self::new(path, src)
+    }
+    .tokenize()
+  }
+
   #[cfg(test)]
   pub(crate) fn test_lex(src: &'src str) -> CompileResult<'src, Vec<Token<'src>>> {
     Self::new("justfile".as_ref(), src).tokenize()
@@ -74,6 +89,7 @@ impl<'src> Lexer<'src> {
       next,
       src,
       path,
+      template: false,
     }
   }
 
@@ -280,7 +296,7 @@ impl<'src> Lexer<'src> {
   /// Consume the text and produce a series of tokens
   fn tokenize(mut self) -> CompileResult<'src, Vec<Token<'src>>> {
     loop {
-      if self.token_start.column == 0 {
+      if self.token_start.column == 0 && !self.template {
         self.lex_line_start()?;
       }
 
@@ -485,15 +501,22 @@ impl<'src> Lexer<'src> {
       '(' => self.lex_delimiter(ParenL),
       ')' => self.lex_delimiter(ParenR),
       '*' => self.lex_single(Asterisk),
+      '%' if self.previous_ends_operand() => self.lex_single(Percent),
       '+' => self.lex_single(Plus),
       ',' => self.lex_single(Comma),
+      '-' if self.previous_ends_operand() => self.lex_single(Minus),
+      '.' => self.lex_single(Dot),
+      '/' if self.next_is_regex() => self.lex_regex(),
       '/' => self.lex_single(Slash),
+      '0'..='9' if !self.next_is_malformed_integer() => self.lex_integer(),
       ':' => self.lex_colon(),
+      '<' => self.lex_choices('<', &[('=', AngleLEquals)], Some(AngleL)),
       '=' => self.lex_choices(
         '=',
-        &[('=', EqualsEquals), ('~', EqualsTilde)],
+        &[('=', EqualsEquals), ('>', EqualsAngleR), ('~', EqualsTilde)],
         Some(Equals),
       ),
+      '>' => self.lex_choices('>', &[('=', AngleREquals)], Some(AngleR)),
       '?' => self.lex_single(QuestionMark),
       '@' => self.lex_single(At),
       '[' => self.lex_delimiter(BracketL),
@@ -788,6 +811,98 @@ impl<'src> Lexer<'src> {
     Ok(())
   }
 
+  /// Check if the previous token can end an operand
+  ///
+  /// `-` and `%` are only binary operators, so they are only lexed as tokens
+  /// when they follow an operand, and are otherwise reported as unknown.
+  fn previous_ends_operand(&self) -> bool {
+    matches!(
+      self
+        .tokens
+        .iter()
+        .rev()
+        .find(|token| token.kind != Whitespace)
+        .map(|token| token.kind),
+      Some(
+        Backtick
+          | BraceR
+          | BracketR
+          | FormatStringEnd
+          | Identifier
+          | Integer
+          | ParenR
+          | StringToken
+      )
+    )
+  }
+
+  /// Check if the next digits are immediately followed by an identifier, like
+  /// `0foo`, which is neither an integer nor an identifier
+  fn next_is_malformed_integer(&self) -> bool {
+    self
+      .rest()
+      .chars()
+      .find(|c| !c.is_ascii_digit())
+      .is_some_and(Self::is_identifier_start)
+  }
+
+  /// Check if the next `/` starts a regular expression literal
+  ///
+  /// Regular expressions only appear as `match` arm patterns, so a `/` is
+  /// only considered to start one when it follows `{` or `,`, and is closed
+  /// by a `/` on the same line which is followed by `=>`.
+  fn next_is_regex(&self) -> bool {
+    let previous = self
+      .tokens
+      .iter()
+      .rev()
+      .find(|token| token.kind != Whitespace)
+      .map(|token| token.kind);
+
+    if !matches!(previous, Some(BraceL | Comma)) {
+      return false;
+    }
+
+    let mut chars = self.rest().chars().skip(1);
+
+    loop {
+      match chars.next() {
+        Some('\\') => {
+          if chars.next().is_none_or(|c| c == '\n') {
+            return false;
+          }
+        }
+        Some('/') => break,
+        Some('\n') | None => return false,
+        Some(_) => {}
+      }
+    }
+
+    chars
+      .skip_while(|c| *c == ' ' || *c == '\t')
+      .take(2)
+      .eq("=>".chars())
+  }
+
+  /// Lex a regular expression literal: /[^/\n]*/
+  fn lex_regex(&mut self) -> CompileResult<'src> {
+    self.presume('/')?;
+
+    loop {
+      if self.accepted('\\')? {
+        self.advance()?;
+      } else if self.accepted('/')? {
+        break;
+      } else {
+        self.advance()?;
+      }
+    }
+
+    self.token(Regex);
+
+    Ok(())
+  }
+
   /// Lex a carriage return and line feed
   fn lex_eol(&mut self) -> CompileResult<'src> {
     if self.accepted('\r')? {
@@ -825,6 +940,17 @@ impl<'src> Lexer<'src> {
     Ok(())
   }
 
+  /// Lex integer: [0-9]+
+  fn lex_integer(&mut self) -> CompileResult<'src> {
+    while self.next.is_some_and(|c| c.is_ascii_digit()) {
+      self.advance()?;
+    }
+
+    self.token(Integer);
+
+    Ok(())
+  }
+
   /// Lex comment: #[^\r\n]
   fn lex_comment(&mut self) -> CompileResult<'src> {
     self.presume('#')?;
@@ -1027,6 +1153,10 @@ mod tests {
     match kind {
       // Fixed lexemes
       AmpersandAmpersand => "&&",
+      AngleL => "<",
+      AngleLEquals => "<=",
+      AngleR => ">",
+      AngleREquals => ">=",
       Asterisk => "*",
       At => "@",
       BangEquals => "!=",
@@ -1042,15 +1172,19 @@ mod tests {
       ColonEquals => ":=",
       Comma => ",",
       Dollar => "$",
+      Dot => ".",
       Eol => "\n",
       Equals => "=",
+      EqualsAngleR => "=>",
       EqualsEquals => "==",
       EqualsTilde => "=~",
       Indent => "  ",
       InterpolationEnd => "}}",
       InterpolationStart => "{{",
+      Minus => "-",
       ParenL => "(",
       ParenR => ")",
+      Percent => "%",
       Plus => "+",
       QuestionMark => "?",
       Slash => "/",
@@ -1061,7 +1195,7 @@ mod tests {
 
       // Variable lexemes
       Backtick | Comment | FormatStringContinue | FormatStringEnd | FormatStringStart
-      | Identifier | StringToken | Text | Unspecified => {
+      | Identifier | Integer | Regex | StringToken | Text | Unspecified => {
         panic!("Token {kind:?} has no default lexeme")
       }
     }
@@ -1215,6 +1349,48 @@ mod tests {
     tokens: (BraceL, BraceL, BraceL, BraceR, BraceR, BraceR),
   }
 
+  test! {
+    name:   regex_match_arm,
+    text:   "{ /a\\/b/ => ",
+    tokens: (BraceL, Whitespace, Regex:"/a\\/b/", Whitespace, EqualsAngleR, Whitespace),
+  }
+
+  test! {
+    name:   slash_is_not_regex_without_arrow,
+    text:   "{ /a/ }",
+    tokens: (BraceL, Whitespace, Slash, Identifier:"a", Slash, Whitespace, BraceR),
+  }
+
+  test! {
+    name:   integer,
+    text:   "1234",
+    tokens: (Integer:"1234"),
+  }
+
+  test! {
+    name:   arithmetic_operators,
+    text:   "1+2-3*4/5%6",
+    tokens: (
+      Integer:"1",
+      Plus,
+      Integer:"2",
+      Minus,
+      Integer:"3",
+      Asterisk,
+      Integer:"4",
+      Slash,
+      Integer:"5",
+      Percent,
+      Integer:"6",
+    ),
+  }
+
+  test! {
+    name:   comparison_operators,
+    text:   "< <= > >=",
+    tokens: (AngleL, Whitespace, AngleLEquals, Whitespace, AngleR, Whitespace, AngleREquals),
+  }
+
   test! {
     name:   dollar,
     text:   "$",
@@ -2135,6 +2311,12 @@ mod tests {
     tokens: (BracketL, BracketR, BracketL, BracketR),
   }
 
+  test! {
+    name:   field_access,
+    text:   "a.b",
+    tokens: (Identifier:"a", Dot, Identifier:"b"),
+  }
+
   test! {
     name:   open_delimiter_eol,
     text:   "[\n](\n){\n}",
@@ -2514,4 +2696,27 @@ consider filing an issue: https://github.com/casey/just/issues/new
   │ ^"
     );
   }
+
+  #[test]
+  fn template() {
+    let tokens = Lexer::lex_template(Path::new("template"), "  a {{ b }}\n{{{{c").unwrap();
+
+    assert_eq!(
+      tokens
+        .iter()
+        .map(|token| (token.kind, token.lexeme()))
+        .collect::<Vec<(TokenKind, &str)>>(),
+      [
+        (Text, "  a "),
+        (InterpolationStart, "{{"),
+        (Whitespace, " "),
+        (Identifier, "b"),
+        (Whitespace, " "),
+        (InterpolationEnd, "}}"),
+        (Eol, "\n"),
+        (Text, "{{{{c"),
+        (Eof, ""),
+      ],
+    );
+  }
 }
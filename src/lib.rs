@@ -10,12 +10,14 @@ pub(crate) use {
     alias_style::AliasStyle,
     analyzer::Analyzer,
     arg_attribute::ArgAttribute,
+    arithmetic_operator::ArithmeticOperator,
     assignment::Assignment,
     assignment_resolver::AssignmentResolver,
     ast::Ast,
     attribute::{Attribute, AttributeDiscriminant},
     attribute_set::AttributeSet,
     binding::Binding,
+    calls::Calls,
     color::Color,
     color_display::ColorDisplay,
     command_color::CommandColor,
@@ -33,6 +35,8 @@ pub(crate) use {
     count::Count,
     delimiter::Delimiter,
     dependency::Dependency,
+    dotenv_error::DotenvError,
+    dotenv_parser::DotenvParser,
     dump_format::DumpFormat,
     enclosure::Enclosure,
     error::Error,
@@ -43,6 +47,10 @@ pub(crate) use {
     format_string_part::FormatStringPart,
     fragment::Fragment,
     function::Function,
+    function_resolver::FunctionResolver,
+    git_checkout::GitCheckout,
+    glob::Glob,
+    import_source::ImportSource,
     interpreter::Interpreter,
     invocation::Invocation,
     invocation_parser::InvocationParser,
@@ -50,12 +58,19 @@ pub(crate) use {
     justfile::Justfile,
     keyed::Keyed,
     keyword::Keyword,
+    last_run::LastRun,
     lexer::Lexer,
     line::Line,
+    line_kind::LineKind,
     list::List,
     load_dotenv::load_dotenv,
     loader::Loader,
+    lockfile::Lockfile,
+    match_arm::MatchArm,
+    match_pattern::MatchPattern,
     module_path::ModulePath,
+    module_scopes::ModuleScopes,
+    module_variables::ModuleVariables,
     name::Name,
     namepath::Namepath,
     ordinal::Ordinal,
@@ -63,6 +78,7 @@ pub(crate) use {
     output_format::OutputFormat,
     parameter::Parameter,
     parameter_kind::ParameterKind,
+    parameter_type::ParameterType,
     parser::Parser,
     pattern::Pattern,
     platform::Platform,
@@ -78,6 +94,8 @@ pub(crate) use {
     search::Search,
     search_config::SearchConfig,
     search_error::SearchError,
+    secrets::Secrets,
+    services::Services,
     set::Set,
     setting::Setting,
     settings::Settings,
@@ -94,6 +112,7 @@ pub(crate) use {
     suggestion::Suggestion,
     switch::Switch,
     table::Table,
+    template::Template,
     thunk::Thunk,
     token::Token,
     token_kind::TokenKind,
@@ -102,27 +121,33 @@ pub(crate) use {
     unstable_feature::UnstableFeature,
     usage::Usage,
     use_color::UseColor,
+    user_function::UserFunction,
+    value::Value,
     variables::Variables,
     verbosity::Verbosity,
     warning::Warning,
+    watcher::Watcher,
     which::which,
+    workspace::Workspace,
   },
   camino::Utf8Path,
   clap::ValueEnum,
   derive_where::derive_where,
   edit_distance::edit_distance,
+  globset::{GlobBuilder, GlobMatcher},
+  ignore::WalkBuilder,
   lexiclean::Lexiclean,
   libc::EXIT_FAILURE,
   rand::seq::IndexedRandom,
   regex::Regex,
   serde::{
     Deserialize, Serialize, Serializer,
-    ser::{SerializeMap, SerializeSeq},
+    ser::{SerializeMap, SerializeSeq, SerializeStruct},
   },
   snafu::{ResultExt, Snafu},
   std::{
     borrow::Cow,
-    cmp::Ordering,
+    cmp::{Ordering, Reverse},
     collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     env,
     ffi::OsString,
@@ -131,13 +156,21 @@ pub(crate) use {
     io::{self, Write},
     iter::{self, FromIterator},
     mem,
+    net::{TcpStream, ToSocketAddrs},
+    num::NonZeroUsize,
     ops::Deref,
-    ops::{Index, RangeInclusive},
-    path::{self, Path, PathBuf},
+    ops::{Index, Range, RangeInclusive},
+    path::{self, Component, Path, PathBuf},
     process::{self, Command, ExitStatus, Stdio},
     str::{self, Chars},
-    sync::{Arc, LazyLock, Mutex, MutexGuard},
-    thread, vec,
+    sync::{
+      Arc, LazyLock, Mutex, MutexGuard,
+      atomic::{self, AtomicBool},
+      mpsc,
+    },
+    thread::{self, ScopedJoinHandle},
+    time::{Duration, Instant, SystemTime},
+    vec,
   },
   strum::{Display, EnumDiscriminants, EnumString, IntoStaticStr},
   tempfile::TempDir,
@@ -163,6 +196,8 @@ pub use {request::Response, subcommand::INIT_JUSTFILE, unindent::unindent};
 type CompileResult<'a, T = ()> = Result<T, CompileError<'a>>;
 type ConfigResult<T> = Result<T, ConfigError>;
 type FunctionResult = Result<String, String>;
+
+type ValueResult = Result<Value, String>;
 type RunResult<'a, T = ()> = Result<T, Error<'a>>;
 type SearchResult<T> = Result<T, SearchError>;
 
@@ -219,12 +254,14 @@ mod alias;
 mod alias_style;
 mod analyzer;
 mod arg_attribute;
+mod arithmetic_operator;
 mod assignment;
 mod assignment_resolver;
 mod ast;
 mod attribute;
 mod attribute_set;
 mod binding;
+mod calls;
 mod color;
 mod color_display;
 mod command_color;
@@ -243,6 +280,8 @@ mod constants;
 mod count;
 mod delimiter;
 mod dependency;
+mod dotenv_error;
+mod dotenv_parser;
 mod dump_format;
 mod enclosure;
 mod error;
@@ -254,6 +293,10 @@ mod filesystem;
 mod format_string_part;
 mod fragment;
 mod function;
+mod function_resolver;
+mod git_checkout;
+mod glob;
+mod import_source;
 mod interpreter;
 mod invocation;
 mod invocation_parser;
@@ -261,12 +304,19 @@ mod item;
 mod justfile;
 mod keyed;
 mod keyword;
+mod last_run;
 mod lexer;
 mod line;
+mod line_kind;
 mod list;
 mod load_dotenv;
 mod loader;
+mod lockfile;
+mod match_arm;
+mod match_pattern;
 mod module_path;
+mod module_scopes;
+mod module_variables;
 mod name;
 mod namepath;
 mod ordinal;
@@ -274,6 +324,7 @@ mod output_error;
 mod output_format;
 mod parameter;
 mod parameter_kind;
+mod parameter_type;
 mod parser;
 mod pattern;
 mod platform;
@@ -290,6 +341,8 @@ mod scope;
 mod search;
 mod search_config;
 mod search_error;
+mod secrets;
+mod services;
 mod set;
 mod setting;
 mod settings;
@@ -308,6 +361,7 @@ mod subcommand;
 mod suggestion;
 mod switch;
 mod table;
+mod template;
 mod thunk;
 mod token;
 mod token_kind;
@@ -317,7 +371,11 @@ mod unresolved_recipe;
 mod unstable_feature;
 mod usage;
 mod use_color;
+mod user_function;
+mod value;
 mod variables;
 mod verbosity;
 mod warning;
+mod watcher;
 mod which;
+mod workspace;
@@ -6,6 +6,8 @@ use super::*;
 pub(crate) struct Line<'src> {
   pub(crate) fragments: Vec<Fragment<'src>>,
   #[serde(skip)]
+  pub(crate) kind: LineKind<'src>,
+  #[serde(skip)]
   pub(crate) number: usize,
 }
 
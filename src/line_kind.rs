@@ -0,0 +1,17 @@
+use super::*;
+
+/// The role of a line in the body of a linewise recipe
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) enum LineKind<'src> {
+  /// A command to run
+  #[default]
+  Command,
+  /// The closing `}` of a `for` loop
+  ForEnd,
+  /// `for name in list {`, whose closing `}` is at index `end` of the body
+  ForStart {
+    end: usize,
+    list: Box<Expression<'src>>,
+    name: Name<'src>,
+  },
+}
@@ -1,63 +1,119 @@
 use super::*;
 
+/// Load the environment files of `module` on top of `dotenv`, the values
+/// loaded by its parent, recording in `sources` the file which set each value.
+/// Command-line options only apply to the root module.
 pub(crate) fn load_dotenv(
   config: &Config,
-  settings: &Settings,
-  working_directory: &Path,
+  module: &Justfile,
+  search: &Search,
+  mut dotenv: BTreeMap<String, String>,
+  sources: &mut BTreeMap<String, PathBuf>,
 ) -> RunResult<'static, BTreeMap<String, String>> {
-  let dotenv_filename = config
-    .dotenv_filename
-    .as_ref()
-    .or(settings.dotenv_filename.as_ref());
+  let settings = &module.settings;
 
-  let dotenv_path = config
-    .dotenv_path
-    .as_ref()
-    .or(settings.dotenv_path.as_ref());
+  let working_directory = if module.is_submodule() {
+    &module.working_directory
+  } else {
+    &search.working_directory
+  };
+
+  let (dotenv_filename, dotenv_paths) = if module.is_submodule() {
+    (settings.dotenv_filename.as_ref(), &settings.dotenv_path)
+  } else {
+    (
+      config
+        .dotenv_filename
+        .as_ref()
+        .or(settings.dotenv_filename.as_ref()),
+      if config.dotenv_path.is_empty() {
+        &settings.dotenv_path
+      } else {
+        &config.dotenv_path
+      },
+    )
+  };
 
   if !settings.dotenv_load
     && !settings.dotenv_override
     && !settings.dotenv_required
     && dotenv_filename.is_none()
-    && dotenv_path.is_none()
+    && dotenv_paths.is_empty()
   {
-    return Ok(BTreeMap::new());
+    return Ok(dotenv);
   }
 
-  if let Some(path) = dotenv_path {
+  let mut paths = Vec::new();
+
+  for path in dotenv_paths {
     let path = working_directory.join(path);
     if filesystem::is_file(&path)? {
-      return load_from_file(&path, settings);
+      paths.push(path);
     }
   }
 
-  let filename = dotenv_filename.map_or(".env", |s| s.as_str());
+  if paths.is_empty() {
+    let filename = dotenv_filename.map_or(".env", |s| s.as_str());
 
-  for directory in working_directory.ancestors() {
-    let path = directory.join(filename);
-    if filesystem::is_file(&path)? {
-      return load_from_file(&path, settings);
+    for directory in working_directory.ancestors() {
+      let path = directory.join(filename);
+      if filesystem::is_file(&path)? {
+        paths.push(path);
+        break;
+      }
     }
   }
 
-  if settings.dotenv_required {
-    Err(Error::DotenvRequired)
-  } else {
-    Ok(BTreeMap::new())
+  if paths.is_empty() && settings.dotenv_required {
+    return Err(Error::DotenvRequired);
   }
+
+  for path in paths {
+    load_from_file(config, &mut dotenv, sources, &path, search, settings)?;
+  }
+
+  Ok(dotenv)
 }
 
+/// Load `path` into `dotenv`, overriding values loaded from earlier files and
+/// recording in `sources` that `path` set them
 fn load_from_file(
+  config: &Config,
+  dotenv: &mut BTreeMap<String, String>,
+  sources: &mut BTreeMap<String, PathBuf>,
   path: &Path,
+  search: &Search,
   settings: &Settings,
-) -> RunResult<'static, BTreeMap<String, String>> {
-  let iter = dotenvy::from_path_iter(path)?;
-  let mut dotenv = BTreeMap::new();
-  for result in iter {
-    let (key, value) = result?;
+) -> RunResult<'static> {
+  let contents = fs::read_to_string(path).map_err(|io_error| DotenvError::Io {
+    io_error,
+    path: path.into(),
+  })?;
+
+  let mut parser = DotenvParser::new(&contents);
+
+  let display = path.strip_prefix(&search.working_directory).unwrap_or(path);
+
+  let color = config.color.stderr().banner();
+
+  // Values may refer to variables loaded from this or earlier files, or to
+  // variables in the environment
+  while let Some((key, value)) =
+    parser.assignment(|name| dotenv.get(name).cloned().or_else(|| env::var(name).ok()))?
+  {
     if settings.dotenv_override || env::var_os(&key).is_none() {
+      if config.verbosity.loquacious() {
+        eprintln!(
+          "{}===> Loaded `{key}` from `{}`{}",
+          color.prefix(),
+          display.display(),
+          color.suffix(),
+        );
+      }
+      sources.insert(key.clone(), display.into());
       dotenv.insert(key, value);
     }
   }
-  Ok(dotenv)
+
+  Ok(())
 }
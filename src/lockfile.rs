@@ -0,0 +1,72 @@
+use super::*;
+
+/// The contents of `justfile.lock`, which records the commits that the
+/// revisions of git imports resolved to, so they resolve to the same commits
+/// until the lockfile is deleted
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Lockfile {
+  /// Repository to revision to commit
+  #[serde(default)]
+  pub(crate) git: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl Lockfile {
+  pub(crate) const FILENAME: &'static str = "justfile.lock";
+
+  /// Load the lockfile at `path`, or an empty lockfile if it doesn't exist
+  pub(crate) fn load(path: &Path) -> RunResult<'static, Self> {
+    let json = match fs::read_to_string(path) {
+      Ok(json) => json,
+      Err(io_error) if io_error.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+      Err(io_error) => {
+        return Err(Error::FilesystemIo {
+          io_error,
+          path: path.into(),
+        });
+      }
+    };
+
+    serde_json::from_str(&json).map_err(|source| Error::LockfileParse {
+      path: path.into(),
+      source,
+    })
+  }
+
+  /// The commit that `rev` of `repository` is locked to, if any
+  pub(crate) fn get(&self, repository: &str, rev: &str) -> Option<&str> {
+    self
+      .git
+      .get(repository)
+      .and_then(|revs| revs.get(rev))
+      .map(String::as_str)
+  }
+
+  pub(crate) fn insert(&mut self, repository: &str, rev: &str, commit: String) {
+    self
+      .git
+      .entry(repository.into())
+      .or_default()
+      .insert(rev.into(), commit);
+  }
+
+  /// Save the lockfile to `path`, or remove the file at `path` if there are
+  /// no git imports to lock
+  pub(crate) fn save(&self, path: &Path) -> RunResult<'static> {
+    let result = if self.git.is_empty() {
+      match fs::remove_file(path) {
+        Err(io_error) if io_error.kind() == io::ErrorKind::NotFound => Ok(()),
+        result => result,
+      }
+    } else {
+      let mut json = serde_json::to_string_pretty(self).unwrap();
+      json.push('\n');
+      fs::write(path, json)
+    };
+
+    result.map_err(|io_error| Error::FilesystemIo {
+      io_error,
+      path: path.into(),
+    })
+  }
+}
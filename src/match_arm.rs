@@ -0,0 +1,26 @@
+use super::*;
+
+/// An arm of a `match` expression, e.g. `'linux' => 'tux'`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MatchArm<'src> {
+  pub(crate) body: Expression<'src>,
+  pub(crate) pattern: MatchPattern<'src>,
+}
+
+impl Display for MatchArm<'_> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(f, "{} => {}", self.pattern, self.body)
+  }
+}
+
+impl Serialize for MatchArm<'_> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    let mut seq = serializer.serialize_seq(None)?;
+    seq.serialize_element(&self.pattern)?;
+    seq.serialize_element(&self.body)?;
+    seq.end()
+  }
+}
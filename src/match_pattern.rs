@@ -0,0 +1,151 @@
+use super::*;
+
+/// The pattern of a `match` arm
+#[derive(Debug, Clone)]
+pub(crate) enum MatchPattern<'src> {
+  /// `'glob'`, which matches like a shell `case` pattern
+  Glob {
+    literal: StringLiteral<'src>,
+    matcher: GlobMatcher,
+  },
+  /// `/regex/`, whose named capture groups are bound in the arm
+  Regex {
+    captures: Vec<Name<'src>>,
+    regex: Regex,
+    token: Token<'src>,
+  },
+  /// `_`, which matches anything
+  Wildcard { token: Token<'src> },
+}
+
+impl<'src> MatchPattern<'src> {
+  pub(crate) fn glob(literal: StringLiteral<'src>) -> CompileResult<'src, Self> {
+    let matcher = Glob::matcher(&literal.cooked).map_err(|source| {
+      literal
+        .token
+        .error(CompileErrorKind::GlobPattern { source })
+    })?;
+
+    Ok(Self::Glob { literal, matcher })
+  }
+
+  pub(crate) fn regex(token: Token<'src>) -> CompileResult<'src, Self> {
+    let lexeme = token.lexeme();
+
+    let regex = lexeme[1..lexeme.len() - 1]
+      .replace("\\/", "/")
+      .parse::<Regex>()
+      .map_err(|source| token.error(CompileErrorKind::MatchArmRegex { source }))?;
+
+    let mut captures = Vec::new();
+
+    for name in regex.capture_names().flatten() {
+      let Some(offset) = [format!("(?<{name}>"), format!("(?P<{name}>")]
+        .iter()
+        .find_map(|group| {
+          lexeme
+            .find(group.as_str())
+            .map(|i| i + group.len() - name.len() - 1)
+        })
+      else {
+        continue;
+      };
+
+      captures.push(Name::from_identifier(Token {
+        column: token.column + offset,
+        kind: TokenKind::Identifier,
+        length: name.len(),
+        offset: token.offset + offset,
+        ..token
+      }));
+    }
+
+    Ok(Self::Regex {
+      captures,
+      regex,
+      token,
+    })
+  }
+
+  pub(crate) fn captures(&self) -> &[Name<'src>] {
+    match self {
+      Self::Regex { captures, .. } => captures,
+      Self::Glob { .. } | Self::Wildcard { .. } => &[],
+    }
+  }
+
+  pub(crate) fn token(&self) -> Token<'src> {
+    match self {
+      Self::Glob { literal, .. } => literal.token,
+      Self::Regex { token, .. } | Self::Wildcard { token } => *token,
+    }
+  }
+
+  /// If `value` matches, the values of the pattern's capture groups
+  pub(crate) fn is_match(&self, value: &str) -> Option<Vec<(Name<'src>, Value)>> {
+    match self {
+      Self::Glob { matcher, .. } => matcher.is_match(value).then(Vec::new),
+      Self::Regex {
+        captures, regex, ..
+      } => regex.captures(value).map(|matched| {
+        captures
+          .iter()
+          .map(|name| {
+            (
+              *name,
+              matched
+                .name(name.lexeme())
+                .map(|group| group.as_str())
+                .unwrap_or_default()
+                .into(),
+            )
+          })
+          .collect()
+      }),
+      Self::Wildcard { .. } => Some(Vec::new()),
+    }
+  }
+}
+
+impl Display for MatchPattern<'_> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    match self {
+      Self::Glob { literal, .. } => write!(f, "{literal}"),
+      Self::Regex { token, .. } | Self::Wildcard { token } => write!(f, "{}", token.lexeme()),
+    }
+  }
+}
+
+impl PartialEq for MatchPattern<'_> {
+  fn eq(&self, other: &Self) -> bool {
+    match (self, other) {
+      (Self::Glob { literal: a, .. }, Self::Glob { literal: b, .. }) => a == b,
+      (Self::Regex { token: a, .. }, Self::Regex { token: b, .. }) => a.lexeme() == b.lexeme(),
+      (Self::Wildcard { .. }, Self::Wildcard { .. }) => true,
+      _ => false,
+    }
+  }
+}
+
+impl Serialize for MatchPattern<'_> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    match self {
+      Self::Glob { literal, .. } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("glob")?;
+        seq.serialize_element(&literal.cooked)?;
+        seq.end()
+      }
+      Self::Regex { regex, .. } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("regex")?;
+        seq.serialize_element(regex.as_str())?;
+        seq.end()
+      }
+      Self::Wildcard { .. } => serializer.serialize_str("_"),
+    }
+  }
+}
@@ -0,0 +1,73 @@
+use super::*;
+
+/// The scope of each module of a justfile, along with the module and the
+/// values loaded from its environment files, keyed by module path
+#[derive(Default)]
+pub(crate) struct ModuleScopes<'src: 'run, 'run> {
+  scopes: BTreeMap<
+    String,
+    (
+      &'run Justfile<'src>,
+      &'run Scope<'src, 'run>,
+      &'run BTreeMap<String, String>,
+    ),
+  >,
+}
+
+impl<'src: 'run, 'run> ModuleScopes<'src, 'run> {
+  /// Deferred scopes for the submodules of `justfile`, with their arguments
+  /// bound.
+  ///
+  /// Modules are evaluated before their submodules, so variables in
+  /// submodules referred to by assignments, like `frontend::version`, are
+  /// evaluated on demand in these scopes and memoized, and the submodules' own
+  /// scopes then start out with the values memoized here.
+  pub(crate) fn deferred(
+    arena: &'run Arena<Scope<'src, 'run>>,
+    dotenvs: &'run BTreeMap<String, BTreeMap<String, String>>,
+    justfile: &'run Justfile<'src>,
+    root: &'run Scope<'src, 'run>,
+  ) -> RunResult<'src, Self> {
+    let mut scopes = Self::default();
+
+    let mut stack = justfile.modules.values().collect::<Vec<&Justfile>>();
+
+    while let Some(module) = stack.pop() {
+      let mut scope = root.deferred_child();
+
+      Evaluator::bind_overrides(&module.assignments, &module.arguments, &mut scope)?;
+
+      let dotenv = dotenvs
+        .get(&module.module_path)
+        .ok_or_else(|| Error::internal("failed to retrieve dotenv for module"))?;
+
+      scopes.insert(module, arena.alloc(scope), dotenv);
+
+      stack.extend(module.modules.values());
+    }
+
+    Ok(scopes)
+  }
+
+  pub(crate) fn get(
+    &self,
+    module_path: &str,
+  ) -> Option<(
+    &'run Justfile<'src>,
+    &'run Scope<'src, 'run>,
+    &'run BTreeMap<String, String>,
+  )> {
+    self.scopes.get(module_path).copied()
+  }
+
+  pub(crate) fn insert(
+    &mut self,
+    module: &'run Justfile<'src>,
+    scope: &'run Scope<'src, 'run>,
+    dotenv: &'run BTreeMap<String, String>,
+  ) {
+    self
+      .scopes
+      .insert(module.module_path.clone(), (module, scope, dotenv));
+  }
+}
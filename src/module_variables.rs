@@ -0,0 +1,102 @@
+use super::*;
+
+/// Variables in other modules referenced by an expression, like
+/// `frontend::version` in `'v' + frontend::version`
+pub(crate) struct ModuleVariables<'expression, 'src> {
+  stack: Vec<&'expression Expression<'src>>,
+}
+
+impl<'expression, 'src> ModuleVariables<'expression, 'src> {
+  pub(crate) fn new(root: &'expression Expression<'src>) -> Self {
+    Self { stack: vec![root] }
+  }
+}
+
+impl<'expression, 'src> Iterator for ModuleVariables<'expression, 'src> {
+  type Item = &'expression Namepath<'src>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      let expression = self.stack.pop()?;
+
+      match expression {
+        Expression::And { lhs, rhs }
+        | Expression::Arithmetic { lhs, rhs, .. }
+        | Expression::Concatenation { lhs, rhs, .. }
+        | Expression::Or { lhs, rhs } => {
+          self.stack.push(rhs);
+          self.stack.push(lhs);
+        }
+        Expression::Assert {
+          condition: Condition { lhs, rhs, .. },
+          error,
+          ..
+        } => {
+          self.stack.push(error);
+          self.stack.push(rhs);
+          self.stack.push(lhs);
+        }
+        Expression::Backtick { .. }
+        | Expression::Integer { .. }
+        | Expression::StringLiteral { .. }
+        | Expression::Variable { .. } => {}
+        Expression::Call { thunk } => {
+          self.stack.extend(thunk.arguments().into_iter().rev());
+        }
+        Expression::Conditional {
+          condition: Condition { lhs, rhs, .. },
+          then,
+          otherwise,
+        } => {
+          self.stack.push(otherwise);
+          self.stack.push(then);
+          self.stack.push(rhs);
+          self.stack.push(lhs);
+        }
+        Expression::Filter {
+          condition: Condition { lhs, rhs, .. },
+          list,
+          ..
+        } => {
+          self.stack.push(rhs);
+          self.stack.push(lhs);
+          self.stack.push(list);
+        }
+        Expression::For { body, list, .. } | Expression::Map { body, list, .. } => {
+          self.stack.push(body);
+          self.stack.push(list);
+        }
+        Expression::FormatString { expressions, .. } => {
+          for (expression, _string) in expressions.iter().rev() {
+            self.stack.push(expression);
+          }
+        }
+        Expression::Field { value, .. } | Expression::Group { contents: value } => {
+          self.stack.push(value);
+        }
+        Expression::Index { index, value, .. } => {
+          self.stack.push(index);
+          self.stack.push(value);
+        }
+        Expression::Join { lhs, rhs, .. } => {
+          self.stack.push(rhs);
+          if let Some(lhs) = lhs {
+            self.stack.push(lhs);
+          }
+        }
+        Expression::List { elements } => {
+          for element in elements.iter().rev() {
+            self.stack.push(element);
+          }
+        }
+        Expression::Match { arms, value } => {
+          for arm in arms.iter().rev() {
+            self.stack.push(&arm.body);
+          }
+          self.stack.push(value);
+        }
+        Expression::ModuleVariable { path } => return Some(path),
+      }
+    }
+  }
+}
@@ -4,10 +4,6 @@ use super::*;
 pub(crate) struct Namepath<'src>(Vec<Name<'src>>);
 
 impl<'src> Namepath<'src> {
-  pub(crate) fn join(&self, name: Name<'src>) -> Self {
-    Self(self.0.iter().copied().chain(iter::once(name)).collect())
-  }
-
   pub(crate) fn push(&mut self, name: Name<'src>) {
     self.0.push(name);
   }
@@ -21,8 +21,9 @@ impl<'src> Node<'src> for Item<'src> {
       Self::Alias(alias) => alias.tree(),
       Self::Assignment(assignment) => assignment.tree(),
       Self::Comment(comment) => comment.tree(),
+      Self::Function(function) => function.tree(),
       Self::Import {
-        relative, optional, ..
+        optional, source, ..
       } => {
         let mut tree = Tree::atom("import");
 
@@ -30,12 +31,13 @@ impl<'src> Node<'src> for Item<'src> {
           tree = tree.push("?");
         }
 
-        tree.push(format!("{relative}"))
+        tree.push(source.tree())
       }
       Self::Module {
+        arguments,
         name,
         optional,
-        relative,
+        source,
         ..
       } => {
         let mut tree = Tree::atom("mod");
@@ -46,8 +48,15 @@ impl<'src> Node<'src> for Item<'src> {
 
         tree = tree.push(name.lexeme());
 
-        if let Some(relative) = relative {
-          tree = tree.push(format!("{relative}"));
+        if let Some(source) = source {
+          tree = tree.push(source.tree());
+        }
+
+        for (name, value) in arguments {
+          tree.push_mut(Tree::list([
+            Tree::atom(name.lexeme()),
+            Tree::string(&value.cooked),
+          ]));
         }
 
         tree
@@ -88,15 +97,17 @@ impl<'src> Node<'src> for Alias<'src, Namepath<'src>> {
 
 impl<'src> Node<'src> for Assignment<'src> {
   fn tree(&self) -> Tree<'src> {
+    let mut tree = Tree::atom("assignment");
+
+    if self.public {
+      tree = tree.push("#").push(Keyword::Pub.lexeme());
+    }
+
     if self.export {
-      Tree::atom("assignment")
-        .push("#")
-        .push(Keyword::Export.lexeme())
-    } else {
-      Tree::atom("assignment")
+      tree = tree.push("#").push(Keyword::Export.lexeme());
     }
-    .push(self.name.lexeme())
-    .push(self.value.tree())
+
+    tree.push(self.name.lexeme()).push(self.value.tree())
   }
 }
 
@@ -104,8 +115,15 @@ impl<'src> Node<'src> for Expression<'src> {
   fn tree(&self) -> Tree<'src> {
     match self {
       Self::And { lhs, rhs } => Tree::atom("&&").push(lhs.tree()).push(rhs.tree()),
+      Self::Arithmetic {
+        lhs, operator, rhs, ..
+      } => Tree::atom(operator.to_string())
+        .push(lhs.tree())
+        .push(rhs.tree()),
       Self::Assert {
-        condition: Condition { lhs, rhs, operator },
+        condition: Condition {
+          lhs, rhs, operator, ..
+        },
         error,
         ..
       } => Tree::atom(Keyword::Assert.lexeme())
@@ -119,12 +137,15 @@ impl<'src> Node<'src> for Expression<'src> {
         let mut tree = Tree::atom("call");
         match thunk {
           Nullary { name, .. } => tree.push_mut(name.lexeme()),
-          Unary { name, arg, .. } => {
+          Unary { name, arg, .. } | UnaryValue { name, arg, .. } => {
             tree.push_mut(name.lexeme());
             tree.push_mut(arg.tree());
           }
           UnaryOpt {
             name, args: (a, b), ..
+          }
+          | UnaryOptValue {
+            name, args: (a, b), ..
           } => {
             tree.push_mut(name.lexeme());
             tree.push_mut(a.tree());
@@ -145,6 +166,9 @@ impl<'src> Node<'src> for Expression<'src> {
           }
           Binary {
             name, args: [a, b], ..
+          }
+          | BinaryValue {
+            name, args: [a, b], ..
           } => {
             tree.push_mut(name.lexeme());
             tree.push_mut(a.tree());
@@ -172,12 +196,20 @@ impl<'src> Node<'src> for Expression<'src> {
             tree.push_mut(b.tree());
             tree.push_mut(c.tree());
           }
+          User { args, path } => {
+            tree.push_mut(path.tree());
+            for arg in args {
+              tree.push_mut(arg.tree());
+            }
+          }
         }
         tree
       }
-      Self::Concatenation { lhs, rhs } => Tree::atom("+").push(lhs.tree()).push(rhs.tree()),
+      Self::Concatenation { lhs, rhs, .. } => Tree::atom("+").push(lhs.tree()).push(rhs.tree()),
       Self::Conditional {
-        condition: Condition { lhs, rhs, operator },
+        condition: Condition {
+          lhs, rhs, operator, ..
+        },
         then,
         otherwise,
       } => {
@@ -189,6 +221,19 @@ impl<'src> Node<'src> for Expression<'src> {
         tree.push_mut(otherwise.tree());
         tree
       }
+      Self::Field { name, value } => Tree::atom("field").push(value.tree()).push(name.lexeme()),
+      Self::Filter {
+        condition: Condition {
+          lhs, rhs, operator, ..
+        },
+        list,
+        name,
+      } => Tree::atom(Keyword::Filter.lexeme())
+        .push(list.tree())
+        .push(name.lexeme())
+        .push(lhs.tree())
+        .push(operator.to_string())
+        .push(rhs.tree()),
       Self::FormatString { start, expressions } => {
         let mut tree = Tree::atom("format");
         tree.push_mut(Tree::string(&start.cooked));
@@ -199,11 +244,45 @@ impl<'src> Node<'src> for Expression<'src> {
         tree
       }
       Self::Group { contents } => Tree::List(vec![contents.tree()]),
-      Self::Join { lhs: None, rhs } => Tree::atom("/").push(rhs.tree()),
+      Self::For { body, list, name } => Tree::atom(Keyword::For.lexeme())
+        .push(name.lexeme())
+        .push(list.tree())
+        .push(body.tree()),
+      Self::Index { index, value, .. } => Tree::atom("index").push(value.tree()).push(index.tree()),
+      Self::Integer { token, .. } => Tree::atom(token.lexeme()),
+      Self::Join { lhs: None, rhs, .. } => Tree::atom("/").push(rhs.tree()),
       Self::Join {
         lhs: Some(lhs),
         rhs,
+        ..
       } => Tree::atom("/").push(lhs.tree()).push(rhs.tree()),
+      Self::List { elements } => {
+        let mut tree = Tree::atom("list");
+        for element in elements {
+          tree.push_mut(element.tree());
+        }
+        tree
+      }
+      Self::Map { body, list, name } => Tree::atom(Keyword::Map.lexeme())
+        .push(list.tree())
+        .push(name.lexeme())
+        .push(body.tree()),
+      Self::Match { arms, value } => {
+        let mut tree = Tree::atom(Keyword::Match.lexeme());
+        tree.push_mut(value.tree());
+        for arm in arms {
+          let pattern = match &arm.pattern {
+            MatchPattern::Glob { literal, .. } => Tree::string(&literal.cooked),
+            MatchPattern::Regex { regex, .. } => {
+              Tree::atom("regex").push(Tree::string(regex.as_str()))
+            }
+            MatchPattern::Wildcard { .. } => Tree::atom("_"),
+          };
+          tree.push_mut(Tree::list([pattern, arm.body.tree()]));
+        }
+        tree
+      }
+      Self::ModuleVariable { path } => path.tree(),
       Self::Or { lhs, rhs } => Tree::atom("||").push(lhs.tree()).push(rhs.tree()),
       Self::StringLiteral {
         string_literal: StringLiteral { cooked, .. },
@@ -277,10 +356,34 @@ impl<'src> Node<'src> for UnresolvedRecipe<'src> {
   }
 }
 
+impl<'src> Node<'src> for UserFunction<'src> {
+  fn tree(&self) -> Tree<'src> {
+    Tree::atom(Keyword::Fn.lexeme())
+      .push(self.name.lexeme())
+      .push(Tree::list(
+        self
+          .parameters
+          .iter()
+          .map(|parameter| Tree::atom(parameter.lexeme())),
+      ))
+      .push(self.body.tree())
+  }
+}
+
 impl<'src> Node<'src> for Parameter<'src> {
   fn tree(&self) -> Tree<'src> {
     let mut children = vec![Tree::atom(self.name.lexeme())];
 
+    if let Some(ty) = &self.ty {
+      let mut tree = Tree::atom("type").push(ty.name());
+
+      if let ParameterType::Enum(values) = ty {
+        tree = tree.extend(values.iter().map(|value| Tree::string(&value.cooked)));
+      }
+
+      children.push(tree);
+    }
+
     if let Some(default) = &self.default {
       children.push(default.tree());
     }
@@ -315,9 +418,14 @@ impl<'src> Node<'src> for Set<'src> {
       | Setting::DotenvLoad(value)
       | Setting::DotenvOverride(value)
       | Setting::DotenvRequired(value)
+      | Setting::EnvClear(value)
+      | Setting::ExplicitExports(value)
       | Setting::Export(value)
       | Setting::Fallback(value)
+      | Setting::InheritSettings(value)
+      | Setting::Lazy(value)
       | Setting::NoExitMessage(value)
+      | Setting::ParallelAssignments(value)
       | Setting::PositionalArguments(value)
       | Setting::Quiet(value)
       | Setting::Unstable(value)
@@ -1,6 +1,6 @@
 use super::*;
 
-#[derive(PartialEq, Debug, Clone, Serialize)]
+#[derive(PartialEq, Debug, Clone)]
 pub(crate) struct Parameter<'src> {
   pub(crate) default: Option<Expression<'src>>,
   pub(crate) export: bool,
@@ -9,7 +9,9 @@ pub(crate) struct Parameter<'src> {
   pub(crate) long: Option<String>,
   pub(crate) name: Name<'src>,
   pub(crate) pattern: Option<Pattern<'src>>,
+  pub(crate) secret: bool,
   pub(crate) short: Option<char>,
+  pub(crate) ty: Option<ParameterType<'src>>,
   pub(crate) value: Option<String>,
 }
 
@@ -42,6 +44,28 @@ impl<'src> Parameter<'src> {
       recipe: recipe.name(),
     })
   }
+
+  pub(crate) fn check_type(
+    &self,
+    recipe: &Recipe<'src>,
+    value: &str,
+    invocation_directory: &Path,
+  ) -> Result<(), Error<'src>> {
+    let Some(ty) = &self.ty else {
+      return Ok(());
+    };
+
+    if ty.accepts(value, invocation_directory) {
+      return Ok(());
+    }
+
+    Err(Error::ArgumentTypeMismatch {
+      argument: value.into(),
+      parameter: self.name.lexeme(),
+      recipe: recipe.name(),
+      ty: Box::new(ty.clone()),
+    })
+  }
 }
 
 impl ColorDisplay for Parameter<'_> {
@@ -53,9 +77,37 @@ impl ColorDisplay for Parameter<'_> {
       write!(f, "$")?;
     }
     write!(f, "{}", color.parameter().paint(self.name.lexeme()))?;
+    if let Some(ty) = &self.ty {
+      write!(f, ": {}", color.annotation().paint(&ty.to_string()))?;
+    }
     if let Some(ref default) = self.default {
       write!(f, "={}", color.string().paint(&default.to_string()))?;
     }
     Ok(())
   }
 }
+
+impl Serialize for Parameter<'_> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    let mut parameter = serializer.serialize_struct("Parameter", 11)?;
+    if self.secret && self.default.is_some() {
+      parameter.serialize_field("default", &Some(Secrets::MASK))?;
+    } else {
+      parameter.serialize_field("default", &self.default)?;
+    }
+    parameter.serialize_field("export", &self.export)?;
+    parameter.serialize_field("help", &self.help)?;
+    parameter.serialize_field("kind", &self.kind)?;
+    parameter.serialize_field("long", &self.long)?;
+    parameter.serialize_field("name", &self.name)?;
+    parameter.serialize_field("pattern", &self.pattern)?;
+    parameter.serialize_field("secret", &self.secret)?;
+    parameter.serialize_field("short", &self.short)?;
+    parameter.serialize_field("type", &self.ty)?;
+    parameter.serialize_field("value", &self.value)?;
+    parameter.end()
+  }
+}
@@ -0,0 +1,82 @@
+use super::*;
+
+/// The type of a recipe parameter, checked against each argument before the
+/// recipe runs
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ParameterType<'src> {
+  /// `true` or `false`
+  Bool,
+  /// One of a fixed set of values
+  Enum(Vec<StringLiteral<'src>>),
+  /// An existing file, relative to the invocation directory
+  File,
+  /// A signed 64-bit integer
+  Int,
+}
+
+impl ParameterType<'_> {
+  pub(crate) const NAMES: &'static [&'static str] = &["bool", "enum", "file", "int"];
+
+  pub(crate) fn accepts(&self, value: &str, invocation_directory: &Path) -> bool {
+    match self {
+      Self::Bool => matches!(value, "true" | "false"),
+      Self::Enum(values) => values.iter().any(|literal| literal.cooked == value),
+      Self::File => invocation_directory.join(value).is_file(),
+      Self::Int => value.parse::<i64>().is_ok(),
+    }
+  }
+
+  pub(crate) fn name(&self) -> &'static str {
+    match self {
+      Self::Bool => "bool",
+      Self::Enum(_) => "enum",
+      Self::File => "file",
+      Self::Int => "int",
+    }
+  }
+
+  /// Description of accepted values, used in error messages
+  pub(crate) fn expected(&self) -> String {
+    match self {
+      Self::Bool => "`true` or `false`".into(),
+      Self::Enum(values) => format!(
+        "one of {}",
+        List::or_ticked(values.iter().map(|literal| &literal.cooked)),
+      ),
+      Self::File => "an existing file".into(),
+      Self::Int => "an integer".into(),
+    }
+  }
+
+  /// Values to offer as shell completions
+  pub(crate) fn completions(&self) -> Vec<&str> {
+    match self {
+      Self::Bool => vec!["false", "true"],
+      Self::Enum(values) => values
+        .iter()
+        .map(|literal| literal.cooked.as_str())
+        .collect(),
+      Self::File | Self::Int => Vec::new(),
+    }
+  }
+}
+
+impl Display for ParameterType<'_> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(f, "{}", self.name())?;
+
+    if let Self::Enum(values) = self {
+      write!(f, "(")?;
+      for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+          write!(f, ", ")?;
+        }
+        write!(f, "{value}")?;
+      }
+      write!(f, ")")?;
+    }
+
+    Ok(())
+  }
+}
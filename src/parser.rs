@@ -26,8 +26,8 @@ use {super::*, TokenKind::*};
 pub(crate) struct Parser<'run, 'src> {
   expected_tokens: BTreeSet<TokenKind>,
   file_depth: u32,
+  function: Option<&'src str>,
   import_offsets: Vec<usize>,
-  module_namepath: Option<&'run Namepath<'src>>,
   next_token: usize,
   recursion_depth: usize,
   tokens: &'run [Token<'src>],
@@ -40,15 +40,14 @@ impl<'run, 'src> Parser<'run, 'src> {
   pub(crate) fn parse(
     file_depth: u32,
     import_offsets: &[usize],
-    module_namepath: Option<&'run Namepath<'src>>,
     tokens: &'run [Token<'src>],
     working_directory: &'run Path,
   ) -> CompileResult<'src, Ast<'src>> {
     Self {
       expected_tokens: BTreeSet::new(),
       file_depth,
+      function: None,
       import_offsets: import_offsets.to_vec(),
-      module_namepath,
       next_token: 0,
       recursion_depth: 0,
       tokens,
@@ -58,6 +57,47 @@ impl<'run, 'src> Parser<'run, 'src> {
     .parse_ast()
   }
 
+  /// Parse a template from `tokens`, produced by `Lexer::lex_template`
+  pub(crate) fn parse_template(tokens: &'run [Token<'src>]) -> CompileResult<'src, Template<'src>> {
+    let mut parser = Self {
+      expected_tokens: BTreeSet::new(),
+      file_depth: 0,
+      function: None,
+      import_offsets: Vec::new(),
+      next_token: 0,
+      recursion_depth: 0,
+      tokens,
+      unstable_features: BTreeSet::new(),
+      working_directory: Path::new(""),
+    };
+
+    let mut fragments = Vec::new();
+
+    while !parser.accepted(Eof)? {
+      if let Some(token) = parser.accept(Text)? {
+        fragments.push(Fragment::Text { token });
+      } else if let Some(token) = parser.accept(Eol)? {
+        fragments.push(Fragment::Text { token });
+      } else if parser.accepted(InterpolationStart)? {
+        fragments.push(Fragment::Interpolation {
+          expression: parser.parse_expression()?,
+        });
+        parser.expect(InterpolationEnd)?;
+      } else {
+        return Err(parser.unexpected_token()?);
+      }
+    }
+
+    Ok(Template {
+      line: Line {
+        fragments,
+        kind: LineKind::Command,
+        number: 0,
+      },
+      unstable_features: parser.unstable_features,
+    })
+  }
+
   fn error(&self, kind: CompileErrorKind<'src>) -> CompileResult<'src, CompileError<'src>> {
     Ok(self.next()?.error(kind))
   }
@@ -358,9 +398,26 @@ impl<'run, 'src> Parser<'run, 'src> {
           }
           Some(Keyword::Export) if self.next_are(&[Identifier, Identifier, ColonEquals]) => {
             self.presume_keyword(Keyword::Export)?;
-            items.push(Item::Assignment(
-              self.parse_assignment(true, take_attributes())?,
-            ));
+            items.push(Item::Assignment(self.parse_assignment(
+              true,
+              false,
+              take_attributes(),
+            )?));
+          }
+          Some(Keyword::Pub)
+            if self.next_are(&[Identifier, Identifier, ColonEquals])
+              || self.next_is_public_export() =>
+          {
+            self.presume_keyword(Keyword::Pub)?;
+            let export = self.accepted_keyword(Keyword::Export)?;
+            items.push(Item::Assignment(self.parse_assignment(
+              export,
+              true,
+              take_attributes(),
+            )?));
+          }
+          Some(Keyword::Fn) if self.next_are(&[Identifier, Identifier, ParenL]) => {
+            items.push(Item::Function(self.parse_function(take_attributes())?));
           }
           Some(Keyword::Unexport)
             if self.next_are(&[Identifier, Identifier, Eof])
@@ -373,23 +430,26 @@ impl<'run, 'src> Parser<'run, 'src> {
           }
           Some(Keyword::Import)
             if self.next_are(&[Identifier, StringToken])
+              || self.next_are(&[Identifier, Identifier, ParenL])
               || self.next_are(&[Identifier, Identifier, StringToken])
               || self.next_are(&[Identifier, QuestionMark]) =>
           {
             self.presume_keyword(Keyword::Import)?;
             let optional = self.accepted(QuestionMark)?;
-            let relative = self.parse_string_literal()?;
+            let source = self.parse_import_source()?;
             items.push(Item::Import {
               absolute: None,
               optional,
-              relative,
+              source,
             });
           }
           Some(Keyword::Mod)
             if self.next_are(&[Identifier, Identifier, Comment])
               || self.next_are(&[Identifier, Identifier, Eof])
               || self.next_are(&[Identifier, Identifier, Eol])
+              || self.next_are(&[Identifier, Identifier, Identifier, ParenL])
               || self.next_are(&[Identifier, Identifier, Identifier, StringToken])
+              || self.next_are(&[Identifier, Identifier, ParenL])
               || self.next_are(&[Identifier, Identifier, StringToken])
               || self.next_are(&[Identifier, QuestionMark]) =>
           {
@@ -401,13 +461,17 @@ impl<'run, 'src> Parser<'run, 'src> {
 
             let name = self.parse_name()?;
 
-            let relative = if self.next_is(StringToken) || self.next_are(&[Identifier, StringToken])
+            let source = if self.next_is(StringToken)
+              || self.next_are(&[Identifier, ParenL])
+              || self.next_are(&[Identifier, StringToken])
             {
-              Some(self.parse_string_literal()?)
+              Some(self.parse_import_source()?)
             } else {
               None
             };
 
+            let arguments = self.parse_module_arguments()?;
+
             let attributes = take_attributes();
 
             attributes.ensure_valid_attributes(
@@ -415,6 +479,7 @@ impl<'run, 'src> Parser<'run, 'src> {
               *name,
               &[
                 AttributeDiscriminant::Doc,
+                AttributeDiscriminant::Export,
                 AttributeDiscriminant::Group,
                 AttributeDiscriminant::Private,
               ],
@@ -427,6 +492,8 @@ impl<'run, 'src> Parser<'run, 'src> {
               _ => unreachable!(),
             };
 
+            let exported = attributes.contains(AttributeDiscriminant::Export);
+
             let private = attributes.contains(AttributeDiscriminant::Private);
 
             let mut groups = Vec::new();
@@ -438,12 +505,14 @@ impl<'run, 'src> Parser<'run, 'src> {
 
             items.push(Item::Module {
               absolute: None,
+              arguments,
               doc,
+              exported,
               groups,
               name,
               optional,
               private,
-              relative,
+              source,
             });
           }
           Some(Keyword::Set)
@@ -457,9 +526,11 @@ impl<'run, 'src> Parser<'run, 'src> {
           }
           _ => {
             if self.next_are(&[Identifier, ColonEquals]) {
-              items.push(Item::Assignment(
-                self.parse_assignment(false, take_attributes())?,
-              ));
+              items.push(Item::Assignment(self.parse_assignment(
+                false,
+                false,
+                take_attributes(),
+              )?));
             } else {
               let doc = pop_doc_comment(&mut items, eol_since_last_comment);
               items.push(Item::Recipe(self.parse_recipe(
@@ -497,10 +568,6 @@ impl<'run, 'src> Parser<'run, 'src> {
 
     Ok(Ast {
       items,
-      module_path: self
-        .module_namepath
-        .map(ToString::to_string)
-        .unwrap_or_default(),
       unstable_features: self.unstable_features,
       warnings: Vec::new(),
       working_directory: self.working_directory.into(),
@@ -531,6 +598,7 @@ impl<'run, 'src> Parser<'run, 'src> {
   fn parse_assignment(
     &mut self,
     export: bool,
+    public: bool,
     attributes: AttributeSet<'src>,
   ) -> CompileResult<'src, Assignment<'src>> {
     let name = self.parse_name()?;
@@ -540,7 +608,16 @@ impl<'run, 'src> Parser<'run, 'src> {
 
     let private = attributes.contains(AttributeDiscriminant::Private);
 
-    attributes.ensure_valid_attributes("Assignment", *name, &[AttributeDiscriminant::Private])?;
+    let secret = attributes.contains(AttributeDiscriminant::Secret);
+
+    attributes.ensure_valid_attributes(
+      "Assignment",
+      *name,
+      &[
+        AttributeDiscriminant::Private,
+        AttributeDiscriminant::Secret,
+      ],
+    )?;
 
     Ok(Assignment {
       export,
@@ -548,10 +625,149 @@ impl<'run, 'src> Parser<'run, 'src> {
       name,
       prelude: false,
       private: private || name.lexeme().starts_with('_'),
+      public,
+      secret,
       value,
     })
   }
 
+  /// Parse a function, e.g. `fn slug(s) := lowercase(s)`
+  /// Parse the source of an `import` or `mod` statement, either a path or
+  /// `git(repository, rev = "…", path = "…")`
+  fn parse_import_source(&mut self) -> CompileResult<'src, ImportSource<'src>> {
+    if !self.next_are(&[Identifier, ParenL]) {
+      return Ok(ImportSource::Path(self.parse_string_literal()?));
+    }
+
+    let keyword = self.parse_name()?;
+
+    if keyword.lexeme() != Keyword::Git.lexeme() {
+      return Err(keyword.error(CompileErrorKind::ExpectedKeyword {
+        expected: vec![Keyword::Git],
+        found: *keyword,
+      }));
+    }
+
+    self.presume(ParenL)?;
+
+    let repository = self.parse_string_literal()?;
+
+    let mut path = None;
+    let mut rev = None;
+
+    while self.accepted(Comma)? {
+      if self.next_is(ParenR) {
+        break;
+      }
+
+      let name = self.parse_name()?;
+
+      self.expect(Equals)?;
+
+      let value = self.parse_string_literal()?;
+
+      let slot = match name.lexeme() {
+        "path" => &mut path,
+        "rev" => &mut rev,
+        _ => {
+          return Err(name.error(CompileErrorKind::UnknownGitImportKeyword {
+            keyword: name.lexeme(),
+          }));
+        }
+      };
+
+      if slot.is_some() {
+        return Err(name.error(CompileErrorKind::DuplicateGitImportKeyword {
+          keyword: name.lexeme(),
+        }));
+      }
+
+      *slot = Some(value);
+    }
+
+    self.expect(ParenR)?;
+
+    let Some(rev) = rev else {
+      return Err(keyword.error(CompileErrorKind::GitImportMissingRev));
+    };
+
+    Ok(ImportSource::Git {
+      path,
+      repository,
+      rev,
+    })
+  }
+
+  /// Parse the optional `(name := "value", …)` arguments of a module
+  fn parse_module_arguments(
+    &mut self,
+  ) -> CompileResult<'src, Vec<(Name<'src>, StringLiteral<'src>)>> {
+    let mut arguments = Vec::<(Name, StringLiteral)>::new();
+
+    if !self.accepted(ParenL)? {
+      return Ok(arguments);
+    }
+
+    while !self.next_is(ParenR) {
+      let name = self.parse_name()?;
+
+      if arguments
+        .iter()
+        .any(|(argument, _value)| argument.lexeme() == name.lexeme())
+      {
+        return Err(name.error(CompileErrorKind::DuplicateVariable {
+          variable: name.lexeme(),
+        }));
+      }
+
+      self.expect(ColonEquals)?;
+
+      arguments.push((name, self.parse_string_literal()?));
+
+      if !self.accepted(Comma)? {
+        break;
+      }
+    }
+
+    self.expect(ParenR)?;
+
+    Ok(arguments)
+  }
+
+  fn parse_function(
+    &mut self,
+    attributes: AttributeSet<'src>,
+  ) -> CompileResult<'src, UserFunction<'src>> {
+    self.presume_keyword(Keyword::Fn)?;
+    let name = self.parse_name()?;
+    self.presume(ParenL)?;
+
+    let mut parameters = Vec::new();
+
+    while !self.next_is(ParenR) {
+      parameters.push(self.parse_name()?);
+
+      if !self.accepted(Comma)? {
+        break;
+      }
+    }
+
+    self.expect(ParenR)?;
+    self.expect(ColonEquals)?;
+    self.function = Some(name.lexeme());
+    let body = self.parse_expression()?;
+    self.function = None;
+    self.expect_eol()?;
+
+    attributes.ensure_valid_attributes("Function", *name, &[])?;
+
+    Ok(UserFunction {
+      body,
+      name,
+      parameters,
+    })
+  }
+
   /// Parse an expression, e.g. `1 + 2`
   fn parse_expression(&mut self) -> CompileResult<'src, Expression<'src>> {
     if self.recursion_depth == if cfg!(windows) { 48 } else { 256 } {
@@ -599,28 +815,183 @@ impl<'run, 'src> Parser<'run, 'src> {
     Ok(disjunct)
   }
 
+  /// Parse a conjunct, which is a sum, e.g. `a + b - c`
+  ///
+  /// `+` is right-associative and `-` is left-associative.
   fn parse_conjunct(&mut self) -> CompileResult<'src, Expression<'src>> {
+    let mut sum = self.parse_product()?;
+
+    while self.next_is(Plus) || self.next_is(Minus) {
+      sum = self.parse_operation(Some(sum))?;
+    }
+
+    Ok(sum)
+  }
+
+  /// Parse a product, e.g. `a * b / c`
+  fn parse_product(&mut self) -> CompileResult<'src, Expression<'src>> {
+    let mut product = if self.next_is(Slash) {
+      self.parse_operation(None)?
+    } else {
+      self.parse_operand()?
+    };
+
+    while self.next_is(Slash) || self.next_is(Asterisk) || self.next_is(Percent) {
+      product = self.parse_operation(Some(product))?;
+    }
+
+    Ok(product)
+  }
+
+  /// Parse an operator and its right-hand side, e.g. `+ b`, given its
+  /// left-hand side, if any
+  fn parse_operation(
+    &mut self,
+    lhs: Option<Expression<'src>>,
+  ) -> CompileResult<'src, Expression<'src>> {
+    let token = self.advance()?;
+
+    let Some(lhs) = lhs else {
+      return Ok(Expression::Join {
+        lhs: None,
+        rhs: self.parse_product()?.into(),
+        token,
+      });
+    };
+
+    let lhs = Box::new(lhs);
+
+    let operator = match token.kind {
+      Plus => {
+        return Ok(Expression::Concatenation {
+          lhs,
+          rhs: self.parse_conjunct()?.into(),
+          token,
+        });
+      }
+      Slash => {
+        let rhs = if self.next_is(Slash) {
+          self.parse_operation(None)?
+        } else {
+          self.parse_operand()?
+        };
+
+        return Ok(Expression::Join {
+          lhs: Some(lhs),
+          rhs: rhs.into(),
+          token,
+        });
+      }
+      Minus => ArithmeticOperator::Difference,
+      Asterisk => ArithmeticOperator::Product,
+      Percent => ArithmeticOperator::Remainder,
+      _ => return Err(self.internal_error(format!("Unexpected operator {}", token.kind))?),
+    };
+
+    self.unstable_features.insert(UnstableFeature::Arithmetic);
+
+    let rhs = if operator == ArithmeticOperator::Difference {
+      self.parse_product()?
+    } else {
+      self.parse_operand()?
+    };
+
+    Ok(Expression::Arithmetic {
+      lhs,
+      operator,
+      rhs: rhs.into(),
+      token,
+    })
+  }
+
+  /// Parse the operand of an operator, e.g. `a`, `if a == b { c } else { d }`,
+  /// or `for x in xs { x }`
+  fn parse_operand(&mut self) -> CompileResult<'src, Expression<'src>> {
     if self.accepted_keyword(Keyword::If)? {
       self.parse_conditional()
-    } else if self.accepted(Slash)? {
-      let lhs = None;
-      let rhs = self.parse_conjunct()?.into();
-      Ok(Expression::Join { lhs, rhs })
+    } else if self.next_is_for_loop() {
+      self.parse_for_loop()
+    } else if self.next_is_match() {
+      self.parse_match()
     } else {
-      let value = self.parse_value()?;
-
-      if self.accepted(Slash)? {
-        let lhs = Some(Box::new(value));
-        let rhs = self.parse_conjunct()?.into();
-        Ok(Expression::Join { lhs, rhs })
-      } else if self.accepted(Plus)? {
-        let lhs = value.into();
-        let rhs = self.parse_conjunct()?.into();
-        Ok(Expression::Concatenation { lhs, rhs })
+      self.parse_value()
+    }
+  }
+
+  /// Parse a `for` loop expression, e.g. `for x in xs { x + '.rs' }`
+  fn parse_for_loop(&mut self) -> CompileResult<'src, Expression<'src>> {
+    let (name, list) = self.parse_for_loop_header()?;
+    self.expect(BraceL)?;
+    let body = self.parse_expression()?.into();
+    self.expect(BraceR)?;
+    Ok(Expression::For {
+      body,
+      list: list.into(),
+      name,
+    })
+  }
+
+  /// Parse the header of a `for` loop, e.g. `for x in xs`
+  fn parse_for_loop_header(&mut self) -> CompileResult<'src, (Name<'src>, Expression<'src>)> {
+    self.presume_keyword(Keyword::For)?;
+    let name = self.parse_name()?;
+    self.presume_keyword(Keyword::In)?;
+    let list = self.parse_expression()?;
+    Ok((name, list))
+  }
+
+  /// Parse a match expression, e.g. `match os() { 'linux' => 'tux', _ => '' }`
+  fn parse_match(&mut self) -> CompileResult<'src, Expression<'src>> {
+    let keyword = self.next()?;
+    self.presume_keyword(Keyword::Match)?;
+
+    let value = self.parse_expression()?;
+
+    self.expect(BraceL)?;
+
+    let mut arms = Vec::new();
+    let mut wildcard = false;
+
+    while !self.next_is(BraceR) {
+      let pattern = if let Some(token) = self.accept(Regex)? {
+        MatchPattern::regex(token)?
+      } else if self.next_is(StringToken) {
+        MatchPattern::glob(self.parse_string_literal()?)?
+      } else if self.next_is(Identifier) {
+        let token = self.next()?;
+        self.expect_keyword(Keyword::Underscore)?;
+        MatchPattern::Wildcard { token }
       } else {
-        Ok(value)
+        return Err(self.unexpected_token()?);
+      };
+
+      if wildcard {
+        return Err(pattern.token().error(CompileErrorKind::MatchArmUnreachable));
+      }
+
+      wildcard = matches!(pattern, MatchPattern::Wildcard { .. });
+
+      self.expect(EqualsAngleR)?;
+
+      let body = self.parse_expression()?;
+
+      arms.push(MatchArm { body, pattern });
+
+      if !self.accepted(Comma)? {
+        break;
       }
     }
+
+    self.expect(BraceR)?;
+
+    if !wildcard {
+      return Err(keyword.error(CompileErrorKind::MatchWithoutWildcard));
+    }
+
+    Ok(Expression::Match {
+      arms,
+      value: value.into(),
+    })
   }
 
   /// Parse a conditional, e.g. `if a == b { "foo" } else { "bar" }`
@@ -653,21 +1024,34 @@ impl<'run, 'src> Parser<'run, 'src> {
 
   fn parse_condition(&mut self) -> CompileResult<'src, Condition<'src>> {
     let lhs = self.parse_expression()?;
+    let token = self.next()?;
     let operator = if self.accepted(BangEquals)? {
       ConditionalOperator::Inequality
     } else if self.accepted(EqualsTilde)? {
       ConditionalOperator::RegexMatch
     } else if self.accepted(BangTilde)? {
       ConditionalOperator::RegexMismatch
+    } else if self.accepted(AngleL)? {
+      ConditionalOperator::LessThan
+    } else if self.accepted(AngleLEquals)? {
+      ConditionalOperator::LessThanOrEqual
+    } else if self.accepted(AngleR)? {
+      ConditionalOperator::GreaterThan
+    } else if self.accepted(AngleREquals)? {
+      ConditionalOperator::GreaterThanOrEqual
     } else {
       self.expect(EqualsEquals)?;
       ConditionalOperator::Equality
     };
+    if operator.is_comparison() {
+      self.unstable_features.insert(UnstableFeature::Arithmetic);
+    }
     let rhs = self.parse_expression()?;
     Ok(Condition {
       lhs: lhs.into(),
       rhs: rhs.into(),
       operator,
+      token,
     })
   }
 
@@ -712,6 +1096,59 @@ impl<'run, 'src> Parser<'run, 'src> {
       && tokens.next().is_some_and(|token| token.kind == StringToken)
   }
 
+  // Check if the next tokens are the start of a `for` loop, i.e., `for x in`.
+  //
+  // `for` is not a reserved word, so this allows variables named `for` to
+  // continue to be used.
+  fn next_is_for_loop(&self) -> bool {
+    let mut tokens = self.rest();
+
+    tokens
+      .next()
+      .is_some_and(|token| token.kind == Identifier && Keyword::For == token.lexeme())
+      && tokens.next().is_some_and(|token| token.kind == Identifier)
+      && tokens
+        .next()
+        .is_some_and(|token| token.kind == Identifier && Keyword::In == token.lexeme())
+  }
+
+  // Check if the next tokens are the start of an exported public assignment,
+  // i.e., `pub export x :=`.
+  //
+  // `pub` and `export` are not reserved words, so this allows recipes named
+  // `pub` to continue to be used.
+  fn next_is_public_export(&self) -> bool {
+    let mut tokens = self.rest();
+
+    tokens
+      .next()
+      .is_some_and(|token| token.kind == Identifier && Keyword::Pub == token.lexeme())
+      && tokens
+        .next()
+        .is_some_and(|token| token.kind == Identifier && Keyword::Export == token.lexeme())
+      && tokens.next().is_some_and(|token| token.kind == Identifier)
+      && tokens.next().is_some_and(|token| token.kind == ColonEquals)
+  }
+
+  // Check if the next tokens are the start of a `match` expression, i.e.,
+  // `match` followed by a value.
+  //
+  // `match` is not a reserved word, so this allows variables named `match` to
+  // continue to be used.
+  fn next_is_match(&self) -> bool {
+    let mut tokens = self.rest();
+
+    tokens
+      .next()
+      .is_some_and(|token| token.kind == Identifier && Keyword::Match == token.lexeme())
+      && tokens.next().is_some_and(|token| {
+        matches!(
+          token.kind,
+          Backtick | BracketL | Identifier | ParenL | StringToken
+        )
+      })
+  }
+
   // Check if the next tokens are a format string, i.e., `f"foo"`.
   //
   // This function skips initial whitespace tokens, but thereafter is
@@ -734,12 +1171,67 @@ impl<'run, 'src> Parser<'run, 'src> {
 
   /// Parse a value, e.g. `(bar)`
   fn parse_value(&mut self) -> CompileResult<'src, Expression<'src>> {
+    let mut value = self.parse_primary()?;
+
+    loop {
+      let mut rest = self.rest();
+
+      match (
+        rest.next().map(|token| token.kind),
+        rest.next().map(|token| token.kind),
+      ) {
+        (Some(Dot), Some(Identifier)) => {
+          self.presume(Dot)?;
+          value = Expression::Field {
+            name: self.parse_name()?,
+            value: value.into(),
+          };
+        }
+        (Some(BracketL), _) => {
+          self.presume(BracketL)?;
+          let token = self.next()?;
+          // list indices are plain integer literals, which are not subject to
+          // the unstable integer arithmetic gate
+          let index = if self.next_are(&[Integer, BracketR]) {
+            let token = self.presume(Integer)?;
+            Expression::Integer {
+              token,
+              value: token
+                .lexeme()
+                .parse()
+                .map_err(|_| token.error(CompileErrorKind::IntegerLiteralOverflow))?,
+            }
+          } else {
+            self.parse_expression()?
+          }
+          .into();
+          self.expect(BracketR)?;
+          value = Expression::Index {
+            index,
+            token,
+            value: value.into(),
+          };
+        }
+        _ => return Ok(value),
+      }
+    }
+  }
+
+  /// Parse a value without trailing field accesses or indexing
+  fn parse_primary(&mut self) -> CompileResult<'src, Expression<'src>> {
     if self.next_is(StringToken) || self.next_is_shell_expanded_string() {
       Ok(Expression::StringLiteral {
         string_literal: self.parse_string_literal()?,
       })
     } else if self.next_is_format_string() {
       self.parse_format_string()
+    } else if let Some(token) = self.accept(Integer)? {
+      self.unstable_features.insert(UnstableFeature::Arithmetic);
+      let value = token
+        .lexeme()
+        .parse()
+        .map_err(|_| token.error(CompileErrorKind::IntegerLiteralOverflow))?;
+      Ok(Expression::Integer { token, value })
     } else if self.next_is(Backtick) {
       let next = self.next()?;
       let kind = StringKind::from_string_or_backtick(next)?;
@@ -755,6 +1247,11 @@ impl<'run, 'src> Parser<'run, 'src> {
       if contents.starts_with("#!") {
         return Err(next.error(CompileErrorKind::BacktickShebang));
       }
+
+      if let Some(function) = self.function {
+        return Err(next.error(CompileErrorKind::FunctionRunsCommand { function }));
+      }
+
       Ok(Expression::Backtick { contents, token })
     } else if self.next_is(Identifier) {
       if let Some(name) = self.accept_keyword(Keyword::Assert)? {
@@ -771,13 +1268,42 @@ impl<'run, 'src> Parser<'run, 'src> {
       } else {
         let name = self.parse_name()?;
 
-        if self.next_is(ParenL) {
+        if self.next_is(ParenL)
+          && (Keyword::Map == name.lexeme() || Keyword::Filter == name.lexeme())
+        {
+          self.parse_iteration(name)
+        } else if self.next_is(ColonColon) {
+          let mut path = Namepath::from(name);
+
+          while self.accepted(ColonColon)? {
+            path.push(self.parse_name()?);
+          }
+
+          if self.next_is(ParenL) {
+            Ok(Expression::Call {
+              thunk: Thunk::User {
+                args: self.parse_sequence()?,
+                path,
+              },
+            })
+          } else {
+            Ok(Expression::ModuleVariable { path })
+          }
+        } else if self.next_is(ParenL) {
+          if let Some(function) = self.function {
+            if name.lexeme() == "shell" {
+              return Err(name.error(CompileErrorKind::FunctionRunsCommand { function }));
+            }
+          }
           let arguments = self.parse_sequence()?;
           if name.lexeme() == "which" {
             self
               .unstable_features
               .insert(UnstableFeature::WhichFunction);
           }
+          if name.lexeme() == "bool" || name.lexeme() == "int" {
+            self.unstable_features.insert(UnstableFeature::Arithmetic);
+          }
           Ok(Expression::Call {
             thunk: Thunk::resolve(name, arguments)?,
           })
@@ -790,6 +1316,8 @@ impl<'run, 'src> Parser<'run, 'src> {
       let contents = self.parse_expression()?.into();
       self.expect(ParenR)?;
       Ok(Expression::Group { contents })
+    } else if self.next_is(BracketL) {
+      self.parse_list()
     } else {
       Err(self.unexpected_token()?)
     }
@@ -990,6 +1518,56 @@ impl<'run, 'src> Parser<'run, 'src> {
     Ok(path)
   }
 
+  /// Parse the arguments of `map` or `filter`, e.g. `(xs, x => uppercase(x))`
+  fn parse_iteration(&mut self, function: Name<'src>) -> CompileResult<'src, Expression<'src>> {
+    self.presume(ParenL)?;
+
+    let list = self.parse_expression()?.into();
+
+    self.expect(Comma)?;
+
+    let name = self.parse_name()?;
+
+    self.expect(EqualsAngleR)?;
+
+    let expression = if Keyword::Map == function.lexeme() {
+      Expression::Map {
+        body: self.parse_expression()?.into(),
+        list,
+        name,
+      }
+    } else {
+      Expression::Filter {
+        condition: self.parse_condition()?,
+        list,
+        name,
+      }
+    };
+
+    self.expect(ParenR)?;
+
+    Ok(expression)
+  }
+
+  /// Parse a list, e.g. `['a', 'b']`
+  fn parse_list(&mut self) -> CompileResult<'src, Expression<'src>> {
+    self.presume(BracketL)?;
+
+    let mut elements = Vec::new();
+
+    while !self.next_is(BracketR) {
+      elements.push(self.parse_expression()?);
+
+      if !self.accepted(Comma)? {
+        break;
+      }
+    }
+
+    self.expect(BracketR)?;
+
+    Ok(Expression::List { elements })
+  }
+
   /// Parse sequence of comma-separated expressions
   fn parse_sequence(&mut self) -> CompileResult<'src, Vec<Expression<'src>>> {
     self.presume(ParenL)?;
@@ -1032,6 +1610,7 @@ impl<'run, 'src> Parser<'run, 'src> {
         long_key,
         name: arg,
         pattern,
+        secret,
         short,
         value,
         ..
@@ -1069,6 +1648,7 @@ impl<'run, 'src> Parser<'run, 'src> {
           name: arg.token,
           pattern: pattern.clone(),
           long: long.as_ref().map(|long| long.cooked.clone()),
+          secret: *secret,
           short: short
             .as_ref()
             .map(|short| short.cooked.chars().next().unwrap()),
@@ -1133,11 +1713,21 @@ impl<'run, 'src> Parser<'run, 'src> {
 
     self.expect_eol()?;
 
-    let body = self.parse_body()?;
+    let mut body = self.parse_body()?;
 
     let shebang = body.first().is_some_and(Line::is_shebang);
     let script = attributes.contains(AttributeDiscriminant::Script);
 
+    if attributes.contains(AttributeDiscriminant::Loops) {
+      if shebang || script {
+        return Err(name.error(CompileErrorKind::LoopsInScriptRecipe {
+          recipe: name.lexeme(),
+        }));
+      }
+
+      self.parse_body_for_loops(&mut body)?;
+    }
+
     if attributes.contains(AttributeDiscriminant::WorkingDirectory)
       && attributes.contains(AttributeDiscriminant::NoCd)
     {
@@ -1148,6 +1738,14 @@ impl<'run, 'src> Parser<'run, 'src> {
       );
     }
 
+    if let Some(attribute) = attributes.get(AttributeDiscriminant::Secret) {
+      return Err(name.error(CompileErrorKind::InvalidAttribute {
+        item_kind: "Recipe",
+        item_name: name.lexeme(),
+        attribute: Box::new(attribute.clone()),
+      }));
+    }
+
     if attributes.contains(AttributeDiscriminant::ExitMessage)
       && attributes.contains(AttributeDiscriminant::NoExitMessage)
     {
@@ -1177,6 +1775,7 @@ impl<'run, 'src> Parser<'run, 'src> {
       doc: doc.filter(|doc| !doc.is_empty()),
       file_depth: self.file_depth,
       import_offsets: self.import_offsets.clone(),
+      module_path: String::new(),
       name,
       namepath: None,
       parameters: positional.into_iter().chain(variadic).collect(),
@@ -1196,6 +1795,13 @@ impl<'run, 'src> Parser<'run, 'src> {
 
     let name = self.parse_name()?;
 
+    let ty = if self.next_is_parameter_type() {
+      self.presume(Colon)?;
+      Some(self.parse_parameter_type()?)
+    } else {
+      None
+    };
+
     let default = if self.accepted(Equals)? {
       Some(self.parse_value()?)
     } else {
@@ -1205,6 +1811,7 @@ impl<'run, 'src> Parser<'run, 'src> {
     let mut help = None;
     let mut long = None;
     let mut pattern = None;
+    let mut secret = false;
     let mut short = None;
     let mut value = None;
 
@@ -1212,6 +1819,7 @@ impl<'run, 'src> Parser<'run, 'src> {
       help = arg.help;
       long = arg.long;
       pattern = arg.pattern;
+      secret = arg.secret;
       short = arg.short;
       value = arg.value;
     }
@@ -1228,11 +1836,58 @@ impl<'run, 'src> Parser<'run, 'src> {
       long,
       name,
       pattern,
+      secret,
       short,
+      ty,
       value,
     })
   }
 
+  /// Check if the next tokens are a parameter type annotation, e.g., the
+  /// `: int` in `foo count: int:`. Since a colon also ends the recipe
+  /// parameter list, an annotation must be followed by another colon on
+  /// the same line.
+  fn next_is_parameter_type(&mut self) -> bool {
+    if !self.next_are(&[Colon, Identifier]) {
+      return false;
+    }
+
+    self
+      .rest()
+      .skip(2)
+      .take_while(|token| !matches!(token.kind, Comment | Eof | Eol))
+      .any(|token| token.kind == Colon)
+  }
+
+  fn parse_parameter_type(&mut self) -> CompileResult<'src, ParameterType<'src>> {
+    let name = self.parse_name()?;
+
+    match name.lexeme() {
+      "bool" => Ok(ParameterType::Bool),
+      "enum" => {
+        self.expect(ParenL)?;
+
+        let mut values = vec![self.parse_string_literal()?];
+
+        while self.accepted(Comma)? {
+          if self.next_is(ParenR) {
+            break;
+          }
+          values.push(self.parse_string_literal()?);
+        }
+
+        self.expect(ParenR)?;
+
+        Ok(ParameterType::Enum(values))
+      }
+      "file" => Ok(ParameterType::File),
+      "int" => Ok(ParameterType::Int),
+      _ => Err(name.error(CompileErrorKind::UnknownParameterType {
+        name: name.lexeme(),
+      })),
+    }
+  }
+
   /// Parse the body of a recipe
   fn parse_body(&mut self) -> CompileResult<'src, Vec<Line<'src>>> {
     let mut lines = Vec::new();
@@ -1261,7 +1916,11 @@ impl<'run, 'src> Parser<'run, 'src> {
           }
         }
 
-        lines.push(Line { fragments, number });
+        lines.push(Line {
+          fragments,
+          kind: LineKind::Command,
+          number,
+        });
       }
     }
 
@@ -1272,6 +1931,123 @@ impl<'run, 'src> Parser<'run, 'src> {
     Ok(lines)
   }
 
+  /// Parse `for` loops in the body of a linewise recipe
+  ///
+  /// A line of the form `for name in list {` starts a loop, which ends at a
+  /// line containing only `}`. Lines inside of loops may be indented.
+  fn parse_body_for_loops(&mut self, body: &mut [Line<'src>]) -> CompileResult<'src> {
+    let mut open = Vec::new();
+    let mut continued = false;
+
+    for i in 0..body.len() {
+      let line = &mut body[i];
+
+      if mem::replace(&mut continued, line.is_continuation()) {
+        continue;
+      }
+
+      let single = line.fragments.len() == 1;
+
+      let Some(Fragment::Text { token }) = line.fragments.first_mut() else {
+        continue;
+      };
+
+      let text = token.lexeme().trim_start();
+
+      let start = single && Self::is_body_for_loop_header(text);
+      let end = single && !open.is_empty() && text.trim_end() == "}";
+
+      if start || end || !open.is_empty() {
+        let indentation = token.length - text.len();
+        token.column += indentation;
+        token.length -= indentation;
+        token.offset += indentation;
+      }
+
+      let token = *token;
+
+      if start {
+        let (name, list) = self.parse_body_for_loop_header(token)?;
+        line.kind = LineKind::ForStart {
+          end: 0,
+          list: list.into(),
+          name,
+        };
+        open.push(i);
+      } else if end {
+        let start = open.pop().unwrap();
+        if let LineKind::ForStart { end, .. } = &mut body[start].kind {
+          *end = i;
+        }
+        body[i].kind = LineKind::ForEnd;
+      }
+    }
+
+    if let Some(&start) = open.last() {
+      if let Some(Fragment::Text { token }) = body[start].fragments.first() {
+        return Err(token.error(CompileErrorKind::UnterminatedForLoop));
+      }
+    }
+
+    Ok(())
+  }
+
+  fn is_body_for_loop_header(text: &str) -> bool {
+    let mut words = text.split_whitespace();
+
+    words.next() == Some(Keyword::For.lexeme())
+      && words.next().is_some_and(Lexer::is_identifier)
+      && words.next() == Some(Keyword::In.lexeme())
+      && text.trim_end().ends_with('{')
+  }
+
+  /// Parse the header of a `for` loop in a recipe body
+  ///
+  /// Recipe bodies are lexed as text, so the header is lexed again, and the
+  /// resulting tokens are relocated to the header's position in the source.
+  fn parse_body_for_loop_header(
+    &mut self,
+    header: Token<'src>,
+  ) -> CompileResult<'src, (Name<'src>, Expression<'src>)> {
+    let relocate = |token: Token<'src>| Token {
+      column: header.column + token.column,
+      line: header.line,
+      offset: header.offset + token.offset,
+      path: header.path,
+      src: header.src,
+      ..token
+    };
+
+    let tokens = Lexer::lex(header.path, header.lexeme().trim_end())
+      .map_err(|error| CompileError {
+        token: relocate(error.token),
+        ..error
+      })?
+      .into_iter()
+      .map(relocate)
+      .collect::<Vec<Token>>();
+
+    let mut parser = Parser {
+      expected_tokens: BTreeSet::new(),
+      file_depth: self.file_depth,
+      function: None,
+      import_offsets: self.import_offsets.clone(),
+      next_token: 0,
+      recursion_depth: 0,
+      tokens: &tokens,
+      unstable_features: BTreeSet::new(),
+      working_directory: self.working_directory,
+    };
+
+    let (name, list) = parser.parse_for_loop_header()?;
+    parser.expect(BraceL)?;
+    parser.expect(Eof)?;
+
+    self.unstable_features.append(&mut parser.unstable_features);
+
+    Ok((name, list))
+  }
+
   /// Parse a boolean setting value
   fn parse_set_bool(&mut self) -> CompileResult<'src, bool> {
     if !self.accepted(ColonEquals)? {
@@ -1315,10 +2091,15 @@ impl<'run, 'src> Parser<'run, 'src> {
       Keyword::DotenvLoad => Some(Setting::DotenvLoad(self.parse_set_bool()?)),
       Keyword::DotenvOverride => Some(Setting::DotenvOverride(self.parse_set_bool()?)),
       Keyword::DotenvRequired => Some(Setting::DotenvRequired(self.parse_set_bool()?)),
+      Keyword::EnvClear => Some(Setting::EnvClear(self.parse_set_bool()?)),
+      Keyword::ExplicitExports => Some(Setting::ExplicitExports(self.parse_set_bool()?)),
       Keyword::Export => Some(Setting::Export(self.parse_set_bool()?)),
       Keyword::Fallback => Some(Setting::Fallback(self.parse_set_bool()?)),
       Keyword::IgnoreComments => Some(Setting::IgnoreComments(self.parse_set_bool()?)),
+      Keyword::InheritSettings => Some(Setting::InheritSettings(self.parse_set_bool()?)),
+      Keyword::Lazy => Some(Setting::Lazy(self.parse_set_bool()?)),
       Keyword::NoExitMessage => Some(Setting::NoExitMessage(self.parse_set_bool()?)),
+      Keyword::ParallelAssignments => Some(Setting::ParallelAssignments(self.parse_set_bool()?)),
       Keyword::PositionalArguments => Some(Setting::PositionalArguments(self.parse_set_bool()?)),
       Keyword::Quiet => Some(Setting::Quiet(self.parse_set_bool()?)),
       Keyword::Unstable => Some(Setting::Unstable(self.parse_set_bool()?)),
@@ -1511,7 +2292,7 @@ mod tests {
   fn test(text: &str, want: Tree) {
     let unindented = unindent(text);
     let tokens = Lexer::test_lex(&unindented).expect("lexing failed");
-    let justfile = Parser::parse(0, &[], None, &tokens, &PathBuf::new()).expect("parsing failed");
+    let justfile = Parser::parse(0, &[], &tokens, &PathBuf::new()).expect("parsing failed");
     let have = justfile.tree();
     if have != want {
       println!("parsed text: {unindented}");
@@ -1550,7 +2331,7 @@ mod tests {
   ) {
     let tokens = Lexer::test_lex(src).expect("Lexing failed in parse test...");
 
-    match Parser::parse(0, &[], None, &tokens, &PathBuf::new()) {
+    match Parser::parse(0, &[], &tokens, &PathBuf::new()) {
       Ok(_) => panic!("Parsing unexpectedly succeeded"),
       Err(have) => {
         let want = CompileError {
@@ -1926,6 +2707,24 @@ mod tests {
     tree: (justfile (assignment x y)),
   }
 
+  test! {
+    name: public_assignment,
+    text: "pub x := y",
+    tree: (justfile (assignment #pub x y)),
+  }
+
+  test! {
+    name: public_export_assignment,
+    text: "pub export x := y",
+    tree: (justfile (assignment #pub #export x y)),
+  }
+
+  test! {
+    name: recipe_named_pub,
+    text: "pub x:",
+    tree: (justfile (recipe pub (params (x)))),
+  }
+
   test! {
     name: comment_export,
     text: "export x := y # foo",
@@ -2462,6 +3261,12 @@ mod tests {
     tree: (justfile (recipe a (body ("foo"))) (recipe b)),
   }
 
+  test! {
+    name: set_explicit_exports_implicit,
+    text: "set explicit-exports",
+    tree: (justfile (set explicit_exports true)),
+  }
+
   test! {
     name: set_export_implicit,
     text: "set export",
@@ -2510,6 +3315,12 @@ mod tests {
     tree: (justfile (set dotenv_load false)),
   }
 
+  test! {
+    name: set_parallel_assignments_implicit,
+    text: "set parallel-assignments",
+    tree: (justfile (set parallel_assignments true)),
+  }
+
   test! {
     name: set_positional_arguments_implicit,
     text: "set positional-arguments",
@@ -2522,6 +3333,24 @@ mod tests {
     tree: (justfile (set positional_arguments true)),
   }
 
+  test! {
+    name: set_inherit_settings_implicit,
+    text: "set inherit-settings",
+    tree: (justfile (set inherit_settings true)),
+  }
+
+  test! {
+    name: set_lazy_implicit,
+    text: "set lazy",
+    tree: (justfile (set lazy true)),
+  }
+
+  test! {
+    name: set_lazy_false,
+    text: "set lazy := false",
+    tree: (justfile (set lazy false)),
+  }
+
   test! {
     name: set_quiet_implicit,
     text: "set quiet",
@@ -2654,6 +3483,24 @@ mod tests {
     tree: (justfile (import "some/file/path.txt")),
   }
 
+  test! {
+    name: git_import,
+    text: "import git(\"../shared.git\", rev = \"v1\", path = \"rust.just\")",
+    tree: (justfile (import (git "../shared.git" (rev "v1") (path "rust.just")))),
+  }
+
+  test! {
+    name: git_import_trailing_comma,
+    text: "import? git(\"shared\", rev = \"v1\",)",
+    tree: (justfile (import ? (git "shared" (rev "v1")))),
+  }
+
+  test! {
+    name: git_module,
+    text: "mod foo git(\"shared\", rev = \"main\") (bar := 'baz')",
+    tree: (justfile (mod foo (git "shared" (rev "main")) (bar "baz"))),
+  }
+
   test! {
     name: optional_import,
     text: "import? \"some/file/path.txt\"     \n",
@@ -2678,6 +3525,18 @@ mod tests {
     tree: (justfile (mod foo "some/file/path.txt")),
   }
 
+  test! {
+    name: module_with_arguments,
+    text: "mod foo (bar := \"a\", baz := 'b')",
+    tree: (justfile (mod foo (bar "a") (baz "b"))),
+  }
+
+  test! {
+    name: module_with_path_and_arguments,
+    text: "mod foo \"some/file/path.txt\" (bar := \"a\",)",
+    tree: (justfile (mod foo "some/file/path.txt" (bar "a"))),
+  }
+
   test! {
     name: optional_module_with_path,
     text: "mod? foo \"some/file/path.txt\"     \n",
@@ -2714,6 +3573,221 @@ mod tests {
     tree: (justfile (assignment foo (format "foo" (+ "abc" "xyz") "bar" "hello" "goodbye"))),
   }
 
+  test! {
+    name: for_loop_expression,
+    text: "a := for x in xs { x + 'y' }",
+    tree: (justfile (assignment a (for x xs (+ x "y")))),
+  }
+
+  test! {
+    name: for_loop_recipe_body,
+    text: "[loops]\nfoo:\n for x in xs {\n  echo {{x}}\n }",
+    tree: (justfile (recipe foo (body ("for x in xs {") ("echo " (x)) ("}")))),
+  }
+
+  error! {
+    name:   git_import_missing_rev,
+    input:  "import git('shared', path = 'x')",
+    offset: 7,
+    line:   0,
+    column: 7,
+    width:  3,
+    kind:   GitImportMissingRev,
+  }
+
+  error! {
+    name:   git_import_unknown_keyword,
+    input:  "import git('shared', branch = 'x')",
+    offset: 21,
+    line:   0,
+    column: 21,
+    width:  6,
+    kind:   UnknownGitImportKeyword { keyword: "branch" },
+  }
+
+  error! {
+    name:   git_import_duplicate_keyword,
+    input:  "import git('shared', rev = 'x', rev = 'y')",
+    offset: 32,
+    line:   0,
+    column: 32,
+    width:  3,
+    kind:   DuplicateGitImportKeyword { keyword: "rev" },
+  }
+
+  error! {
+    name:   module_duplicate_argument,
+    input:  "mod foo (a := 'x', a := 'y')",
+    offset: 19,
+    line:   0,
+    column: 19,
+    width:  1,
+    kind:   DuplicateVariable { variable: "a" },
+  }
+
+  error! {
+    name:   unterminated_for_loop,
+    input:  "[loops]\nfoo:\n for x in xs {\n  echo",
+    offset: 14,
+    line:   2,
+    column: 1,
+    width:  13,
+    kind:   UnterminatedForLoop,
+  }
+
+  error! {
+    name:   for_loop_header_error_is_relocated,
+    input:  "[loops]\nfoo:\n for x in xs ys {\n }",
+    offset: 26,
+    line:   2,
+    column: 13,
+    width:  2,
+    kind:   UnexpectedToken {
+      expected: vec![
+        AmpersandAmpersand,
+        Asterisk,
+        BarBar,
+        BraceL,
+        ColonColon,
+        Minus,
+        ParenL,
+        Percent,
+        Plus,
+        Slash,
+      ],
+      found: Identifier,
+    },
+  }
+
+  test! {
+    name: match_expression,
+    text: "a := match x { 'y*' => 'z', /^(?<w>.)$/ => w, _ => '' }",
+    tree: (justfile (assignment a (match x ("y*" "z") ((regex "^(?<w>.)$") w) (_ "")))),
+  }
+
+  error! {
+    name:   match_without_wildcard,
+    input:  "a := match x { 'y' => 'z' }",
+    offset: 5,
+    line:   0,
+    column: 5,
+    width:  5,
+    kind:   MatchWithoutWildcard,
+  }
+
+  error! {
+    name:   match_arm_after_wildcard,
+    input:  "a := match x { _ => 'z', 'y' => 'z' }",
+    offset: 25,
+    line:   0,
+    column: 25,
+    width:  3,
+    kind:   MatchArmUnreachable,
+  }
+
+  test! {
+    name: function,
+    text: "fn f(a, b) := a + b",
+    tree: (justfile (fn f (a b) (+ a b))),
+  }
+
+  test! {
+    name: function_without_parameters,
+    text: "fn f() := 'a'",
+    tree: (justfile (fn f () "a")),
+  }
+
+  test! {
+    name: module_function_call,
+    text: "x := foo::bar::baz('a')",
+    tree: (justfile (assignment x (call (foo bar baz) "a"))),
+  }
+
+  test! {
+    name: module_variable,
+    text: "x := foo::bar::baz + 'a'",
+    tree: (justfile (assignment x (+ (foo bar baz) "a"))),
+  }
+
+  test! {
+    name: recipe_named_fn,
+    text: "fn a:",
+    tree: (justfile (recipe fn (params (a)))),
+  }
+
+  test! {
+    name: recipe_parameter_types,
+    text: r#"foo a: bool b: enum("x", 'y') c: file d: int="1":"#,
+    tree: (justfile (recipe foo (params
+      (a (type bool))
+      (b (type enum "x" "y"))
+      (c (type file))
+      (d (type int) "1")
+    ))),
+  }
+
+  test! {
+    name: recipe_parameter_type_with_dependency,
+    text: "foo a: int: bar",
+    tree: (justfile (recipe foo (params (a (type int))) (deps bar))),
+  }
+
+  test! {
+    name: recipe_parameter_without_type,
+    text: "foo a: int",
+    tree: (justfile (recipe foo (params (a)) (deps int))),
+  }
+
+  error! {
+    name: unknown_parameter_type,
+    input: "foo a: float:",
+    offset: 7,
+    line: 0,
+    column: 7,
+    width: 5,
+    kind: UnknownParameterType { name: "float" },
+  }
+
+  test! {
+    name: field_access,
+    text: "a := b.c.d",
+    tree: (justfile (assignment a (field (field b c) d))),
+  }
+
+  test! {
+    name: index_access,
+    text: "a := b['c' + d][e]",
+    tree: (justfile (assignment a (index (index b (+ "c" d)) e))),
+  }
+
+  test! {
+    name: access_binds_tighter_than_operators,
+    text: "a := b.c + f(d)[e]",
+    tree: (justfile (assignment a (+ (field b c) (index (call f d) e)))),
+  }
+
+  test! {
+    name: arithmetic_precedence,
+    text: "a := b - c * d % e + f",
+    tree: (justfile (assignment a (+ (- b (% (* c d) e)) f))),
+  }
+
+  test! {
+    name: arithmetic_comparison,
+    text: "a := if b < c { d } else if b >= c { e } else { f }",
+    tree: (justfile (assignment a (if b < c d (if b >= c e f)))),
+  }
+
+  error! {
+    name:   integer_literal_overflow,
+    input:  "a := 99999999999999999999",
+    offset: 5,
+    line:   0,
+    column: 5,
+    width:  20,
+    kind:   IntegerLiteralOverflow,
+  }
+
   error! {
     name:   alias_syntax_multiple_rhs,
     input:  "alias foo := bar baz",
@@ -2777,7 +3851,9 @@ mod tests {
     kind:   UnexpectedToken {
       expected: vec![
         Backtick,
+        BracketL,
         Identifier,
+        Integer,
         ParenL,
         StringToken,
       ],
@@ -2795,7 +3871,9 @@ mod tests {
     kind:   UnexpectedToken {
       expected: vec![
         Backtick,
+        BracketL,
         Identifier,
+        Integer,
         ParenL,
         StringToken,
       ],
@@ -2839,7 +3917,9 @@ mod tests {
     kind: UnexpectedToken{
       expected: vec![
         Backtick,
+        BracketL,
         Identifier,
+        Integer,
         ParenL,
         ParenR,
         Slash,
@@ -2922,7 +4002,9 @@ mod tests {
     kind:   UnexpectedToken {
       expected: vec![
         Backtick,
+        BracketL,
         Identifier,
+        Integer,
         ParenL,
         Slash,
         StringToken,
@@ -2941,8 +4023,10 @@ mod tests {
     kind:   UnexpectedToken {
       expected: vec![
         Backtick,
+        BracketL,
         BracketR,
         Identifier,
+        Integer,
         ParenL,
         Slash,
         StringToken,
@@ -2959,7 +4043,17 @@ mod tests {
     column: 20,
     width:  0,
     kind:   UnexpectedToken {
-      expected: vec![AmpersandAmpersand, BarBar, BracketR, Comma, Plus, Slash],
+      expected: vec![
+        AmpersandAmpersand,
+        Asterisk,
+        BarBar,
+        BracketR,
+        Comma,
+        Minus,
+        Percent,
+        Plus,
+        Slash,
+      ],
       found: Eof,
     },
   }
@@ -3011,36 +4105,6 @@ mod tests {
     },
   }
 
-  error! {
-    name:   unknown_function,
-    input:  "a := foo()",
-    offset: 5,
-    line:   0,
-    column: 5,
-    width:  3,
-    kind:   UnknownFunction{function: "foo"},
-  }
-
-  error! {
-    name:   unknown_function_in_interpolation,
-    input:  "a:\n echo {{bar()}}",
-    offset: 11,
-    line:   1,
-    column: 8,
-    width:  3,
-    kind:   UnknownFunction{function: "bar"},
-  }
-
-  error! {
-    name:   unknown_function_in_default,
-    input:  "a f=baz():",
-    offset: 4,
-    line:   0,
-    column: 4,
-    width:  3,
-    kind:   UnknownFunction{function: "baz"},
-  }
-
   error! {
     name: function_argument_count_nullary,
     input: "x := arch('foo')",
@@ -5,6 +5,7 @@ fn tap_stream_sink<'a>(
   line_buf: &'a Mutex<Vec<u8>>,
   is_tap_subtest: &'a Mutex<Option<bool>>,
   recipe_name: &'a str,
+  secrets: &'a Secrets,
   test_point_number: usize,
 ) -> impl Fn(&[u8]) -> io::Result<()> + 'a {
   move |chunk| {
@@ -13,7 +14,8 @@ fn tap_stream_sink<'a>(
     let mut stdout = stdout_lock.lock();
     while let Some(pos) = buf.iter().position(|&b| b == b'\n' || b == b'\r') {
       let line = String::from_utf8_lossy(&buf[..pos]);
-      let line = line.trim_end();
+      let line = secrets.mask(line.trim_end());
+      let line = line.as_ref();
       if !is_visually_empty(line) {
         let mut is_sub = is_tap_subtest.lock().unwrap();
         if is_sub.is_none() {
@@ -184,6 +186,40 @@ fn stream_command_output_piped(
   })
 }
 
+/// Capture command output while streaming it to stderr a line at a time, with
+/// secrets masked
+fn stream_command_output_to_stderr(
+  cmd: Command,
+  secrets: &Secrets,
+) -> (io::Result<process::Output>, Option<Signal>) {
+  let line_buf = Mutex::new(Vec::<u8>::new());
+
+  let write_masked = |bytes: &[u8]| {
+    let text = String::from_utf8_lossy(bytes);
+    io::stderr()
+      .lock()
+      .write_all(secrets.mask(&text).as_bytes())
+  };
+
+  let result = stream_command_output(cmd, &|chunk| {
+    let mut buf = line_buf.lock().unwrap();
+    buf.extend_from_slice(chunk);
+    while let Some(pos) = buf.iter().position(|&b| b == b'\n') {
+      write_masked(&buf[..=pos])?;
+      buf.drain(..=pos);
+    }
+    Ok(())
+  });
+
+  let rest = line_buf.into_inner().unwrap();
+
+  if !rest.is_empty() {
+    let _ = write_masked(&rest);
+  }
+
+  result
+}
+
 /// Return a `Error::Signal` if the process was terminated by a signal,
 /// otherwise return an `Error::UnknownFailure`
 fn error_from_signal(recipe: &str, line_number: Option<usize>, exit_status: ExitStatus) -> Error {
@@ -211,6 +247,10 @@ pub(crate) struct Recipe<'src, D = Dependency<'src>> {
   pub(crate) file_depth: u32,
   #[serde(skip)]
   pub(crate) import_offsets: Vec<usize>,
+  /// Path of the module containing this recipe, which is empty until the
+  /// recipe is resolved
+  #[serde(skip)]
+  pub(crate) module_path: String,
   pub(crate) name: Name<'src>,
   pub(crate) namepath: Option<String>,
   pub(crate) parameters: Vec<Parameter<'src>>,
@@ -221,11 +261,6 @@ pub(crate) struct Recipe<'src, D = Dependency<'src>> {
 }
 
 impl Recipe<'_> {
-  pub(crate) fn module_path(&self) -> &str {
-    let namepath = self.namepath();
-    &namepath[0..namepath.rfind("::").unwrap_or_default()]
-  }
-
   pub(crate) fn namepath(&self) -> &str {
     self.namepath.as_ref().unwrap()
   }
@@ -314,6 +349,14 @@ impl<'src, D> Recipe<'src, D> {
     Ok(())
   }
 
+  pub(crate) fn is_exported(&self) -> bool {
+    self.attributes.contains(AttributeDiscriminant::Export)
+  }
+
+  pub(crate) fn is_override(&self) -> bool {
+    self.attributes.contains(AttributeDiscriminant::Override)
+  }
+
   pub(crate) fn is_parallel(&self) -> bool {
     self.attributes.contains(AttributeDiscriminant::Parallel)
   }
@@ -326,6 +369,23 @@ impl<'src, D> Recipe<'src, D> {
     self.shebang
   }
 
+  pub(crate) fn is_service(&self) -> bool {
+    self.attributes.contains(AttributeDiscriminant::Service)
+  }
+
+  /// Seconds to wait for a `[service]` recipe to become ready
+  pub(crate) fn service_timeout(&self) -> u64 {
+    if let Some(Attribute::Service {
+      timeout: Some(timeout),
+      ..
+    }) = self.attributes.get(AttributeDiscriminant::Service)
+    {
+      timeout.cooked.parse().unwrap_or(Services::DEFAULT_TIMEOUT)
+    } else {
+      Services::DEFAULT_TIMEOUT
+    }
+  }
+
   pub(crate) fn takes_positional_arguments(&self, settings: &Settings) -> bool {
     settings.positional_arguments
       || self
@@ -353,6 +413,67 @@ impl<'src, D> Recipe<'src, D> {
       || (cfg!(windows) && windows)
   }
 
+  /// Environment variables to pass through from `just`'s environment, if the
+  /// recipe runs with a cleared environment, either because `env-clear` is
+  /// set or because the recipe has `[env-pass]` attributes.
+  fn env_pass(&self, settings: &Settings) -> Option<Vec<&str>> {
+    let mut names = settings.env_clear.then(Vec::new);
+
+    for attribute in &self.attributes {
+      if let Attribute::EnvPass(pass) = attribute {
+        names
+          .get_or_insert_with(Vec::new)
+          .extend(pass.iter().map(|name| name.cooked.as_str()));
+      }
+    }
+
+    names
+  }
+
+  /// Names of recipes from `[finally]` attributes
+  pub(crate) fn finally(&self) -> impl Iterator<Item = &str> {
+    self
+      .finally_literals()
+      .map(|literal| literal.cooked.as_str())
+  }
+
+  pub(crate) fn finally_literals(&self) -> impl Iterator<Item = &StringLiteral<'src>> {
+    self
+      .attributes
+      .iter()
+      .filter_map(|attribute| {
+        if let Attribute::Finally(recipes) = attribute {
+          Some(recipes)
+        } else {
+          None
+        }
+      })
+      .flatten()
+  }
+
+  /// Globs from `[watch]` attributes, relative to the directory of the source
+  /// file containing the attribute
+  pub(crate) fn watch_globs(&self) -> impl Iterator<Item = Glob> + '_ {
+    self
+      .attributes
+      .iter()
+      .filter_map(|attribute| {
+        if let Attribute::Watch(patterns) = attribute {
+          Some(patterns)
+        } else {
+          None
+        }
+      })
+      .flatten()
+      .map(|pattern| {
+        Glob::new(
+          pattern.token.path.parent().unwrap_or(Path::new("")),
+          &pattern.cooked,
+        )
+        .expect("`[watch]` globs are checked when parsed")
+      })
+  }
+
   fn print_exit_message(&self, settings: &Settings) -> bool {
     if self.attributes.contains(AttributeDiscriminant::ExitMessage) {
       true
@@ -381,6 +502,73 @@ impl<'src, D> Recipe<'src, D> {
     Some(working_directory)
   }
 
+  /// Check a `[service]` recipe's readiness probes
+  pub(crate) fn service_ready(&self, context: &ExecutionContext<'src, '_>, scope: &Scope) -> bool {
+    let Some(Attribute::Service {
+      command,
+      file,
+      port,
+      ..
+    }) = self.attributes.get(AttributeDiscriminant::Service)
+    else {
+      return true;
+    };
+
+    let working_directory = self
+      .working_directory(context)
+      .unwrap_or_else(|| context.working_directory());
+
+    if let Some(file) = file {
+      if !working_directory.join(&file.cooked).exists() {
+        return false;
+      }
+    }
+
+    if let Some(port) = port {
+      let address = if port.cooked.contains(':') {
+        port.cooked.clone()
+      } else {
+        format!("localhost:{}", port.cooked)
+      };
+
+      let connected = address.to_socket_addrs().is_ok_and(|mut addresses| {
+        addresses
+          .any(|address| TcpStream::connect_timeout(&address, Duration::from_secs(1)).is_ok())
+      });
+
+      if !connected {
+        return false;
+      }
+    }
+
+    if let Some(command) = command {
+      let mut cmd = context.module.settings.shell_command(context.config);
+
+      if let Some(names) = self.env_pass(&context.module.settings) {
+        cmd.clear_env_except(&names);
+      }
+
+      cmd
+        .arg(&command.cooked)
+        .current_dir(&working_directory)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .export(
+          &context.module.settings,
+          context.dotenv,
+          scope,
+          &context.module.unexports,
+        );
+
+      if !cmd.status_guard().0.is_ok_and(|status| status.success()) {
+        return false;
+      }
+    }
+
+    true
+  }
+
   pub(crate) fn no_quiet(&self) -> bool {
     self.attributes.contains(AttributeDiscriminant::NoQuiet)
   }
@@ -411,6 +599,8 @@ impl<'src, D> Recipe<'src, D> {
 
     let evaluator = Evaluator::new(context, BTreeMap::new(), is_dependency, scope);
 
+    self.render_templates(context, scope, is_dependency)?;
+
     if self.is_script() {
       self.run_script(
         context,
@@ -434,6 +624,94 @@ impl<'src, D> Recipe<'src, D> {
     }
   }
 
+  /// Render the templates of the recipe's `[render]` attributes
+  fn render_templates<'run>(
+    &self,
+    context: &ExecutionContext<'src, 'run>,
+    scope: &Scope<'src, 'run>,
+    is_dependency: bool,
+  ) -> RunResult<'src> {
+    let working_directory = self
+      .working_directory(context)
+      .unwrap_or_else(|| context.working_directory());
+
+    for attribute in &self.attributes {
+      let Attribute::Render(output, template) = attribute else {
+        continue;
+      };
+
+      if context.config.dry_run {
+        eprintln!("[{attribute}]");
+        continue;
+      }
+
+      let path = working_directory.join(&template.cooked);
+
+      let text = fs::read_to_string(&path).map_err(|io_error| Error::RenderIo {
+        io_error,
+        path,
+        recipe: self.name(),
+      })?;
+
+      let rendered = Template::render(
+        context,
+        scope,
+        is_dependency,
+        Path::new(&template.cooked),
+        &text,
+      )
+      .map_err(|message| Error::Template { message })?;
+
+      let path = working_directory.join(&output.cooked);
+
+      fs::write(&path, rendered).map_err(|io_error| Error::RenderIo {
+        io_error,
+        path,
+        recipe: self.name(),
+      })?;
+    }
+
+    Ok(())
+  }
+
+  /// Expand the `for` loops in `range` of the body into the lines to run,
+  /// along with the loop variables to bind when evaluating each line
+  fn expand_for_loops<'body>(
+    &'body self,
+    evaluator: &mut Evaluator<'src, '_>,
+    range: Range<usize>,
+    bindings: &mut Vec<(Name<'src>, Value)>,
+    expanded: &mut Vec<(&'body Line<'src>, Vec<(Name<'src>, Value)>)>,
+  ) -> RunResult<'src> {
+    let mut i = range.start;
+
+    while i < range.end {
+      let line = &self.body[i];
+
+      match &line.kind {
+        LineKind::Command => {
+          expanded.push((line, bindings.clone()));
+          i += 1;
+        }
+        LineKind::ForEnd => return Err(Error::internal("unmatched end of `for` loop")),
+        LineKind::ForStart { end, list, name } => {
+          let list =
+            evaluator.with_bindings(bindings, |evaluator| evaluator.evaluate_value(list))?;
+
+          for element in list.into_elements() {
+            bindings.push((*name, element));
+            self.expand_for_loops(evaluator, i + 1..*end, bindings, expanded)?;
+            bindings.pop();
+          }
+
+          i = end + 1;
+        }
+      }
+    }
+
+    Ok(())
+  }
+
   fn run_linewise<'run>(
     &self,
     context: &ExecutionContext<'src, 'run>,
@@ -446,8 +724,16 @@ impl<'src, D> Recipe<'src, D> {
   ) -> RunResult<'src, ()> {
     let config = &context.config;
 
-    let mut lines = self.body.iter().peekable();
-    let mut line_number = self.line_number() + 1;
+    let mut expanded = Vec::new();
+    self.expand_for_loops(
+      &mut evaluator,
+      0..self.body.len(),
+      &mut Vec::new(),
+      &mut expanded,
+    )?;
+
+    let mut lines = expanded.iter().peekable();
+    let mut line_number = 0;
     let is_tap_subtest = Mutex::new(Option::<bool>::None);
     loop {
       if lines.peek().is_none() {
@@ -455,20 +741,22 @@ impl<'src, D> Recipe<'src, D> {
       }
       let mut evaluated = String::new();
       let mut continued = false;
-      let quiet_line = lines.peek().is_some_and(|line| line.is_quiet());
-      let infallible_line = lines.peek().is_some_and(|line| line.is_infallible());
+      let quiet_line = lines.peek().is_some_and(|(line, _)| line.is_quiet());
+      let infallible_line = lines.peek().is_some_and(|(line, _)| line.is_infallible());
 
       let comment_line = context.module.settings.ignore_comments
-        && lines.peek().is_some_and(|line| line.is_comment());
+        && lines.peek().is_some_and(|(line, _)| line.is_comment());
 
       loop {
         if lines.peek().is_none() {
           break;
         }
-        let line = lines.next().unwrap();
-        line_number += 1;
+        let (line, bindings) = lines.next().unwrap();
+        line_number = line.number + 1;
         if !comment_line {
-          evaluated += &evaluator.evaluate_line(line, continued)?;
+          evaluated += &evaluator.with_bindings(bindings, |evaluator| {
+            evaluator.evaluate_line(line, continued)
+          })?;
         }
         if line.is_continuation() && !comment_line {
           continued = true;
@@ -510,7 +798,7 @@ impl<'src, D> Recipe<'src, D> {
           eprint!("[{}] ", color.paint(&timestamp));
         }
 
-        eprintln!("{}", color.paint(command));
+        eprintln!("{}", color.paint(&context.secrets.mask(command)));
       }
 
       if config.dry_run {
@@ -535,6 +823,10 @@ impl<'src, D> Recipe<'src, D> {
         cmd.stdout(Stdio::null());
       }
 
+      if let Some(names) = self.env_pass(&context.module.settings) {
+        cmd.clear_env_except(&names);
+      }
+
       for attribute in &self.attributes {
         if let Attribute::Env(key, value) = attribute {
           cmd.env(&key.cooked, &value.cooked);
@@ -548,6 +840,10 @@ impl<'src, D> Recipe<'src, D> {
         &context.module.unexports,
       );
 
+      if self.is_service() {
+        cmd.new_process_group();
+      }
+
       if tap_output.is_some() {
         let (result, caught) = match output_format {
           OutputFormat::Tap => capture_command_output(cmd),
@@ -560,17 +856,12 @@ impl<'src, D> Recipe<'src, D> {
               &line_buf,
               &is_tap_subtest,
               recipe_name,
+              context.secrets,
               tap_test_number.unwrap_or(0),
             );
             stream_command_output(cmd, &sink)
           }
-          OutputFormat::TapStderr => {
-            let stderr_lock = io::stderr();
-            stream_command_output(cmd, &|chunk| {
-              let mut stderr = stderr_lock.lock();
-              stderr.write_all(chunk)
-            })
-          }
+          OutputFormat::TapStderr => stream_command_output_to_stderr(cmd, context.secrets),
           OutputFormat::Default => unreachable!(),
         };
 
@@ -689,7 +980,7 @@ impl<'src, D> Recipe<'src, D> {
             .color
             .command(config.command_color)
             .stderr()
-            .paint(line)
+            .paint(&context.secrets.mask(line))
         );
       }
     }
@@ -743,7 +1034,14 @@ impl<'src, D> Recipe<'src, D> {
     let script = executor.script(self, &evaluated_lines);
 
     if config.verbosity.grandiloquent() {
-      eprintln!("{}", config.color.doc().stderr().paint(&script));
+      eprintln!(
+        "{}",
+        config
+          .color
+          .doc()
+          .stderr()
+          .paint(&context.secrets.mask(&script))
+      );
     }
 
     fs::write(&path, script).map_err(|error| Error::TempdirIo {
@@ -762,6 +1060,10 @@ impl<'src, D> Recipe<'src, D> {
       command.args(positional);
     }
 
+    if let Some(names) = self.env_pass(&context.module.settings) {
+      command.clear_env_except(&names);
+    }
+
     for attribute in &self.attributes {
       if let Attribute::Env(key, value) = attribute {
         command.env(&key.cooked, &value.cooked);
@@ -775,6 +1077,10 @@ impl<'src, D> Recipe<'src, D> {
       &context.module.unexports,
     );
 
+    if self.is_service() {
+      command.new_process_group();
+    }
+
     if tap_output.is_some() {
       let (result, caught) = match output_format {
         OutputFormat::Tap => capture_command_output(command),
@@ -788,17 +1094,12 @@ impl<'src, D> Recipe<'src, D> {
             &line_buf,
             &is_tap_subtest,
             recipe_name,
+            context.secrets,
             tap_test_number.unwrap_or(0),
           );
           stream_command_output(command, &sink)
         }
-        OutputFormat::TapStderr => {
-          let stderr_lock = io::stderr();
-          stream_command_output(command, &|chunk| {
-            let mut stderr = stderr_lock.lock();
-            stderr.write_all(chunk)
-          })
-        }
+        OutputFormat::TapStderr => stream_command_output_to_stderr(command, context.secrets),
         OutputFormat::Default => unreachable!(),
       };
 
@@ -931,19 +1232,27 @@ impl<D: Display> ColorDisplay for Recipe<'_, D> {
       write!(f, " {dependency}")?;
     }
 
+    let mut depth = 0;
+
     for (i, line) in self.body.iter().enumerate() {
       if i == 0 {
         writeln!(f)?;
       }
+      if line.kind == LineKind::ForEnd {
+        depth -= 1;
+      }
       for (j, fragment) in line.fragments.iter().enumerate() {
         if j == 0 {
-          write!(f, "    ")?;
+          write!(f, "    {}", "  ".repeat(depth))?;
         }
         match fragment {
           Fragment::Text { token } => write!(f, "{}", token.lexeme())?,
           Fragment::Interpolation { expression, .. } => write!(f, "{{{{ {expression} }}}}")?,
         }
       }
+      if let LineKind::ForStart { .. } = line.kind {
+        depth += 1;
+      }
       if i + 1 < self.body.len() {
         writeln!(f)?;
       }
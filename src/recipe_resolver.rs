@@ -4,6 +4,8 @@ pub(crate) struct RecipeResolver<'src: 'run, 'run> {
   assignments: &'run Table<'src, Assignment<'src>>,
   module_path: &'run str,
   modules: &'run Table<'src, Justfile<'src>>,
+  overridden_recipes: Table<'src, UnresolvedRecipe<'src>>,
+  resolved_overridden_recipes: Table<'src, Arc<Recipe<'src>>>,
   resolved_recipes: Table<'src, Arc<Recipe<'src>>>,
   unresolved_recipes: Table<'src, UnresolvedRecipe<'src>>,
 }
@@ -13,6 +15,7 @@ impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
     assignments: &'run Table<'src, Assignment<'src>>,
     module_path: &'run str,
     modules: &'run Table<'src, Justfile<'src>>,
+    overridden_recipes: Table<'src, UnresolvedRecipe<'src>>,
     settings: &Settings,
     unresolved_recipes: Table<'src, UnresolvedRecipe<'src>>,
   ) -> CompileResult<'src, Table<'src, Arc<Recipe<'src>>>> {
@@ -20,6 +23,8 @@ impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
       assignments,
       module_path,
       modules,
+      overridden_recipes,
+      resolved_overridden_recipes: Table::new(),
       resolved_recipes: Table::new(),
       unresolved_recipes,
     };
@@ -28,7 +33,15 @@ impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
       resolver.resolve_recipe(&mut Vec::new(), unresolved)?;
     }
 
-    for recipe in resolver.resolved_recipes.values() {
+    while let Some(unresolved) = resolver.overridden_recipes.pop() {
+      resolver.resolve_overridden_recipe(&mut Vec::new(), unresolved)?;
+    }
+
+    for recipe in resolver
+      .resolved_recipes
+      .values()
+      .chain(resolver.resolved_overridden_recipes.values())
+    {
       for (i, parameter) in recipe.parameters.iter().enumerate() {
         if let Some(expression) = &parameter.default {
           for variable in expression.variables() {
@@ -37,6 +50,22 @@ impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
         }
       }
 
+      for literal in recipe.finally_literals() {
+        let Some(finally) = resolver.resolved_recipes.get(&literal.cooked) else {
+          return Err(literal.token.error(FinallyRecipeUnknown {
+            recipe: recipe.name(),
+            unknown: literal.cooked.clone(),
+          }));
+        };
+
+        if finally.min_arguments() > 0 {
+          return Err(literal.token.error(FinallyRecipeParameters {
+            finally: finally.name(),
+            recipe: recipe.name(),
+          }));
+        }
+      }
+
       for dependency in &recipe.dependencies {
         for group in &dependency.arguments {
           for argument in group {
@@ -47,7 +76,26 @@ impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
         }
       }
 
+      let mut loop_variables = Vec::new();
+
       for line in &recipe.body {
+        match &line.kind {
+          LineKind::Command => {}
+          LineKind::ForEnd => {
+            loop_variables.pop();
+            continue;
+          }
+          LineKind::ForStart { list, name, .. } => {
+            for variable in list.variables() {
+              if !loop_variables.contains(&variable.lexeme()) {
+                resolver.resolve_variable(&variable, &recipe.parameters)?;
+              }
+            }
+            loop_variables.push(name.lexeme());
+            continue;
+          }
+        }
+
         if line.is_comment() && settings.ignore_comments {
           continue;
         }
@@ -55,7 +103,9 @@ impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
         for fragment in &line.fragments {
           if let Fragment::Interpolation { expression, .. } = fragment {
             for variable in expression.variables() {
-              resolver.resolve_variable(&variable, &recipe.parameters)?;
+              if !loop_variables.contains(&variable.lexeme()) {
+                resolver.resolve_variable(&variable, &recipe.parameters)?;
+              }
             }
           }
         }
@@ -93,13 +143,43 @@ impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
     }
 
     stack.push(recipe.name());
+    let dependencies = self.resolve_dependencies(&recipe, stack)?;
+    stack.pop();
+
+    let resolved = Arc::new(recipe.resolve(self.module_path, false, dependencies)?);
+    self.resolved_recipes.insert(Arc::clone(&resolved));
+    Ok(resolved)
+  }
+
+  /// Resolve a recipe replaced by an `[override]` recipe, which is kept so
+  /// that it can be run as a `super::NAME` dependency
+  fn resolve_overridden_recipe(
+    &mut self,
+    stack: &mut Vec<&'src str>,
+    recipe: UnresolvedRecipe<'src>,
+  ) -> CompileResult<'src, Arc<Recipe<'src>>> {
+    stack.push(recipe.name());
+    let dependencies = self.resolve_dependencies(&recipe, stack)?;
+    stack.pop();
 
-    let dependencies = recipe
+    let resolved = Arc::new(recipe.resolve(self.module_path, true, dependencies)?);
+    self
+      .resolved_overridden_recipes
+      .insert(Arc::clone(&resolved));
+    Ok(resolved)
+  }
+
+  fn resolve_dependencies(
+    &mut self,
+    recipe: &UnresolvedRecipe<'src>,
+    stack: &mut Vec<&'src str>,
+  ) -> CompileResult<'src, Vec<Arc<Recipe<'src>>>> {
+    recipe
       .dependencies
       .iter()
       .map(|dependency| {
         self
-          .resolve_dependency(dependency, &recipe, stack)?
+          .resolve_dependency(dependency, recipe, stack)?
           .ok_or_else(|| {
             dependency.recipe.last().error(UnknownDependency {
               recipe: recipe.name(),
@@ -107,13 +187,7 @@ impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
             })
           })
       })
-      .collect::<CompileResult<Vec<Arc<Recipe>>>>()?;
-
-    stack.pop();
-
-    let resolved = Arc::new(recipe.resolve(self.module_path, dependencies)?);
-    self.resolved_recipes.insert(Arc::clone(&resolved));
-    Ok(resolved)
+      .collect()
   }
 
   fn resolve_dependency(
@@ -122,15 +196,22 @@ impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
     recipe: &UnresolvedRecipe<'src>,
     stack: &mut Vec<&'src str>,
   ) -> CompileResult<'src, Option<Arc<Recipe<'src>>>> {
-    let name = dependency.recipe.last().lexeme();
+    let (last, path) = dependency.recipe.split_last();
 
-    if dependency.recipe.components() > 1 {
+    let name = last.lexeme();
+
+    if matches!(path, [prefix] if prefix.lexeme() == Keyword::Super.lexeme()) {
+      // recipe was overridden by an `[override]` recipe
+      if let Some(resolved) = self.resolved_overridden_recipes.get(name) {
+        Ok(Some(Arc::clone(resolved)))
+      } else if let Some(unresolved) = self.overridden_recipes.remove(name) {
+        Ok(Some(self.resolve_overridden_recipe(stack, unresolved)?))
+      } else {
+        Ok(None)
+      }
+    } else if dependency.recipe.components() > 1 {
       // recipe is in a submodule and is thus already resolved
-      Ok(Analyzer::resolve_recipe(
-        &dependency.recipe,
-        self.modules,
-        &self.resolved_recipes,
-      ))
+      Analyzer::resolve_recipe(&dependency.recipe, self.modules, &self.resolved_recipes)
     } else if let Some(resolved) = self.resolved_recipes.get(name) {
       // recipe is the current module and has already been resolved
       Ok(Some(Arc::clone(resolved)))
@@ -22,14 +22,19 @@ pub fn run(args: impl Iterator<Item = impl Into<OsString> + Clone>) -> Result<()
 
   let loader = Loader::new();
 
+  let secrets = Secrets::default();
+
   config
     .and_then(|config| {
       SignalHandler::install(config.verbosity)?;
-      config.subcommand.execute(&config, &loader)
+      config.subcommand.execute(&config, &loader, &secrets)
     })
     .map_err(|error| {
       if !verbosity.quiet() && error.print_message() {
-        eprintln!("{}", error.color_display(color.stderr()));
+        eprintln!(
+          "{}",
+          secrets.mask(&error.color_display(color.stderr()).to_string())
+        );
       }
       error.code().unwrap_or(EXIT_FAILURE)
     })
@@ -2,22 +2,47 @@ use super::*;
 
 #[derive(Debug)]
 pub(crate) struct Scope<'src: 'run, 'run> {
-  bindings: Table<'src, Binding<'src, String>>,
+  bindings: Table<'src, Binding<'src>>,
+  deferred: Option<Mutex<BTreeMap<String, Value>>>,
   parent: Option<&'run Self>,
 }
 
+impl Clone for Scope<'_, '_> {
+  fn clone(&self) -> Self {
+    Self {
+      bindings: self.bindings.clone(),
+      deferred: self
+        .deferred
+        .as_ref()
+        .map(|deferred| Mutex::new(deferred.lock().unwrap().clone())),
+      parent: self.parent,
+    }
+  }
+}
+
 impl<'src, 'run> Scope<'src, 'run> {
   pub(crate) fn child(&'run self) -> Self {
     Self {
-      parent: Some(self),
       bindings: Table::new(),
+      deferred: None,
+      parent: Some(self),
+    }
+  }
+
+  /// Create a child scope whose assignments are evaluated on first reference
+  /// and memoized in the returned scope
+  pub(crate) fn deferred_child(&'run self) -> Self {
+    Self {
+      deferred: Some(Mutex::new(BTreeMap::new())),
+      ..self.child()
     }
   }
 
   pub(crate) fn root() -> Self {
     let mut root = Self {
-      parent: None,
       bindings: Table::new(),
+      deferred: None,
+      parent: None,
     };
 
     for (key, value) in constants() {
@@ -37,6 +62,8 @@ impl<'src, 'run> Scope<'src, 'run> {
         },
         prelude: true,
         private: false,
+        public: false,
+        secret: false,
         value: (*value).into(),
       });
     }
@@ -52,15 +79,35 @@ impl<'src, 'run> Scope<'src, 'run> {
     self.bindings.contains_key(name)
   }
 
-  pub(crate) fn value(&self, name: &str) -> Option<&str> {
+  pub(crate) fn value(&self, name: &str) -> Option<&Value> {
     if let Some(binding) = self.bindings.get(name) {
-      Some(binding.value.as_ref())
+      Some(&binding.value)
     } else {
       self.parent?.value(name)
     }
   }
 
-  pub(crate) fn bindings(&self) -> impl Iterator<Item = &Binding<String>> {
+  /// The nearest scope, starting with this one, which defers evaluation of
+  /// assignments
+  pub(crate) fn deferring(&self) -> Option<&Self> {
+    if self.deferred.is_some() {
+      Some(self)
+    } else {
+      self.parent?.deferring()
+    }
+  }
+
+  pub(crate) fn deferred_value(&self, name: &str) -> Option<Value> {
+    self.deferred.as_ref()?.lock().unwrap().get(name).cloned()
+  }
+
+  pub(crate) fn defer(&self, name: &str, value: Value) {
+    if let Some(deferred) = &self.deferred {
+      deferred.lock().unwrap().insert(name.into(), value);
+    }
+  }
+
+  pub(crate) fn bindings(&self) -> impl Iterator<Item = &Binding<'src>> {
     self.bindings.values()
   }
 
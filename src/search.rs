@@ -217,7 +217,7 @@ impl Search {
   /// Search upwards from `directory` for the root directory of a software
   /// project, as determined by the presence of one of the version control
   /// system directories given in `PROJECT_ROOT_CHILDREN`
-  fn project_root(ceiling: Option<&Path>, directory: &Path) -> SearchResult<PathBuf> {
+  pub(crate) fn project_root(ceiling: Option<&Path>, directory: &Path) -> SearchResult<PathBuf> {
     for directory in directory.ancestors() {
       let entries = fs::read_dir(directory).map_err(|io_error| SearchError::Io {
         io_error,
@@ -3,6 +3,11 @@ use super::*;
 #[derive(Debug, Snafu)]
 #[snafu(visibility(pub(crate)))]
 pub(crate) enum SearchError {
+  #[snafu(display("Invalid workspace filter `{filter}`: {source}"))]
+  Filter {
+    filter: String,
+    source: globset::Error,
+  },
   #[snafu(display("Cannot initialize global justfile"))]
   GlobalJustfileInit,
   #[snafu(display("Global justfile not found"))]
@@ -30,6 +35,11 @@ pub(crate) enum SearchError {
   MultipleCandidates { candidates: BTreeSet<PathBuf> },
   #[snafu(display("No justfile found"))]
   NotFound,
+  #[snafu(display("Error searching `{}` for justfiles: {source}", directory.display()))]
+  Walk {
+    directory: PathBuf,
+    source: ignore::Error,
+  },
 }
 
 #[cfg(test)]
@@ -0,0 +1,72 @@
+use super::*;
+
+/// Values marked secret, which are masked in echoed commands, dumps, and
+/// captured output
+#[derive(Default)]
+pub(crate) struct Secrets {
+  values: Mutex<BTreeSet<String>>,
+}
+
+impl Secrets {
+  pub(crate) const MASK: &'static str = "***";
+
+  /// Values shorter than this are not masked, since they would mask unrelated
+  /// output
+  const MIN_LENGTH: usize = 3;
+
+  /// Replace occurrences of secret values in `text` with `***`, longest values
+  /// first. Masking is best-effort: occurrences are replaced wherever they
+  /// appear, including inside longer words, and secrets which have been
+  /// transformed, for example by encoding or quoting, are not masked.
+  pub(crate) fn mask<'text>(&self, text: &'text str) -> Cow<'text, str> {
+    let values = self.values();
+
+    if !values.iter().any(|secret| text.contains(secret.as_str())) {
+      return Cow::Borrowed(text);
+    }
+
+    let mut values = values.iter().collect::<Vec<&String>>();
+
+    values.sort_by_key(|secret| Reverse(secret.len()));
+
+    let mut masked = text.to_owned();
+
+    for secret in values {
+      masked = masked.replace(secret.as_str(), Self::MASK);
+    }
+
+    Cow::Owned(masked)
+  }
+
+  pub(crate) fn register(&self, value: &str) {
+    if value.chars().count() >= Self::MIN_LENGTH {
+      self.values().insert(value.to_owned());
+    }
+  }
+
+  /// Register a value, and if it is a list or map, each of its elements
+  pub(crate) fn register_value(&self, value: &Value) {
+    self.register(&value.to_string());
+
+    match value {
+      Value::List(elements) => {
+        for element in elements {
+          self.register_value(element);
+        }
+      }
+      Value::Map(entries) => {
+        for entry in entries.values() {
+          self.register_value(entry);
+        }
+      }
+      Value::Integer(_) | Value::String(_) => {}
+    }
+  }
+
+  fn values(&self) -> MutexGuard<BTreeSet<String>> {
+    match self.values.lock() {
+      Ok(guard) => guard,
+      Err(poison_error) => poison_error.into_inner(),
+    }
+  }
+}
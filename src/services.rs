@@ -0,0 +1,97 @@
+use super::*;
+
+/// Running `[service]` recipes
+///
+/// Services are started in the background when first depended upon, and
+/// are stopped, along with their child processes, once every invocation
+/// has finished.
+pub(crate) struct Services<'scope, 'env: 'scope, 'src: 'scope> {
+  running: Mutex<Vec<ScopedJoinHandle<'scope, RunResult<'src>>>>,
+  stopping: Arc<AtomicBool>,
+  thread_scope: &'scope thread::Scope<'scope, 'env>,
+}
+
+impl<'scope, 'env: 'scope, 'src: 'scope> Services<'scope, 'env, 'src> {
+  pub(crate) const DEFAULT_TIMEOUT: u64 = 30;
+
+  const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+  pub(crate) fn new(thread_scope: &'scope thread::Scope<'scope, 'env>) -> Self {
+    Self {
+      running: Mutex::new(Vec::new()),
+      stopping: Arc::new(AtomicBool::new(false)),
+      thread_scope,
+    }
+  }
+
+  /// Start `run` in the background, and block until `ready` returns true
+  ///
+  /// `run` is passed a flag which is set once services are being stopped.
+  pub(crate) fn start(
+    &self,
+    recipe: &Recipe<'src>,
+    ready: impl Fn() -> bool,
+    run: impl FnOnce(&AtomicBool) -> RunResult<'src> + Send + 'scope,
+  ) -> RunResult<'src> {
+    let stopping = self.stopping.clone();
+
+    let handle = self.thread_scope.spawn(move || run(&stopping));
+
+    let timeout = recipe.service_timeout();
+
+    let deadline = Instant::now() + Duration::from_secs(timeout);
+
+    let result = loop {
+      if ready() {
+        break Ok(());
+      }
+
+      if handle.is_finished() {
+        handle
+          .join()
+          .map_err(|_| Error::internal("service thread panicked"))??;
+
+        return Err(Error::ServiceExited {
+          recipe: recipe.name(),
+        });
+      }
+
+      if let Some(signal) = SignalHandler::instance().caught() {
+        break Err(Error::Interrupted { signal });
+      }
+
+      if Instant::now() >= deadline {
+        break Err(Error::ServiceTimeout {
+          recipe: recipe.name(),
+          timeout,
+        });
+      }
+
+      thread::sleep(Self::POLL_INTERVAL);
+    };
+
+    self.running.lock().unwrap().push(handle);
+
+    result
+  }
+
+  /// Terminate running services and wait for them to exit
+  pub(crate) fn stop(self) -> RunResult<'src> {
+    self.stopping.store(true, atomic::Ordering::Relaxed);
+
+    let running = self.running.into_inner().unwrap();
+
+    while !running.iter().all(ScopedJoinHandle::is_finished) {
+      SignalHandler::instance().terminate_services();
+      thread::sleep(Self::POLL_INTERVAL);
+    }
+
+    for handle in running {
+      handle
+        .join()
+        .map_err(|_| Error::internal("service thread panicked"))??;
+    }
+
+    Ok(())
+  }
+}
@@ -1,6 +1,6 @@
 use super::*;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Set<'src> {
   pub(crate) name: Name<'src>,
   pub(crate) value: Setting<'src>,
@@ -1,6 +1,6 @@
 use super::*;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) enum Setting<'src> {
   AllowDuplicateRecipes(bool),
   AllowDuplicateVariables(bool),
@@ -9,11 +9,16 @@ pub(crate) enum Setting<'src> {
   DotenvOverride(bool),
   DotenvPath(Expression<'src>),
   DotenvRequired(bool),
+  EnvClear(bool),
+  ExplicitExports(bool),
   Export(bool),
   Fallback(bool),
   IgnoreComments(bool),
+  InheritSettings(bool),
+  Lazy(bool),
   NoExitMessage(bool),
   OutputFormat(Expression<'src>),
+  ParallelAssignments(bool),
   PositionalArguments(bool),
   Quiet(bool),
   ScriptInterpreter(Interpreter<Expression<'src>>),
@@ -58,10 +63,15 @@ impl Display for Setting<'_> {
       | Self::DotenvLoad(value)
       | Self::DotenvOverride(value)
       | Self::DotenvRequired(value)
+      | Self::EnvClear(value)
+      | Self::ExplicitExports(value)
       | Self::Export(value)
       | Self::Fallback(value)
       | Self::IgnoreComments(value)
+      | Self::InheritSettings(value)
+      | Self::Lazy(value)
       | Self::NoExitMessage(value)
+      | Self::ParallelAssignments(value)
       | Self::PositionalArguments(value)
       | Self::Quiet(value)
       | Self::Unstable(value)
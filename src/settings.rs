@@ -5,20 +5,25 @@ pub(crate) const DEFAULT_SHELL_ARGS: &[&str] = &["-cu"];
 pub(crate) const WINDOWS_POWERSHELL_SHELL: &str = "powershell.exe";
 pub(crate) const WINDOWS_POWERSHELL_ARGS: &[&str] = &["-NoLogo", "-Command"];
 
-#[derive(Debug, PartialEq, Serialize, Default)]
+#[derive(Clone, Debug, PartialEq, Serialize, Default)]
 pub(crate) struct Settings {
   pub(crate) allow_duplicate_recipes: bool,
   pub(crate) allow_duplicate_variables: bool,
   pub(crate) dotenv_filename: Option<String>,
   pub(crate) dotenv_load: bool,
   pub(crate) dotenv_override: bool,
-  pub(crate) dotenv_path: Option<PathBuf>,
+  pub(crate) dotenv_path: Vec<PathBuf>,
   pub(crate) dotenv_required: bool,
+  pub(crate) env_clear: bool,
+  pub(crate) explicit_exports: bool,
   pub(crate) export: bool,
   pub(crate) fallback: bool,
   pub(crate) ignore_comments: bool,
+  pub(crate) inherit_settings: bool,
+  pub(crate) lazy: bool,
   pub(crate) no_exit_message: bool,
   pub(crate) output_format: Option<crate::output_format::OutputFormat>,
+  pub(crate) parallel_assignments: bool,
   pub(crate) positional_arguments: bool,
   pub(crate) quiet: bool,
   #[serde(skip)]
@@ -32,6 +37,26 @@ pub(crate) struct Settings {
 }
 
 impl Settings {
+  /// The settings which a submodule with `set inherit-settings` takes from
+  /// this module, which are those that control how recipes are run. Settings
+  /// which affect parsing, analysis, variable evaluation, dotenv loading, or
+  /// the working directory are left at their defaults.
+  pub(crate) fn inheritable(&self) -> Self {
+    Self {
+      env_clear: self.env_clear,
+      export: self.export,
+      no_exit_message: self.no_exit_message,
+      positional_arguments: self.positional_arguments,
+      quiet: self.quiet,
+      script_interpreter: self.script_interpreter.clone(),
+      shell: self.shell.clone(),
+      tempdir: self.tempdir.clone(),
+      windows_powershell: self.windows_powershell,
+      windows_shell: self.windows_shell.clone(),
+      ..Self::default()
+    }
+  }
+
   pub(crate) fn shell_command(&self, config: &Config) -> Command {
     let (command, args) = self.shell(config);
 
@@ -3,7 +3,9 @@ use super::*;
 pub(crate) struct SignalHandler {
   caught: Option<Signal>,
   children: BTreeMap<i32, (Command, bool)>,
+  deferred: usize,
   initialized: bool,
+  terminating: bool,
   verbosity: Verbosity,
 }
 
@@ -38,14 +40,16 @@ impl SignalHandler {
     Self {
       caught: None,
       children: BTreeMap::new(),
+      deferred: 0,
       initialized: false,
+      terminating: false,
       verbosity: Verbosity::default(),
     }
   }
 
   fn handle(&mut self, signal: Signal) {
     if signal.is_fatal() {
-      if self.children.is_empty() {
+      if self.children.is_empty() && self.deferred == 0 {
         process::exit(signal.code());
       }
 
@@ -114,6 +118,83 @@ impl SignalHandler {
     }
   }
 
+  pub(crate) fn caught(&self) -> Option<Signal> {
+    self.caught
+  }
+
+  /// Don't exit on fatal signals received while no child is running, until
+  /// a matching call to `undefer`, so that `[finally]` recipes get to run
+  pub(crate) fn defer(&mut self) {
+    self.deferred += 1;
+  }
+
+  /// Replace the caught signal, returning the previous one
+  pub(crate) fn replace_caught(&mut self, signal: Option<Signal>) -> Option<Signal> {
+    mem::replace(&mut self.caught, signal)
+  }
+
+  pub(crate) fn undefer(&mut self) {
+    self.deferred -= 1;
+  }
+
+  /// Allow recipes to run again after `terminate_recipes`
+  pub(crate) fn resume_recipes(&mut self) {
+    self.terminating = false;
+  }
+
+  /// Terminate child processes running recipes, so that `--watch` can restart
+  /// them, and keep terminating those spawned until `resume_recipes` is
+  /// called. `[service]` recipes, which lead their own process group, are
+  /// left running until the run that started them stops them.
+  pub(crate) fn terminate_recipes(&mut self) {
+    self.terminating = true;
+    self.terminate(false);
+  }
+
+  /// Terminate the process groups of running `[service]` recipes
+  pub(crate) fn terminate_services(&self) {
+    self.terminate(true);
+  }
+
+  fn terminate(&self, services: bool) {
+    for &child in self.children.keys() {
+      self.terminate_child(child, services);
+    }
+  }
+
+  /// Terminate `child` if it leads its own process group, and so is a
+  /// service, and `services` is true, or if it doesn't and `services` is false
+  fn terminate_child(&self, child: i32, services: bool) {
+    #[cfg(not(windows))]
+    {
+      let pid = nix::unistd::Pid::from_raw(child);
+
+      let leader = nix::unistd::getpgid(Some(pid)) == Ok(pid);
+
+      if leader != services {
+        return;
+      }
+
+      if self.verbosity.loquacious() {
+        eprintln!(
+          "just: sending {} to child process {child}",
+          Signal::Terminate
+        );
+      }
+
+      let target = if leader {
+        nix::unistd::Pid::from_raw(-child)
+      } else {
+        pid
+      };
+
+      nix::sys::signal::kill(target, Some(Signal::Terminate.into())).ok();
+    }
+
+    #[cfg(windows)]
+    let _ = (child, services);
+  }
+
   pub(crate) fn spawn<T>(
     command: Command,
     f: impl FnOnce(process::Child) -> io::Result<T>,
@@ -157,6 +238,10 @@ impl SignalHandler {
 
     instance.children.insert(pid, (command, forward_all));
 
+    if instance.terminating {
+      instance.terminate_child(pid, false);
+    }
+
     drop(instance);
 
     let result = f(child);
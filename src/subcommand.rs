@@ -19,6 +19,9 @@ pub(crate) enum Subcommand {
     arguments: Vec<OsString>,
     binary: OsString,
   },
+  CompleteParameter {
+    arguments: Vec<String>,
+  },
   Completions {
     shell: completions::Shell,
   },
@@ -48,6 +51,11 @@ pub(crate) enum Subcommand {
     path: ModulePath,
   },
   Variables,
+  Workspace {
+    arguments: Vec<String>,
+    filters: Vec<String>,
+    jobs: NonZeroUsize,
+  },
 }
 
 impl Default for Subcommand {
@@ -59,7 +67,12 @@ impl Default for Subcommand {
 }
 
 impl Subcommand {
-  pub(crate) fn execute<'src>(&self, config: &Config, loader: &'src Loader) -> RunResult<'src> {
+  pub(crate) fn execute<'src>(
+    &self,
+    config: &Config,
+    loader: &'src Loader,
+    secrets: &Secrets,
+  ) -> RunResult<'src> {
     use Subcommand::*;
 
     match self {
@@ -74,6 +87,11 @@ impl Subcommand {
       Init => return Self::init(config),
       Man => return Self::man(),
       Request { request } => return Self::request(request),
+      Workspace {
+        arguments,
+        filters,
+        jobs,
+      } => return Self::workspace(config, loader, arguments, filters, *jobs),
       _ => {}
     }
 
@@ -92,26 +110,62 @@ impl Subcommand {
 
     match self {
       Choose { chooser } => {
-        Self::choose(config, justfile, &search, chooser.as_deref())?;
+        Self::choose(config, justfile, &search, secrets, chooser.as_deref())?;
       }
+      CompleteParameter { arguments } => Self::complete_parameter(justfile, arguments),
       Command { .. } | Evaluate { .. } => {
-        justfile.run(config, &search, &[])?;
+        justfile.run(config, &search, secrets, &[])?;
       }
       Dump => Self::dump(config, compilation)?,
       Format => Self::format(config, &search, compilation)?,
       Groups => Self::groups(config, justfile),
       List { path } => Self::list(config, justfile, path)?,
-      Run { arguments } => Self::run(config, loader, search, compilation, arguments)?,
+      Run { arguments } => Self::run(config, loader, secrets, search, compilation, arguments)?,
       Show { path } => Self::show(config, justfile, path)?,
       Summary => Self::summary(config, justfile),
       Usage { path } => Self::usage(config, justfile, path)?,
       Variables => Self::variables(justfile),
-      Changelog | Completions { .. } | Edit | Init | Man | Request { .. } => unreachable!(),
+      Changelog | Completions { .. } | Edit | Init | Man | Request { .. } | Workspace { .. } => {
+        unreachable!()
+      }
     }
 
     Ok(())
   }
 
+  fn complete_parameter(justfile: &Justfile, arguments: &[String]) {
+    let Some((path, arguments)) = arguments.split_first() else {
+      return;
+    };
+
+    let Ok(path) = ModulePath::try_from([path.as_str()].as_slice()) else {
+      return;
+    };
+
+    let Ok((_alias, recipe)) = Self::resolve_path(justfile, &path) else {
+      return;
+    };
+
+    let mut positional = recipe.parameters.iter().filter(|p| !p.is_option());
+
+    let index = arguments.iter().filter(|arg| !arg.starts_with('-')).count();
+
+    let parameter = positional
+      .clone()
+      .nth(index)
+      .or_else(|| positional.next_back().filter(|p| p.kind.is_variadic()));
+
+    let Some(ty) = parameter.and_then(|parameter| parameter.ty.as_ref()) else {
+      return;
+    };
+
+    println!("{}", ty.name());
+
+    for value in ty.completions() {
+      println!("{value}");
+    }
+  }
+
   fn groups(config: &Config, justfile: &Justfile) {
     println!("Recipe groups:");
     for group in justfile.public_groups(config) {
@@ -122,10 +176,31 @@ impl Subcommand {
   fn run<'src>(
     config: &Config,
     loader: &'src Loader,
+    secrets: &Secrets,
     mut search: Search,
     mut compilation: Compilation<'src>,
     arguments: &[String],
   ) -> RunResult<'src> {
+    let arguments = if config.last || config.rerun_failed {
+      let last_run = LastRun::load(config, &search)?;
+
+      if config.rerun_failed && !last_run.failed() {
+        if !config.verbosity.quiet() {
+          eprintln!("No recipes failed in the last run.");
+        }
+        return Ok(());
+      }
+
+      Cow::Owned(last_run.arguments(config.rerun_failed))
+    } else {
+      Cow::Borrowed(arguments)
+    };
+
+    if config.watch {
+      compilation.save_lockfile()?;
+      return Self::watch(config, &search, secrets, compilation, &arguments);
+    }
+
     let starting_parent = search.justfile.parent().as_ref().unwrap().lexiclean();
 
     loop {
@@ -136,7 +211,9 @@ impl Subcommand {
           SearchConfig::FromInvocationDirectory | SearchConfig::FromSearchDirectory { .. }
         );
 
-      let result = justfile.run(config, &search, arguments);
+      compilation.save_lockfile()?;
+
+      let result = justfile.run(config, &search, secrets, &arguments);
 
       if fallback {
         if let Err(err @ (Error::UnknownRecipe { .. } | Error::UnknownSubmodule { .. })) = result {
@@ -177,6 +254,94 @@ impl Subcommand {
     }
   }
 
+  fn watch<'src>(
+    config: &Config,
+    search: &Search,
+    secrets: &Secrets,
+    compilation: Compilation<'src>,
+    arguments: &[String],
+  ) -> RunResult<'src> {
+    let mut files = Self::run_until_changed(config, search, secrets, &compilation, arguments)?;
+
+    loop {
+      // Each recompilation gets its own loader, so that the sources of
+      // previous compilations are freed
+      let loader = Loader::new();
+
+      let result = Self::compile(config, &loader, search).and_then(|compilation| {
+        Self::run_until_changed(config, search, secrets, &compilation, arguments)
+      });
+
+      match result {
+        Ok(watched) => files = watched,
+        Err(Error::Interrupted { signal }) => return Err(Error::Interrupted { signal }),
+        Err(error) => {
+          Self::print_watch_error(config, secrets, &error);
+          Watcher::new(files.clone(), Vec::new()).wait();
+        }
+      }
+    }
+  }
+
+  fn print_watch_error(config: &Config, secrets: &Secrets, error: &Error) {
+    if !config.verbosity.quiet() && error.print_message() {
+      eprintln!(
+        "{}",
+        secrets.mask(&error.color_display(config.color.stderr()).to_string())
+      );
+    }
+  }
+
+  /// Run `compilation`, restarting if watched files change while it runs,
+  /// and wait for them to change, returning the compiled files
+  fn run_until_changed<'src>(
+    config: &Config,
+    search: &Search,
+    secrets: &Secrets,
+    compilation: &Compilation<'src>,
+    arguments: &[String],
+  ) -> RunResult<'src, Vec<PathBuf>> {
+    let mut files = compilation.srcs.keys().cloned().collect::<Vec<PathBuf>>();
+    files.sort();
+
+    let globs = compilation.justfile.watch_globs(config, arguments)?;
+
+    let mut watcher = Watcher::new(files.clone(), globs);
+
+    let (sender, receiver) = mpsc::channel();
+
+    // Recipes which haven't started yet are stopped as well, until the
+    // current run finishes. Services are stopped by the run itself once its
+    // recipes have exited.
+    thread::spawn(move || {
+      watcher.wait();
+      SignalHandler::instance().terminate_recipes();
+      sender.send(()).ok();
+    });
+
+    let result = compilation.justfile.run(config, search, secrets, arguments);
+
+    let restarted = receiver.try_recv().is_ok();
+
+    match result {
+      Err(error @ Error::Interrupted { .. }) if !restarted => return Err(error),
+      Err(error) if !restarted => Self::print_watch_error(config, secrets, &error),
+      _ => {}
+    }
+
+    if config.verbosity.loquacious() {
+      eprintln!("just: waiting for changes…");
+    }
+
+    if !restarted {
+      receiver.recv().ok();
+    }
+
+    SignalHandler::instance().resume_recipes();
+
+    Ok(files)
+  }
+
   fn compile<'src>(
     config: &Config,
     loader: &'src Loader,
@@ -203,6 +368,7 @@ impl Subcommand {
     config: &Config,
     justfile: &Justfile<'src>,
     search: &Search,
+    secrets: &Secrets,
     chooser: Option<&str>,
   ) -> RunResult<'src> {
     let mut recipes = Vec::<&Recipe>::new();
@@ -283,7 +449,7 @@ impl Subcommand {
       .map(str::to_owned)
       .collect::<Vec<String>>();
 
-    justfile.run(config, search, &recipes)
+    justfile.run(config, search, secrets, &recipes)
   }
 
   fn completions(shell: completions::Shell) {
@@ -779,7 +945,7 @@ impl Subcommand {
     Ok(())
   }
 
-  fn resolve_path<'src, 'run>(
+  pub(crate) fn resolve_path<'src, 'run>(
     mut module: &'run Justfile<'src>,
     path: &ModulePath,
   ) -> RunResult<'src, (Option<&'run Alias<'src>>, &'run Recipe<'src>)> {
@@ -820,6 +986,17 @@ impl Subcommand {
     }
     println!();
   }
+
+  fn workspace<'src>(
+    config: &Config,
+    loader: &'src Loader,
+    arguments: &[String],
+    filters: &[String],
+    jobs: NonZeroUsize,
+  ) -> RunResult<'src> {
+    let workspace = crate::Workspace::find(config, filters)?;
+    workspace.run(config, loader, arguments, jobs)
+  }
 }
 
 #[cfg(test)]
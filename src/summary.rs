@@ -19,9 +19,10 @@ use {
 
 mod full {
   pub(crate) use crate::{
-    assignment::Assignment, condition::Condition, conditional_operator::ConditionalOperator,
-    dependency::Dependency, expression::Expression, fragment::Fragment, justfile::Justfile,
-    line::Line, parameter::Parameter, parameter_kind::ParameterKind, recipe::Recipe, thunk::Thunk,
+    arithmetic_operator::ArithmeticOperator, assignment::Assignment, condition::Condition,
+    conditional_operator::ConditionalOperator, dependency::Dependency, expression::Expression,
+    fragment::Fragment, justfile::Justfile, line::Line, parameter::Parameter,
+    parameter_kind::ParameterKind, recipe::Recipe, thunk::Thunk,
   };
 }
 
@@ -187,6 +188,11 @@ pub enum Expression {
     lhs: Box<Self>,
     rhs: Box<Self>,
   },
+  Arithmetic {
+    lhs: Box<Self>,
+    operator: ArithmeticOperator,
+    rhs: Box<Self>,
+  },
   Assert {
     condition: Condition,
     error: Box<Self>,
@@ -209,14 +215,43 @@ pub enum Expression {
     otherwise: Box<Self>,
     operator: ConditionalOperator,
   },
+  Filter {
+    condition: Condition,
+    list: Box<Self>,
+    name: String,
+  },
+  For {
+    body: Box<Self>,
+    list: Box<Self>,
+    name: String,
+  },
   FormatString {
     start: String,
     expressions: Vec<(Self, String)>,
   },
+  Index {
+    index: Box<Self>,
+    value: Box<Self>,
+  },
+  Integer {
+    value: i64,
+  },
   Join {
     lhs: Option<Box<Self>>,
     rhs: Box<Self>,
   },
+  List {
+    elements: Vec<Self>,
+  },
+  Map {
+    body: Box<Self>,
+    list: Box<Self>,
+    name: String,
+  },
+  Match {
+    arms: Vec<(String, Self)>,
+    value: Box<Self>,
+  },
   Or {
     lhs: Box<Self>,
     rhs: Box<Self>,
@@ -237,8 +272,17 @@ impl Expression {
         lhs: Self::new(lhs).into(),
         rhs: Self::new(rhs).into(),
       },
+      Arithmetic {
+        lhs, operator, rhs, ..
+      } => Self::Arithmetic {
+        lhs: Self::new(lhs).into(),
+        operator: ArithmeticOperator::new(*operator),
+        rhs: Self::new(rhs).into(),
+      },
       Assert {
-        condition: full::Condition { lhs, rhs, operator },
+        condition: full::Condition {
+          lhs, rhs, operator, ..
+        },
         error,
         ..
       } => Self::Assert {
@@ -257,14 +301,21 @@ impl Expression {
           name: name.lexeme().to_owned(),
           arguments: Vec::new(),
         },
-        full::Thunk::Unary { name, arg, .. } => Self::Call {
-          name: name.lexeme().to_owned(),
-          arguments: vec![Self::new(arg)],
-        },
+        full::Thunk::Unary { name, arg, .. } | full::Thunk::UnaryValue { name, arg, .. } => {
+          Self::Call {
+            name: name.lexeme().to_owned(),
+            arguments: vec![Self::new(arg)],
+          }
+        }
         full::Thunk::UnaryOpt {
           name,
           args: (a, opt_b),
           ..
+        }
+        | full::Thunk::UnaryOptValue {
+          name,
+          args: (a, opt_b),
+          ..
         } => {
           let mut arguments = Vec::new();
           if let Some(b) = opt_b.as_ref() {
@@ -292,6 +343,9 @@ impl Expression {
         }
         full::Thunk::Binary {
           name, args: [a, b], ..
+        }
+        | full::Thunk::BinaryValue {
+          name, args: [a, b], ..
         } => Self::Call {
           name: name.lexeme().to_owned(),
           arguments: vec![Self::new(a), Self::new(b)],
@@ -318,13 +372,19 @@ impl Expression {
           name: name.lexeme().to_owned(),
           arguments: vec![Self::new(a), Self::new(b), Self::new(c)],
         },
+        full::Thunk::User { args, path } => Self::Call {
+          name: path.to_string(),
+          arguments: args.iter().map(Self::new).collect(),
+        },
       },
-      Concatenation { lhs, rhs } => Self::Concatenation {
+      Concatenation { lhs, rhs, .. } => Self::Concatenation {
         lhs: Self::new(lhs).into(),
         rhs: Self::new(rhs).into(),
       },
       Conditional {
-        condition: full::Condition { lhs, rhs, operator },
+        condition: full::Condition {
+          lhs, rhs, operator, ..
+        },
         otherwise,
         then,
       } => Self::Conditional {
@@ -334,6 +394,33 @@ impl Expression {
         rhs: Self::new(rhs).into(),
         then: Self::new(then).into(),
       },
+      Field { name, value } => Self::Index {
+        index: Self::String {
+          text: name.lexeme().to_owned(),
+        }
+        .into(),
+        value: Self::new(value).into(),
+      },
+      Filter {
+        condition: full::Condition {
+          lhs, rhs, operator, ..
+        },
+        list,
+        name,
+      } => Self::Filter {
+        condition: Condition {
+          lhs: Box::new(Self::new(lhs)),
+          rhs: Box::new(Self::new(rhs)),
+          operator: ConditionalOperator::new(*operator),
+        },
+        list: Self::new(list).into(),
+        name: name.lexeme().to_owned(),
+      },
+      For { body, list, name } => Self::For {
+        body: Self::new(body).into(),
+        list: Self::new(list).into(),
+        name: name.lexeme().to_owned(),
+      },
       FormatString { start, expressions } => Self::FormatString {
         start: start.cooked.clone(),
         expressions: expressions
@@ -342,10 +429,33 @@ impl Expression {
           .collect(),
       },
       Group { contents } => Self::new(contents),
-      Join { lhs, rhs } => Self::Join {
+      Index { index, value, .. } => Self::Index {
+        index: Self::new(index).into(),
+        value: Self::new(value).into(),
+      },
+      Integer { value, .. } => Self::Integer { value: *value },
+      Join { lhs, rhs, .. } => Self::Join {
         lhs: lhs.as_ref().map(|lhs| Self::new(lhs).into()),
         rhs: Self::new(rhs).into(),
       },
+      List { elements } => Self::List {
+        elements: elements.iter().map(Self::new).collect(),
+      },
+      Map { body, list, name } => Self::Map {
+        body: Self::new(body).into(),
+        list: Self::new(list).into(),
+        name: name.lexeme().to_owned(),
+      },
+      Match { arms, value } => Self::Match {
+        arms: arms
+          .iter()
+          .map(|arm| (arm.pattern.to_string(), Self::new(&arm.body)))
+          .collect(),
+        value: Self::new(value).into(),
+      },
+      ModuleVariable { path } => Self::Variable {
+        name: path.to_string(),
+      },
       Or { lhs, rhs } => Self::Or {
         lhs: Self::new(lhs).into(),
         rhs: Self::new(rhs).into(),
@@ -360,6 +470,27 @@ impl Expression {
   }
 }
 
+#[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug, Clone)]
+pub enum ArithmeticOperator {
+  Difference,
+  Product,
+  Quotient,
+  Remainder,
+  Sum,
+}
+
+impl ArithmeticOperator {
+  fn new(operator: full::ArithmeticOperator) -> Self {
+    match operator {
+      full::ArithmeticOperator::Difference => Self::Difference,
+      full::ArithmeticOperator::Product => Self::Product,
+      full::ArithmeticOperator::Quotient => Self::Quotient,
+      full::ArithmeticOperator::Remainder => Self::Remainder,
+      full::ArithmeticOperator::Sum => Self::Sum,
+    }
+  }
+}
+
 #[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug, Clone)]
 pub struct Condition {
   lhs: Box<Expression>,
@@ -370,7 +501,11 @@ pub struct Condition {
 #[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug, Clone)]
 pub enum ConditionalOperator {
   Equality,
+  GreaterThan,
+  GreaterThanOrEqual,
   Inequality,
+  LessThan,
+  LessThanOrEqual,
   RegexMatch,
   RegexMismatch,
 }
@@ -379,7 +514,11 @@ impl ConditionalOperator {
   fn new(operator: full::ConditionalOperator) -> Self {
     match operator {
       full::ConditionalOperator::Equality => Self::Equality,
+      full::ConditionalOperator::GreaterThan => Self::GreaterThan,
+      full::ConditionalOperator::GreaterThanOrEqual => Self::GreaterThanOrEqual,
       full::ConditionalOperator::Inequality => Self::Inequality,
+      full::ConditionalOperator::LessThan => Self::LessThan,
+      full::ConditionalOperator::LessThanOrEqual => Self::LessThanOrEqual,
       full::ConditionalOperator::RegexMatch => Self::RegexMatch,
       full::ConditionalOperator::RegexMismatch => Self::RegexMismatch,
     }
@@ -1,6 +1,6 @@
 use {super::*, std::collections::btree_map};
 
-#[derive(Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(transparent)]
 pub(crate) struct Table<'key, V: Keyed<'key>> {
   map: BTreeMap<&'key str, V>,
@@ -33,6 +33,10 @@ impl<'key, V: Keyed<'key>> Table<'key, V> {
     self.map.values()
   }
 
+  pub(crate) fn values_mut(&mut self) -> btree_map::ValuesMut<&'key str, V> {
+    self.map.values_mut()
+  }
+
   pub(crate) fn contains_key(&self, key: &str) -> bool {
     self.map.contains_key(key)
   }
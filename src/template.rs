@@ -0,0 +1,79 @@
+use super::*;
+
+/// Text containing `{{…}}` interpolations, rendered by the `render()` and
+/// `template()` functions and the `[render]` attribute
+pub(crate) struct Template<'src> {
+  pub(crate) line: Line<'src>,
+  pub(crate) unstable_features: BTreeSet<UnstableFeature>,
+}
+
+impl Template<'_> {
+  /// Render template `src`, read from `path`, with the variables in `scope`
+  ///
+  /// Errors contain tokens which borrow `src`, so they are returned as
+  /// messages, including the context of the error in the template.
+  pub(crate) fn render(
+    context: &ExecutionContext,
+    scope: &Scope,
+    is_dependency: bool,
+    path: &Path,
+    src: &str,
+  ) -> Result<String, String> {
+    Self::render_inner(context, scope, is_dependency, path, src).map_err(|error| {
+      let message = error.color_display(Color::never()).to_string();
+      message
+        .strip_prefix("error: ")
+        .unwrap_or(&message)
+        .to_owned()
+    })
+  }
+
+  fn render_inner<'src, 'run>(
+    context: &ExecutionContext<'src, 'run>,
+    scope: &'run Scope<'src, 'run>,
+    is_dependency: bool,
+    path: &'src Path,
+    src: &'src str,
+  ) -> RunResult<'src, String> {
+    let tokens = Lexer::lex_template(path, src)?;
+
+    let template = Parser::parse_template(&tokens)?;
+
+    if let Some(&unstable_feature) = template.unstable_features.first() {
+      context
+        .config
+        .require_unstable(context.module, unstable_feature)?;
+    }
+
+    let expressions = template
+      .line
+      .fragments
+      .iter()
+      .filter_map(|fragment| match fragment {
+        Fragment::Interpolation { expression } => Some(expression),
+        Fragment::Text { .. } => None,
+      })
+      .collect::<Vec<&Expression>>();
+
+    for expression in &expressions {
+      for variable in expression.variables() {
+        let name = variable.lexeme();
+        if scope.value(name).is_none() && !context.module.assignments.contains_key(name) {
+          return Err(
+            variable
+              .error(CompileErrorKind::UndefinedVariable { variable: name })
+              .into(),
+          );
+        }
+      }
+    }
+
+    FunctionResolver::resolve_functions(
+      &context.module.functions,
+      &context.module.modules,
+      expressions,
+    )?;
+
+    Evaluator::evaluate_template(context, is_dependency, scope, &template)
+  }
+}
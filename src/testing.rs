@@ -59,8 +59,8 @@ pub(crate) fn analysis_error(
 ) {
   let tokens = Lexer::test_lex(src).expect("Lexing failed in parse test...");
 
-  let ast = Parser::parse(0, &[], None, &tokens, &PathBuf::new())
-    .expect("Parsing failed in analysis test...");
+  let ast =
+    Parser::parse(0, &[], &tokens, &PathBuf::new()).expect("Parsing failed in analysis test...");
 
   let root = PathBuf::from("justfile");
   let mut asts: HashMap<PathBuf, Ast> = HashMap::new();
@@ -70,11 +70,14 @@ pub(crate) fn analysis_error(
   paths.insert("justfile".into(), "justfile".into());
 
   match Analyzer::analyze(
+    &[],
     &asts,
     &Config::default(),
     None,
+    false,
     &[],
     &[],
+    "",
     None,
     &paths,
     false,
@@ -127,6 +130,7 @@ macro_rules! run_error {
           .run(
             &config,
             &search,
+            &$crate::Secrets::default(),
             &arguments,
           ).expect_err("Expected runtime error") {
             $error => $check
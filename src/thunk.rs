@@ -45,6 +45,28 @@ pub(crate) enum Thunk<'src> {
     function: fn(function::Context, &str, &str, &str) -> FunctionResult,
     args: [Box<Expression<'src>>; 3],
   },
+  UnaryValue {
+    name: Name<'src>,
+    #[derive_where(skip(Debug, EqHashOrd))]
+    function: fn(function::Context, &Value) -> ValueResult,
+    arg: Box<Expression<'src>>,
+  },
+  UnaryOptValue {
+    name: Name<'src>,
+    #[derive_where(skip(Debug, EqHashOrd))]
+    function: fn(function::Context, &Value, Option<&Value>) -> ValueResult,
+    args: (Box<Expression<'src>>, Box<Option<Expression<'src>>>),
+  },
+  BinaryValue {
+    name: Name<'src>,
+    #[derive_where(skip(Debug, EqHashOrd))]
+    function: fn(function::Context, &Value, &Value) -> ValueResult,
+    args: [Box<Expression<'src>>; 2],
+  },
+  User {
+    args: Vec<Expression<'src>>,
+    path: Namepath<'src>,
+  },
 }
 
 impl<'src> Thunk<'src> {
@@ -56,7 +78,36 @@ impl<'src> Thunk<'src> {
       | Self::UnaryPlus { name, .. }
       | Self::Binary { name, .. }
       | Self::BinaryPlus { name, .. }
-      | Self::Ternary { name, .. } => *name,
+      | Self::Ternary { name, .. }
+      | Self::UnaryValue { name, .. }
+      | Self::UnaryOptValue { name, .. }
+      | Self::BinaryValue { name, .. } => *name,
+      Self::User { path, .. } => *path.last(),
+    }
+  }
+
+  /// The arguments of this call, in order
+  pub(crate) fn arguments(&self) -> Vec<&Expression<'src>> {
+    match self {
+      Self::Nullary { .. } => Vec::new(),
+      Self::Unary { arg, .. } | Self::UnaryValue { arg, .. } => vec![arg],
+      Self::UnaryOpt {
+        args: (a, opt_b), ..
+      }
+      | Self::UnaryOptValue {
+        args: (a, opt_b), ..
+      } => iter::once(a.as_ref()).chain(opt_b.as_ref()).collect(),
+      Self::UnaryPlus {
+        args: (a, rest), ..
+      } => iter::once(a.as_ref()).chain(rest).collect(),
+      Self::Binary { args, .. } | Self::BinaryValue { args, .. } => {
+        args.iter().map(Box::as_ref).collect()
+      }
+      Self::BinaryPlus {
+        args: (args, rest), ..
+      } => args.iter().map(Box::as_ref).chain(rest).collect(),
+      Self::Ternary { args, .. } => args.iter().map(Box::as_ref).collect(),
+      Self::User { args, .. } => args.iter().collect(),
     }
   }
 
@@ -64,74 +115,102 @@ impl<'src> Thunk<'src> {
     name: Name<'src>,
     mut arguments: Vec<Expression<'src>>,
   ) -> CompileResult<'src, Self> {
-    function::get(name.lexeme()).map_or(
-      Err(name.error(CompileErrorKind::UnknownFunction {
-        function: name.lexeme(),
-      })),
-      |function| match (function, arguments.len()) {
-        (Function::Nullary(function), 0) => Ok(Thunk::Nullary { function, name }),
-        (Function::Unary(function), 1) => Ok(Thunk::Unary {
+    let Some(function) = function::get(name.lexeme()) else {
+      return Ok(Thunk::User {
+        args: arguments,
+        path: name.into(),
+      });
+    };
+
+    match (function, arguments.len()) {
+      (Function::Nullary(function), 0) => Ok(Thunk::Nullary { function, name }),
+      (Function::Unary(function), 1) => Ok(Thunk::Unary {
+        function,
+        arg: arguments.pop().unwrap().into(),
+        name,
+      }),
+      (Function::UnaryOpt(function), 1..=2) => {
+        let a = arguments.remove(0).into();
+        let b = match arguments.pop() {
+          Some(value) => Some(value).into(),
+          None => None.into(),
+        };
+        Ok(Thunk::UnaryOpt {
           function,
-          arg: arguments.pop().unwrap().into(),
+          args: (a, b),
           name,
-        }),
-        (Function::UnaryOpt(function), 1..=2) => {
-          let a = arguments.remove(0).into();
-          let b = match arguments.pop() {
-            Some(value) => Some(value).into(),
-            None => None.into(),
-          };
-          Ok(Thunk::UnaryOpt {
-            function,
-            args: (a, b),
-            name,
-          })
-        }
-        (Function::UnaryPlus(function), 1..=usize::MAX) => {
-          let rest = arguments.drain(1..).collect();
-          let a = Box::new(arguments.pop().unwrap());
-          Ok(Thunk::UnaryPlus {
-            function,
-            args: (a, rest),
-            name,
-          })
-        }
-        (Function::Binary(function), 2) => {
-          let b = arguments.pop().unwrap().into();
-          let a = arguments.pop().unwrap().into();
-          Ok(Thunk::Binary {
-            function,
-            args: [a, b],
-            name,
-          })
-        }
-        (Function::BinaryPlus(function), 2..=usize::MAX) => {
-          let rest = arguments.drain(2..).collect();
-          let b = arguments.pop().unwrap().into();
-          let a = arguments.pop().unwrap().into();
-          Ok(Thunk::BinaryPlus {
-            function,
-            args: ([a, b], rest),
-            name,
-          })
-        }
-        (Function::Ternary(function), 3) => {
-          let c = arguments.pop().unwrap().into();
-          let b = arguments.pop().unwrap().into();
-          let a = arguments.pop().unwrap().into();
-          Ok(Thunk::Ternary {
-            function,
-            args: [a, b, c],
-            name,
-          })
-        }
-        (function, _) => Err(name.error(CompileErrorKind::FunctionArgumentCountMismatch {
-          function: name.lexeme(),
-          found: arguments.len(),
-          expected: function.argc(),
-        })),
-      },
-    )
+        })
+      }
+      (Function::UnaryPlus(function), 1..=usize::MAX) => {
+        let rest = arguments.drain(1..).collect();
+        let a = Box::new(arguments.pop().unwrap());
+        Ok(Thunk::UnaryPlus {
+          function,
+          args: (a, rest),
+          name,
+        })
+      }
+      (Function::Binary(function), 2) => {
+        let b = arguments.pop().unwrap().into();
+        let a = arguments.pop().unwrap().into();
+        Ok(Thunk::Binary {
+          function,
+          args: [a, b],
+          name,
+        })
+      }
+      (Function::BinaryPlus(function), 2..=usize::MAX) => {
+        let rest = arguments.drain(2..).collect();
+        let b = arguments.pop().unwrap().into();
+        let a = arguments.pop().unwrap().into();
+        Ok(Thunk::BinaryPlus {
+          function,
+          args: ([a, b], rest),
+          name,
+        })
+      }
+      (Function::Ternary(function), 3) => {
+        let c = arguments.pop().unwrap().into();
+        let b = arguments.pop().unwrap().into();
+        let a = arguments.pop().unwrap().into();
+        Ok(Thunk::Ternary {
+          function,
+          args: [a, b, c],
+          name,
+        })
+      }
+      (Function::UnaryValue(function), 1) => Ok(Thunk::UnaryValue {
+        function,
+        arg: arguments.pop().unwrap().into(),
+        name,
+      }),
+      (Function::UnaryOptValue(function), 1..=2) => {
+        let a = arguments.remove(0).into();
+        let b = match arguments.pop() {
+          Some(value) => Some(value).into(),
+          None => None.into(),
+        };
+        Ok(Thunk::UnaryOptValue {
+          function,
+          args: (a, b),
+          name,
+        })
+      }
+      (Function::BinaryValue(function), 2) => {
+        let b = arguments.pop().unwrap().into();
+        let a = arguments.pop().unwrap().into();
+        Ok(Thunk::BinaryValue {
+          function,
+          args: [a, b],
+          name,
+        })
+      }
+      (function, _) => Err(name.error(CompileErrorKind::FunctionArgumentCountMismatch {
+        function: name.lexeme(),
+        found: arguments.len(),
+        expected: function.argc(),
+      })),
+    }
   }
 }
 
@@ -140,9 +219,14 @@ impl Display for Thunk<'_> {
     use Thunk::*;
     match self {
       Nullary { name, .. } => write!(f, "{}()", name.lexeme()),
-      Unary { name, arg, .. } => write!(f, "{}({arg})", name.lexeme()),
+      Unary { name, arg, .. } | UnaryValue { name, arg, .. } => {
+        write!(f, "{}({arg})", name.lexeme())
+      }
       UnaryOpt {
         name, args: (a, b), ..
+      }
+      | UnaryOptValue {
+        name, args: (a, b), ..
       } => {
         if let Some(b) = b.as_ref() {
           write!(f, "{}({a}, {b})", name.lexeme())
@@ -163,6 +247,9 @@ impl Display for Thunk<'_> {
       }
       Binary {
         name, args: [a, b], ..
+      }
+      | BinaryValue {
+        name, args: [a, b], ..
       } => write!(f, "{}({a}, {b})", name.lexeme()),
       BinaryPlus {
         name,
@@ -180,6 +267,16 @@ impl Display for Thunk<'_> {
         args: [a, b, c],
         ..
       } => write!(f, "{}({a}, {b}, {c})", name.lexeme()),
+      User { args, path } => {
+        write!(f, "{path}(")?;
+        for (i, arg) in args.iter().enumerate() {
+          if i > 0 {
+            write!(f, ", ")?;
+          }
+          write!(f, "{arg}")?;
+        }
+        write!(f, ")")
+      }
     }
   }
 }
@@ -191,12 +288,19 @@ impl Serialize for Thunk<'_> {
   {
     let mut seq = serializer.serialize_seq(None)?;
     seq.serialize_element("call")?;
-    seq.serialize_element(&self.name())?;
+    if let Self::User { path, .. } = self {
+      seq.serialize_element(path)?;
+    } else {
+      seq.serialize_element(&self.name())?;
+    }
     match self {
       Self::Nullary { .. } => {}
-      Self::Unary { arg, .. } => seq.serialize_element(&arg)?,
+      Self::Unary { arg, .. } | Self::UnaryValue { arg, .. } => seq.serialize_element(&arg)?,
       Self::UnaryOpt {
         args: (a, opt_b), ..
+      }
+      | Self::UnaryOptValue {
+        args: (a, opt_b), ..
       } => {
         seq.serialize_element(a)?;
         if let Some(b) = opt_b.as_ref() {
@@ -211,7 +315,7 @@ impl Serialize for Thunk<'_> {
           seq.serialize_element(arg)?;
         }
       }
-      Self::Binary { args, .. } => {
+      Self::Binary { args, .. } | Self::BinaryValue { args, .. } => {
         for arg in args {
           seq.serialize_element(arg)?;
         }
@@ -226,6 +330,11 @@ impl Serialize for Thunk<'_> {
           seq.serialize_element(arg)?;
         }
       }
+      Self::User { args, .. } => {
+        for arg in args {
+          seq.serialize_element(arg)?;
+        }
+      }
     }
     seq.end()
   }
@@ -3,6 +3,10 @@ use super::*;
 #[derive(Debug, PartialEq, Clone, Copy, Ord, PartialOrd, Eq)]
 pub(crate) enum TokenKind {
   AmpersandAmpersand,
+  AngleL,
+  AngleLEquals,
+  AngleR,
+  AngleREquals,
   Asterisk,
   At,
   Backtick,
@@ -21,9 +25,11 @@ pub(crate) enum TokenKind {
   Comment,
   Dedent,
   Dollar,
+  Dot,
   Eof,
   Eol,
   Equals,
+  EqualsAngleR,
   EqualsEquals,
   EqualsTilde,
   FormatStringContinue,
@@ -31,12 +37,16 @@ pub(crate) enum TokenKind {
   FormatStringStart,
   Identifier,
   Indent,
+  Integer,
   InterpolationEnd,
   InterpolationStart,
+  Minus,
   ParenL,
   ParenR,
+  Percent,
   Plus,
   QuestionMark,
+  Regex,
   Slash,
   StringToken,
   Text,
@@ -52,6 +62,10 @@ impl Display for TokenKind {
       "{}",
       match *self {
         AmpersandAmpersand => "'&&'",
+        AngleL => "'<'",
+        AngleLEquals => "'<='",
+        AngleR => "'>'",
+        AngleREquals => "'>='",
         Asterisk => "'*'",
         At => "'@'",
         Backtick => "backtick",
@@ -70,20 +84,26 @@ impl Display for TokenKind {
         Comment => "comment",
         Dedent => "dedent",
         Dollar => "'$'",
+        Dot => "'.'",
         Eof => "end of file",
         Eol => "end of line",
         Equals => "'='",
+        EqualsAngleR => "'=>'",
         EqualsEquals => "'=='",
         EqualsTilde => "'=~'",
         FormatStringContinue | FormatStringEnd | FormatStringStart => "format string",
         Identifier => "identifier",
         Indent => "indent",
+        Integer => "integer",
         InterpolationEnd => "'}}'",
         InterpolationStart => "'{{'",
+        Minus => "'-'",
         ParenL => "'('",
         ParenR => "')'",
+        Percent => "'%'",
         Plus => "'+'",
         QuestionMark => "?",
+        Regex => "regular expression",
         Slash => "'/'",
         StringToken => "string",
         Text => "command text",
@@ -12,6 +12,10 @@ macro_rules! tree {
     $crate::tree::Tree::atom(stringify!($atom))
   };
 
+  { - } => {
+    $crate::tree::Tree::atom("-")
+  };
+
   { $atom:literal } => {
     $crate::tree::Tree::atom(format!("\"{}\"", $atom))
   };
@@ -32,6 +36,18 @@ macro_rules! tree {
     $crate::tree::Tree::atom("*")
   };
 
+  { % } => {
+    $crate::tree::Tree::atom("%")
+  };
+
+  { < } => {
+    $crate::tree::Tree::atom("<")
+  };
+
+  { >= } => {
+    $crate::tree::Tree::atom(">=")
+  };
+
   { && } => {
     $crate::tree::Tree::atom("&&")
   };
@@ -43,6 +59,10 @@ macro_rules! tree {
   { != } => {
     $crate::tree::Tree::atom("!=")
   };
+
+  { _ } => {
+    $crate::tree::Tree::atom("_")
+  };
 }
 
 /// A `Tree` is either…
@@ -3,9 +3,40 @@ use super::*;
 pub(crate) type UnresolvedRecipe<'src> = Recipe<'src, UnresolvedDependency<'src>>;
 
 impl<'src> UnresolvedRecipe<'src> {
+  /// Expressions in parameter defaults, dependency arguments, and the body
+  pub(crate) fn expressions(&self) -> impl Iterator<Item = &Expression<'src>> {
+    self
+      .parameters
+      .iter()
+      .filter_map(|parameter| parameter.default.as_ref())
+      .chain(
+        self
+          .dependencies
+          .iter()
+          .flat_map(|dependency| &dependency.arguments),
+      )
+      .chain(self.body.iter().flat_map(|line| {
+        let list = match &line.kind {
+          LineKind::ForStart { list, .. } => Some(list.as_ref()),
+          LineKind::Command | LineKind::ForEnd => None,
+        };
+
+        list
+          .into_iter()
+          .chain(line.fragments.iter().filter_map(|fragment| match fragment {
+            Fragment::Interpolation { expression } => Some(expression),
+            Fragment::Text { .. } => None,
+          }))
+      }))
+  }
+
+  /// Resolve this recipe in the module at `module_path`. Recipes replaced by
+  /// an `[override]` recipe are `overridden`, and are referred to as
+  /// `super::NAME`.
   pub(crate) fn resolve(
     self,
     module_path: &str,
+    overridden: bool,
     resolved: Vec<Arc<Recipe<'src>>>,
   ) -> CompileResult<'src, Recipe<'src>> {
     assert_eq!(
@@ -49,6 +80,11 @@ impl<'src> UnresolvedRecipe<'src> {
       namepath.push_str("::");
     }
 
+    if overridden {
+      namepath.push_str(Keyword::Super.lexeme());
+      namepath.push_str("::");
+    }
+
     namepath.push_str(self.name.lexeme());
 
     Ok(Recipe {
@@ -58,6 +94,7 @@ impl<'src> UnresolvedRecipe<'src> {
       doc: self.doc,
       file_depth: self.file_depth,
       import_offsets: self.import_offsets,
+      module_path: module_path.into(),
       name: self.name,
       namepath: Some(namepath),
       parameters: self.parameters,
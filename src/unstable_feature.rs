@@ -2,6 +2,7 @@ use super::*;
 
 #[derive(Copy, Clone, Debug, PartialEq, Ord, Eq, PartialOrd)]
 pub(crate) enum UnstableFeature {
+  Arithmetic,
   FormatSubcommand,
   LogicalOperators,
   WhichFunction,
@@ -10,6 +11,7 @@ pub(crate) enum UnstableFeature {
 impl Display for UnstableFeature {
   fn fmt(&self, f: &mut Formatter) -> fmt::Result {
     match self {
+      Self::Arithmetic => write!(f, "Integer arithmetic is currently unstable."),
       Self::FormatSubcommand => write!(f, "The `--fmt` command is currently unstable."),
       Self::LogicalOperators => write!(
         f,
@@ -174,6 +174,10 @@ impl ColorDisplay for UsageParameter<'_> {
       write!(f, " {help}")?;
     }
 
+    if let Some(ty) = &self.parameter.ty {
+      write!(f, " [type: {ty}]")?;
+    }
+
     if let Some(default) = &self.parameter.default {
       if self.parameter.value.is_none() {
         write!(f, " [default: {default}]")?;
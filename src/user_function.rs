@@ -0,0 +1,30 @@
+use super::*;
+
+/// A user-defined function, e.g. `fn slug(s) := lowercase(s)`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct UserFunction<'src> {
+  pub(crate) body: Expression<'src>,
+  pub(crate) name: Name<'src>,
+  pub(crate) parameters: Vec<Name<'src>>,
+}
+
+impl<'src> Keyed<'src> for UserFunction<'src> {
+  fn key(&self) -> &'src str {
+    self.name.lexeme()
+  }
+}
+
+impl Display for UserFunction<'_> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(f, "fn {}(", self.name)?;
+
+    for (i, parameter) in self.parameters.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      write!(f, "{parameter}")?;
+    }
+
+    write!(f, ") := {}", self.body)
+  }
+}
@@ -0,0 +1,130 @@
+use super::*;
+
+/// The result of evaluating an expression
+///
+/// Lists are displayed as their elements separated by spaces, and maps as
+/// JSON, so they may be used anywhere a string is expected.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Value {
+  Integer(i64),
+  List(Vec<Value>),
+  Map(BTreeMap<String, Value>),
+  String(String),
+}
+
+impl Value {
+  /// Convert parsed JSON into a value. Booleans, floats, and nulls, which
+  /// have no value equivalent, become strings.
+  pub(crate) fn from_json(json: serde_json::Value) -> Self {
+    match json {
+      serde_json::Value::Array(elements) => {
+        Self::List(elements.into_iter().map(Self::from_json).collect())
+      }
+      serde_json::Value::Bool(bool) => bool.to_string().into(),
+      serde_json::Value::Null => String::new().into(),
+      serde_json::Value::Number(number) => match number.as_i64() {
+        Some(integer) => Self::Integer(integer),
+        None => number.to_string().into(),
+      },
+      serde_json::Value::Object(object) => Self::Map(
+        object
+          .into_iter()
+          .map(|(key, value)| (key, Self::from_json(value)))
+          .collect(),
+      ),
+      serde_json::Value::String(string) => string.into(),
+    }
+  }
+
+  /// Convert parsed TOML into a value. Booleans, floats, and datetimes become
+  /// strings.
+  pub(crate) fn from_toml(toml: toml::Value) -> Self {
+    match toml {
+      toml::Value::Array(elements) => {
+        Self::List(elements.into_iter().map(Self::from_toml).collect())
+      }
+      toml::Value::Boolean(bool) => bool.to_string().into(),
+      toml::Value::Datetime(datetime) => datetime.to_string().into(),
+      toml::Value::Float(float) => float.to_string().into(),
+      toml::Value::Integer(integer) => Self::Integer(integer),
+      toml::Value::String(string) => string.into(),
+      toml::Value::Table(table) => Self::Map(
+        table
+          .into_iter()
+          .map(|(key, value)| (key, Self::from_toml(value)))
+          .collect(),
+      ),
+    }
+  }
+
+  /// An integer, or a string containing one, as an integer
+  pub(crate) fn integer(&self) -> Option<i64> {
+    match self {
+      Self::Integer(integer) => Some(*integer),
+      Self::List(_) | Self::Map(_) => None,
+      Self::String(string) => string.parse().ok(),
+    }
+  }
+
+  /// Empty strings, lists, and maps are false for `&&` and `||`
+  pub(crate) fn is_empty(&self) -> bool {
+    match self {
+      Self::Integer(_) => false,
+      Self::List(elements) => elements.is_empty(),
+      Self::Map(entries) => entries.is_empty(),
+      Self::String(string) => string.is_empty(),
+    }
+  }
+
+  /// A list's elements, a map's keys, or a string as a single element
+  pub(crate) fn into_elements(self) -> Vec<Self> {
+    match self {
+      Self::List(elements) => elements,
+      Self::Map(entries) => entries.into_keys().map(Self::from).collect(),
+      Self::Integer(_) | Self::String(_) => vec![self],
+    }
+  }
+
+  pub(crate) fn to_json(&self) -> serde_json::Value {
+    match self {
+      Self::Integer(integer) => (*integer).into(),
+      Self::List(elements) => elements.iter().map(Self::to_json).collect(),
+      Self::Map(entries) => entries
+        .iter()
+        .map(|(key, value)| (key.clone(), value.to_json()))
+        .collect(),
+      Self::String(string) => string.as_str().into(),
+    }
+  }
+}
+
+impl Display for Value {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    match self {
+      Self::Integer(integer) => write!(f, "{integer}"),
+      Self::List(elements) => {
+        for (i, element) in elements.iter().enumerate() {
+          if i > 0 {
+            write!(f, " ")?;
+          }
+          write!(f, "{element}")?;
+        }
+        Ok(())
+      }
+      Self::Map(_) => write!(f, "{}", self.to_json()),
+      Self::String(string) => write!(f, "{string}"),
+    }
+  }
+}
+
+impl From<String> for Value {
+  fn from(string: String) -> Self {
+    Self::String(string)
+  }
+}
+
+impl From<&str> for Value {
+  fn from(string: &str) -> Self {
+    Self::String(string.into())
+  }
+}
@@ -1,12 +1,16 @@
 use super::*;
 
+/// Variables referenced by an expression, excluding those bound within it,
+/// like `x` in `map(xs, x => x)`
 pub(crate) struct Variables<'expression, 'src> {
-  stack: Vec<&'expression Expression<'src>>,
+  stack: Vec<(&'expression Expression<'src>, Vec<&'src str>)>,
 }
 
 impl<'expression, 'src> Variables<'expression, 'src> {
   pub(crate) fn new(root: &'expression Expression<'src>) -> Self {
-    Self { stack: vec![root] }
+    Self {
+      stack: vec![(root, Vec::new())],
+    }
   }
 }
 
@@ -15,35 +19,44 @@ impl<'src> Iterator for Variables<'_, 'src> {
 
   fn next(&mut self) -> Option<Name<'src>> {
     loop {
-      match self.stack.pop()? {
+      let (expression, bound) = self.stack.pop()?;
+
+      let mut push = |expression| self.stack.push((expression, bound.clone()));
+
+      match expression {
         Expression::And { lhs, rhs } | Expression::Or { lhs, rhs } => {
-          self.stack.push(lhs);
-          self.stack.push(rhs);
+          push(lhs);
+          push(rhs);
+        }
+        Expression::Arithmetic { lhs, rhs, .. } | Expression::Concatenation { lhs, rhs, .. } => {
+          push(rhs);
+          push(lhs);
         }
         Expression::Assert {
-          condition:
-            Condition {
-              lhs,
-              rhs,
-              operator: _,
-            },
+          condition: Condition { lhs, rhs, .. },
           error,
           ..
         } => {
-          self.stack.push(error);
-          self.stack.push(rhs);
-          self.stack.push(lhs);
+          push(error);
+          push(rhs);
+          push(lhs);
         }
-        Expression::Backtick { .. } | Expression::StringLiteral { .. } => {}
+        Expression::Backtick { .. }
+        | Expression::Integer { .. }
+        | Expression::ModuleVariable { .. }
+        | Expression::StringLiteral { .. } => {}
         Expression::Call { thunk } => match thunk {
           Thunk::Nullary { .. } => {}
-          Thunk::Unary { arg, .. } => self.stack.push(arg),
+          Thunk::Unary { arg, .. } | Thunk::UnaryValue { arg, .. } => push(arg),
           Thunk::UnaryOpt {
             args: (a, opt_b), ..
+          }
+          | Thunk::UnaryOptValue {
+            args: (a, opt_b), ..
           } => {
-            self.stack.push(a);
+            push(a);
             if let Some(b) = opt_b.as_ref() {
-              self.stack.push(b);
+              push(b);
             }
           }
           Thunk::UnaryPlus {
@@ -51,12 +64,12 @@ impl<'src> Iterator for Variables<'_, 'src> {
           } => {
             let first: &[&Expression] = &[a];
             for arg in first.iter().copied().chain(rest).rev() {
-              self.stack.push(arg);
+              push(arg);
             }
           }
-          Thunk::Binary { args, .. } => {
+          Thunk::Binary { args, .. } | Thunk::BinaryValue { args, .. } => {
             for arg in args.iter().rev() {
-              self.stack.push(arg);
+              push(arg);
             }
           }
           Thunk::BinaryPlus {
@@ -65,49 +78,83 @@ impl<'src> Iterator for Variables<'_, 'src> {
           } => {
             let first: &[&Expression] = &[a, b];
             for arg in first.iter().copied().chain(rest).rev() {
-              self.stack.push(arg);
+              push(arg);
             }
           }
           Thunk::Ternary { args, .. } => {
             for arg in args.iter().rev() {
-              self.stack.push(arg);
+              push(arg);
+            }
+          }
+          Thunk::User { args, .. } => {
+            for arg in args.iter().rev() {
+              push(arg);
             }
           }
         },
-        Expression::Concatenation { lhs, rhs } => {
-          self.stack.push(rhs);
-          self.stack.push(lhs);
-        }
         Expression::Conditional {
-          condition:
-            Condition {
-              lhs,
-              rhs,
-              operator: _,
-            },
+          condition: Condition { lhs, rhs, .. },
           then,
           otherwise,
         } => {
-          self.stack.push(otherwise);
-          self.stack.push(then);
-          self.stack.push(rhs);
-          self.stack.push(lhs);
+          push(otherwise);
+          push(then);
+          push(rhs);
+          push(lhs);
         }
         Expression::FormatString { expressions, .. } => {
           for (expression, _string) in expressions {
-            self.stack.push(expression);
+            push(expression);
           }
         }
-        Expression::Group { contents } => {
-          self.stack.push(contents);
+        Expression::Field { value, .. } | Expression::Group { contents: value } => {
+          push(value);
         }
-        Expression::Join { lhs, rhs } => {
-          self.stack.push(rhs);
+        Expression::Index { index, value, .. } => {
+          push(index);
+          push(value);
+        }
+        Expression::Join { lhs, rhs, .. } => {
+          push(rhs);
           if let Some(lhs) = lhs {
-            self.stack.push(lhs);
+            push(lhs);
+          }
+        }
+        Expression::Filter {
+          condition: Condition { lhs, rhs, .. },
+          list,
+          name,
+        } => {
+          let mut inner = bound.clone();
+          inner.push(name.lexeme());
+          self.stack.push((rhs, inner.clone()));
+          self.stack.push((lhs, inner));
+          self.stack.push((list, bound));
+        }
+        Expression::List { elements } => {
+          for element in elements.iter().rev() {
+            push(element);
+          }
+        }
+        Expression::For { body, list, name } | Expression::Map { body, list, name } => {
+          let mut inner = bound.clone();
+          inner.push(name.lexeme());
+          self.stack.push((body, inner));
+          self.stack.push((list, bound));
+        }
+        Expression::Match { arms, value } => {
+          for arm in arms.iter().rev() {
+            let mut inner = bound.clone();
+            inner.extend(arm.pattern.captures().iter().map(|name| name.lexeme()));
+            self.stack.push((&arm.body, inner));
+          }
+          self.stack.push((value, bound));
+        }
+        Expression::Variable { name, .. } => {
+          if !bound.contains(&name.lexeme()) {
+            return Some(*name);
           }
         }
-        Expression::Variable { name, .. } => return Some(*name),
       }
     }
   }
@@ -0,0 +1,189 @@
+use super::*;
+
+#[cfg(target_os = "linux")]
+use {
+  nix::{
+    errno::Errno,
+    poll::{PollFd, PollFlags, PollTimeout},
+    sys::inotify::{AddWatchFlags, InitFlags, Inotify},
+  },
+  std::os::fd::AsFd,
+};
+
+/// Modification time and size of a watched file, or `None` if it could not
+/// be read
+type Stamp = Option<(SystemTime, u64)>;
+
+/// Watches files for changes, for `--watch`
+///
+/// On Linux, inotify is used to detect when files may have changed. On
+/// other platforms, files are polled. In both cases, a change is only
+/// reported once file modification times or sizes differ from the last
+/// snapshot.
+pub(crate) struct Watcher {
+  files: Vec<PathBuf>,
+  globs: Vec<Glob>,
+  #[cfg(target_os = "linux")]
+  inotify: Option<Inotify>,
+  snapshot: BTreeMap<PathBuf, Stamp>,
+}
+
+impl Watcher {
+  const DEBOUNCE_MILLIS: u16 = 100;
+
+  const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+  #[cfg(target_os = "linux")]
+  fn add_watch(&self, directory: &Path) {
+    if let Some(inotify) = &self.inotify {
+      inotify
+        .add_watch(
+          directory,
+          AddWatchFlags::IN_ATTRIB
+            | AddWatchFlags::IN_CLOSE_WRITE
+            | AddWatchFlags::IN_CREATE
+            | AddWatchFlags::IN_DELETE
+            | AddWatchFlags::IN_MODIFY
+            | AddWatchFlags::IN_MOVED_FROM
+            | AddWatchFlags::IN_MOVED_TO,
+        )
+        .ok();
+    }
+  }
+
+  #[cfg(not(target_os = "linux"))]
+  fn add_watch(&self, _directory: &Path) {}
+
+  /// Block until files may have changed
+  #[cfg(target_os = "linux")]
+  fn block(&self) {
+    let Some(inotify) = &self.inotify else {
+      thread::sleep(Self::POLL_INTERVAL);
+      return;
+    };
+
+    loop {
+      match Self::poll(inotify, PollTimeout::NONE) {
+        Ok(true) => return,
+        Ok(false) | Err(Errno::EINTR) => {}
+        Err(_) => {
+          thread::sleep(Self::POLL_INTERVAL);
+          return;
+        }
+      }
+    }
+  }
+
+  #[cfg(not(target_os = "linux"))]
+  fn block(&self) {
+    thread::sleep(Self::POLL_INTERVAL);
+  }
+
+  /// Wait for the debounce interval, returning true if files may have changed
+  /// during it
+  #[cfg(target_os = "linux")]
+  fn drain(&self) -> bool {
+    let Some(inotify) = &self.inotify else {
+      thread::sleep(Duration::from_millis(Self::DEBOUNCE_MILLIS.into()));
+      return false;
+    };
+
+    Self::poll(inotify, PollTimeout::from(Self::DEBOUNCE_MILLIS)).unwrap_or_default()
+  }
+
+  #[cfg(not(target_os = "linux"))]
+  fn drain(&self) -> bool {
+    thread::sleep(Duration::from_millis(Self::DEBOUNCE_MILLIS.into()));
+    false
+  }
+
+  pub(crate) fn new(files: Vec<PathBuf>, globs: Vec<Glob>) -> Self {
+    let mut watcher = Self {
+      files,
+      globs,
+      #[cfg(target_os = "linux")]
+      inotify: Inotify::init(InitFlags::IN_CLOEXEC | InitFlags::IN_NONBLOCK).ok(),
+      snapshot: BTreeMap::new(),
+    };
+
+    watcher.snapshot = watcher.scan();
+
+    watcher
+  }
+
+  /// Wait up to `timeout` for inotify events and consume them, returning true
+  /// if there were any
+  #[cfg(target_os = "linux")]
+  fn poll(inotify: &Inotify, timeout: PollTimeout) -> Result<bool, Errno> {
+    let ready = nix::poll::poll(
+      &mut [PollFd::new(inotify.as_fd(), PollFlags::POLLIN)],
+      timeout,
+    )?;
+
+    Ok(ready > 0 && matches!(inotify.read_events(), Ok(events) if !events.is_empty()))
+  }
+
+  fn scan(&self) -> BTreeMap<PathBuf, Stamp> {
+    let mut snapshot = BTreeMap::new();
+
+    for file in &self.files {
+      if let Some(parent) = file.parent() {
+        self.add_watch(parent);
+      }
+
+      snapshot.insert(file.clone(), Self::stamp(fs::metadata(file)));
+    }
+
+    for glob in &self.globs {
+      self.walk(glob, glob.base(), &mut snapshot);
+    }
+
+    snapshot
+  }
+
+  /// Block until watched files have changed, and then until no changes have
+  /// been seen for the debounce interval
+  pub(crate) fn wait(&mut self) {
+    loop {
+      self.block();
+
+      while self.drain() {}
+
+      let snapshot = self.scan();
+
+      if snapshot != self.snapshot {
+        self.snapshot = snapshot;
+        return;
+      }
+    }
+  }
+
+  fn stamp(metadata: io::Result<fs::Metadata>) -> Stamp {
+    let metadata = metadata.ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+  }
+
+  fn walk(&self, glob: &Glob, directory: &Path, snapshot: &mut BTreeMap<PathBuf, Stamp>) {
+    let Ok(entries) = fs::read_dir(directory) else {
+      return;
+    };
+
+    self.add_watch(directory);
+
+    for entry in entries.flatten() {
+      let path = entry.path();
+
+      let Ok(file_type) = entry.file_type() else {
+        continue;
+      };
+
+      if file_type.is_dir() {
+        if entry.file_name() != ".git" {
+          self.walk(glob, &path, snapshot);
+        }
+      } else if glob.is_match(&path) {
+        snapshot.insert(path, Self::stamp(entry.metadata()));
+      }
+    }
+  }
+}
@@ -0,0 +1,345 @@
+use super::*;
+
+/// The justfiles beneath a project root, as run by `--workspace`
+pub(crate) struct Workspace {
+  packages: Vec<Package>,
+  root: PathBuf,
+}
+
+/// A directory in a workspace containing a justfile
+struct Package {
+  justfile: PathBuf,
+  name: String,
+}
+
+/// The result of running a recipe in a package
+struct Report {
+  error_message: Option<String>,
+  exit_code: Option<i32>,
+  output: String,
+}
+
+impl Workspace {
+  /// Find the justfiles beneath the project root containing the invocation
+  /// directory, skipping paths ignored by git and, if any `filters` are given,
+  /// directories whose path relative to the root matches none of them
+  pub(crate) fn find(config: &Config, filters: &[String]) -> SearchResult<Self> {
+    let root = Search::project_root(config.ceiling.as_deref(), &config.invocation_directory)?;
+
+    let mut candidates = BTreeMap::<PathBuf, BTreeSet<PathBuf>>::new();
+
+    for entry in Glob::walker(&root, true) {
+      let entry = entry.map_err(|source| SearchError::Walk {
+        directory: root.clone(),
+        source,
+      })?;
+
+      if entry.file_type().is_none_or(|file_type| file_type.is_dir()) {
+        continue;
+      }
+
+      let is_justfile = entry.file_name().to_str().is_some_and(|name| {
+        search::JUSTFILE_NAMES
+          .iter()
+          .any(|justfile_name| name.eq_ignore_ascii_case(justfile_name))
+      });
+
+      // Entries are files beneath `root`, so they always have a parent
+      if is_justfile && entry.path().is_file() {
+        candidates
+          .entry(entry.path().parent().unwrap().into())
+          .or_default()
+          .insert(entry.into_path());
+      }
+    }
+
+    let mut packages = Vec::new();
+
+    for (directory, mut candidates) in candidates {
+      if candidates.len() > 1 {
+        return Err(SearchError::MultipleCandidates { candidates });
+      }
+
+      // Packages were found by walking `root`, so their directories are
+      // beneath it
+      let relative = directory.strip_prefix(&root).unwrap();
+
+      let name = if relative.as_os_str().is_empty() {
+        ".".into()
+      } else {
+        relative
+          .components()
+          .map(|component| component.as_os_str().to_string_lossy())
+          .collect::<Vec<Cow<str>>>()
+          .join("/")
+      };
+
+      // Directories are only added to `candidates` along with a justfile
+      packages.push(Package {
+        justfile: candidates.pop_first().unwrap(),
+        name,
+      });
+    }
+
+    let filters = filters
+      .iter()
+      .map(|filter| {
+        Glob::matcher(filter.trim_end_matches('/')).map_err(|source| SearchError::Filter {
+          filter: filter.clone(),
+          source,
+        })
+      })
+      .collect::<SearchResult<Vec<GlobMatcher>>>()?;
+
+    if !filters.is_empty() {
+      packages.retain(|package| filters.iter().any(|filter| filter.is_match(&package.name)));
+    }
+
+    Ok(Self { packages, root })
+  }
+
+  /// Run the recipe named by the first of `arguments` in each package whose
+  /// justfile defines it, up to `jobs` at a time, writing a TAP stream with one
+  /// subtest per package
+  pub(crate) fn run<'src>(
+    &self,
+    config: &Config,
+    loader: &'src Loader,
+    arguments: &[String],
+    jobs: NonZeroUsize,
+  ) -> RunResult<'src> {
+    let recipe = &arguments[0];
+
+    let path =
+      ModulePath::try_from([recipe.as_str()].as_slice()).map_err(|()| Error::UnknownRecipe {
+        recipe: recipe.clone(),
+        suggestion: None,
+      })?;
+
+    let mut packages = Vec::new();
+
+    for package in &self.packages {
+      match Compiler::compile(config, loader, &package.justfile) {
+        Ok(compilation) => {
+          if Subcommand::resolve_path(&compilation.justfile, &path).is_ok() {
+            packages.push((package, None));
+          }
+        }
+        Err(error) => {
+          let message = error.color_display(Color::never()).to_string();
+          packages.push((package, Some(message)));
+        }
+      }
+    }
+
+    if packages.is_empty() {
+      return Err(Error::WorkspaceUnknownRecipe {
+        recipe: recipe.clone(),
+        root: self.root.clone(),
+      });
+    }
+
+    let color = config.color.stdout().active();
+
+    {
+      let mut stdout = io::stdout().lock();
+      let mut writer = tap_dancer::TapWriterBuilder::new(&mut stdout)
+        .color(color)
+        .default_locale()
+        .build()
+        .map_err(|io_error| Error::StdoutIo { io_error })?;
+      writer
+        .plan_ahead(packages.len())
+        .map_err(|io_error| Error::StdoutIo { io_error })?;
+    }
+
+    let (sender, receiver) = mpsc::channel();
+
+    let next = atomic::AtomicUsize::new(0);
+
+    let failures = thread::scope(|scope| {
+      for _ in 0..jobs.get().min(packages.len()) {
+        let sender = sender.clone();
+        let next = &next;
+        let packages = &packages;
+        scope.spawn(move || {
+          loop {
+            let index = next.fetch_add(1, atomic::Ordering::Relaxed);
+
+            let Some((package, compile_error)) = packages.get(index) else {
+              break;
+            };
+
+            let report = match compile_error {
+              Some(message) => Report {
+                error_message: Some(message.clone()),
+                exit_code: None,
+                output: String::new(),
+              },
+              None => Self::run_package(config, package, arguments, color, jobs),
+            };
+
+            if sender.send((index, report)).is_err() {
+              break;
+            }
+          }
+        });
+      }
+
+      drop(sender);
+
+      // Packages may finish in any order, but are reported in the order in
+      // which they were found
+      let mut finished = BTreeMap::new();
+      let mut reported = 0;
+      let mut failures = 0;
+
+      for (index, report) in receiver {
+        finished.insert(index, report);
+
+        while let Some(report) = finished.remove(&reported) {
+          let (package, _) = packages[reported];
+          reported += 1;
+          if !Self::report(color, reported, package, report)? {
+            failures += 1;
+          }
+        }
+      }
+
+      Ok(failures)
+    })
+    .map_err(|io_error| Error::StdoutIo { io_error })?;
+
+    let count = packages.len();
+
+    writeln!(
+      io::stdout(),
+      "# {} of {count} {} passed",
+      count - failures,
+      Count("package", count),
+    )
+    .map_err(|io_error| Error::StdoutIo { io_error })?;
+
+    if failures > 0 {
+      Err(Error::TapFailure { count, failures })
+    } else {
+      Ok(())
+    }
+  }
+
+  /// Write `report` as a subtest followed by a test point, returning whether
+  /// the package passed
+  fn report(color: bool, number: usize, package: &Package, report: Report) -> io::Result<bool> {
+    let mut stdout = io::stdout().lock();
+
+    let lines = report
+      .output
+      .lines()
+      .map(|line| line.trim_end_matches('\r'))
+      .filter(|line| !is_visually_empty(line))
+      .collect::<Vec<&str>>();
+
+    if !lines.is_empty() {
+      writeln!(stdout, "    # Subtest: {}", package.name)?;
+      for line in lines {
+        writeln!(stdout, "    {line}")?;
+      }
+    }
+
+    let ok = report.error_message.is_none();
+
+    let mut writer = tap_dancer::TapWriterBuilder::new(&mut stdout)
+      .color(color)
+      .default_locale()
+      .build_without_printing()?;
+
+    writer.test_point(&tap_dancer::TestResult {
+      number,
+      name: package.name.clone(),
+      ok,
+      directive: None,
+      error_message: report.error_message,
+      exit_code: report.exit_code,
+      output: None,
+      suppress_yaml: ok,
+    })?;
+
+    Ok(ok)
+  }
+
+  fn run_package(
+    config: &Config,
+    package: &Package,
+    arguments: &[String],
+    color: bool,
+    jobs: NonZeroUsize,
+  ) -> Report {
+    // Justfiles were found by walking a directory, so they have a parent
+    let directory = package.justfile.parent().unwrap();
+
+    let mut command = Command::new(env::current_exe().unwrap_or_else(|_| "just".into()));
+
+    command
+      .current_dir(directory)
+      .arg("--justfile")
+      .arg(&package.justfile)
+      .arg("--working-directory")
+      .arg(directory)
+      .arg("--output-format")
+      .arg(OutputFormat::Tap.to_string())
+      .arg("--color")
+      .arg(if color { "always" } else { "never" });
+
+    if config.dry_run {
+      command.arg("--dry-run");
+    }
+
+    if config.unstable {
+      command.arg("--unstable");
+    }
+
+    if config.verbosity.quiet() {
+      command.arg("--quiet");
+    }
+
+    if config.yes {
+      command.arg("--yes");
+    }
+
+    for (name, value) in &config.overrides {
+      command.arg("--set").arg(name).arg(value);
+    }
+
+    command
+      .args(arguments)
+      .stdin(if jobs.get() == 1 {
+        Stdio::inherit()
+      } else {
+        Stdio::null()
+      })
+      .stdout(Stdio::piped())
+      .stderr(Stdio::piped());
+
+    match command.output() {
+      Ok(output) => {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_owned();
+        Report {
+          error_message: (!output.status.success()).then(|| {
+            if stderr.is_empty() {
+              format!("Recipe `{}` failed", arguments[0])
+            } else {
+              stderr
+            }
+          }),
+          exit_code: output.status.code().filter(|_| !output.status.success()),
+          output: String::from_utf8_lossy(&output.stdout).into_owned(),
+        }
+      }
+      Err(io_error) => Report {
+        error_message: Some(format!("Failed to invoke `just`: {io_error}")),
+        exit_code: None,
+        output: String::new(),
+      },
+    }
+  }
+}
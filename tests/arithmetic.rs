@@ -0,0 +1,392 @@
+use super::*;
+
+#[test]
+fn arithmetic_is_unstable() {
+  Test::new()
+    .justfile(
+      "
+        x := 1 + 2
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stderr_regex("error: Integer arithmetic is currently unstable..*")
+    .failure();
+}
+
+#[test]
+fn integer_literal() {
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        x := 42
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout("42")
+    .success();
+}
+
+#[test]
+fn operators() {
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        foo:
+          @echo {{ 7 + 2 }} {{ 7 - 2 }} {{ 7 * 2 }} {{ 7 / 2 }} {{ 7 % 2 }}
+      ",
+    )
+    .stdout("9 5 14 3 1\n")
+    .success();
+}
+
+#[test]
+fn negative_result() {
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        x := 2 - 5
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout("-3")
+    .success();
+}
+
+#[test]
+fn precedence() {
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        foo:
+          @echo {{ 1 + 2 * 3 }} {{ (1 + 2) * 3 }} {{ 10 - 4 - 3 }} {{ 2 * 9 / 3 % 4 }}
+      ",
+    )
+    .stdout("7 9 3 2\n")
+    .success();
+}
+
+#[test]
+fn numeric_string_operands() {
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        foo:
+          @echo {{ `echo 7` - 2 }} {{ `echo 6` * 7 }} {{ int(`echo 7`) / 2 }}
+      ",
+    )
+    .stdout("5 42 3\n")
+    .success();
+}
+
+#[test]
+fn plus_on_strings_concatenates() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{ '1' + '2' }}
+      ",
+    )
+    .stdout("12\n")
+    .success();
+}
+
+#[test]
+fn plus_on_string_and_integer_concatenates() {
+  Test::new()
+    .justfile(
+      "
+        port := 'port-' + json_parse('{\"port\":8080}').port
+
+        foo:
+          @echo {{ port }}
+      ",
+    )
+    .stdout("port-8080\n")
+    .success();
+
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        foo:
+          @echo {{ 'a' + 2 }} {{ 'a' / 2 }} {{ 1 + 2 }}
+      ",
+    )
+    .stdout("a2 a/2 3\n")
+    .success();
+}
+
+#[test]
+fn plus_and_slash_on_numeric_string_and_integer() {
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        foo:
+          @echo {{ '3' + 4 }} {{ 4 + '3' }} {{ `echo 8` / 2 }} {{ num_cpus() / 2 }}
+      ",
+    )
+    .stdout(format!("7 7 4 {}\n", num_cpus::get() / 2))
+    .success();
+}
+
+#[test]
+fn slash_on_strings_joins_paths() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{ '6' / '3' }}
+      ",
+    )
+    .stdout("6/3\n")
+    .success();
+}
+
+#[test]
+fn comparison_operators() {
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        foo:
+          @echo {{ if 10 > 9 { 'a' } else { 'b' } }} \
+            {{ if 9 >= 9 { 'a' } else { 'b' } }} \
+            {{ if '10' < '9' { 'a' } else { 'b' } }} \
+            {{ if 2 <= 1 { 'a' } else { 'b' } }}
+      ",
+    )
+    .stdout("a a b b\n")
+    .success();
+}
+
+#[test]
+fn comparison_is_unstable() {
+  Test::new()
+    .justfile(
+      "
+        x := if '1' < '2' { 'a' } else { 'b' }
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stderr_regex("error: Integer arithmetic is currently unstable..*")
+    .failure();
+}
+
+#[test]
+fn int_function() {
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        x := int(' 3 ') * 4
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout("12")
+    .success();
+}
+
+#[test]
+fn bool_function() {
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        foo:
+          @echo {{ bool('true') }} {{ bool('false') }} {{ bool(0) }} {{ bool(3) }}
+      ",
+    )
+    .stdout("true false false true\n")
+    .success();
+}
+
+#[test]
+fn division_by_zero() {
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        x := 1 / 0
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: Operator `/` failed: Division by zero
+         ——▶ justfile:3:8
+          │
+        3 │ x := 1 / 0
+          │        ^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn non_integer_operand() {
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        x := 'a' - 1
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: Operator `-` failed: Expected integer but found `a`
+         ——▶ justfile:3:10
+          │
+        3 │ x := 'a' - 1
+          │          ^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn non_integer_comparison() {
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        x := if 'a' < '1' { 'a' } else { 'b' }
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: Operator `<` failed: Expected integer but found `a`
+         ——▶ justfile:3:13
+          │
+        3 │ x := if 'a' < '1' { 'a' } else { 'b' }
+          │             ^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn overflow() {
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        x := 9223372036854775807 + 1
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: Operator `+` failed: Integer overflow
+         ——▶ justfile:3:26
+          │
+        3 │ x := 9223372036854775807 + 1
+          │                          ^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn integer_literal_too_large() {
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        x := 9223372036854775808
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: Integer literal is too large
+         ——▶ justfile:3:6
+          │
+        3 │ x := 9223372036854775808
+          │      ^^^^^^^^^^^^^^^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn int_function_error() {
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        x := int('abc')
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: Call to function `int` failed: `abc` is not an integer
+         ——▶ justfile:3:6
+          │
+        3 │ x := int('abc')
+          │      ^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        x := 1 + 2 * (3 - 4) % 5
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+        set unstable := true
+
+        x := 1 + 2 * (3 - 4) % 5
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn dump_json() {
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        x := 1 - 2 * 3
+      ",
+    )
+    .args(["--dump", "--dump-format", "json"])
+    .stdout_regex(r#".*"value":\["-",1,\["\*",2,3\]\].*"#)
+    .success();
+}
@@ -15,3 +15,126 @@ foos := `print('foo' * 4)`
     .stdout("foofoofoofoo")
     .success();
 }
+
+#[test]
+fn independent_backticks_run_in_parallel() {
+  // Each backtick waits for the other to start, so evaluating them one after
+  // another fails
+  Test::new()
+    .justfile(
+      "
+        set parallel-assignments
+
+        a := `touch a; i=0; until [ -f b ]; do i=$((i+1)); [ $i -lt 1000 ] || exit 1; sleep 0.01; done; echo a`
+        b := `touch b; i=0; until [ -f a ]; do i=$((i+1)); [ $i -lt 1000 ] || exit 1; sleep 0.01; done; echo b`
+
+        foo:
+          @echo {{ a }}{{ b }}
+      ",
+    )
+    .stdout("ab\n")
+    .success();
+}
+
+#[test]
+fn dependent_backticks_run_after_dependencies() {
+  Test::new()
+    .justfile(
+      "
+        set parallel-assignments
+
+        a := `echo a > a.txt; echo a`
+        b := a + `cat a.txt`
+        c := b + `echo c`
+      ",
+    )
+    .args(["--evaluate", "c"])
+    .stdout("aac")
+    .success();
+}
+
+#[test]
+fn first_error_is_reported() {
+  Test::new()
+    .justfile(
+      "
+        set parallel-assignments
+
+        a := `exit 2`
+        b := `exit 3`
+        c := `exit 4`
+      ",
+    )
+    .stderr(
+      "
+        error: Backtick failed with exit code 2
+         ——▶ justfile:3:6
+          │
+        3 │ a := `exit 2`
+          │      ^^^^^^^^
+      ",
+    )
+    .status(2);
+}
+
+#[test]
+fn dependency_error_is_reported_first() {
+  Test::new()
+    .justfile(
+      "
+        set parallel-assignments
+
+        a := c + 'x'
+        b := `exit 3`
+        c := `exit 4`
+      ",
+    )
+    .stderr(
+      "
+        error: Backtick failed with exit code 4
+         ——▶ justfile:5:6
+          │
+        5 │ c := `exit 4`
+          │      ^^^^^^^^
+      ",
+    )
+    .status(4);
+}
+
+#[test]
+fn first_error_in_source_order_is_reported() {
+  Test::new()
+    .justfile(
+      "
+        set parallel-assignments
+
+        b := `exit 3`
+        a := `exit 2`
+      ",
+    )
+    .stderr(
+      "
+        error: Backtick failed with exit code 3
+         ——▶ justfile:3:6
+          │
+        3 │ b := `exit 3`
+          │      ^^^^^^^^
+      ",
+    )
+    .status(3);
+}
+
+#[test]
+fn backticks_run_serially_by_default() {
+  Test::new()
+    .justfile(
+      "
+        a := `echo a >> log; echo a`
+        b := `echo b >> log; echo b`
+        c := `echo c >> log; cat log`
+      ",
+    )
+    .args(["--evaluate", "c"])
+    .stdout("a\nb\nc")
+    .success();
+}
@@ -437,3 +437,203 @@ fn dotenv_path_does_not_override_dotenv_file() {
     .stdout("ROOT\n")
     .success();
 }
+
+#[test]
+fn dotenv_path_list_is_layered() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-path := ['.env', '.env.local']
+
+        @foo:
+          echo $A $B
+      ",
+    )
+    .write(".env", "A=a\nB=b")
+    .write(".env.local", "B=local")
+    .stdout("a local\n")
+    .success();
+}
+
+#[test]
+fn dotenv_path_list_skips_missing_files() {
+  Test::new()
+    .justfile(
+      "
+        env := 'dev'
+
+        set dotenv-path := ['.env', '.env.local', f'.env.{{env}}']
+
+        @foo:
+          echo $A $B
+      ",
+    )
+    .write(".env", "A=a\nB=b")
+    .write(".env.dev", "B=dev")
+    .stdout("a dev\n")
+    .success();
+}
+
+#[test]
+fn dotenv_path_list_required() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-path := ['.env', '.env.local']
+        set dotenv-required
+
+        foo:
+      ",
+    )
+    .stderr("error: Dotenv file not found\n")
+    .failure();
+}
+
+#[test]
+fn dotenv_values_may_refer_to_earlier_files() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-path := ['.env', '.env.local']
+
+        @foo:
+          echo $B
+      ",
+    )
+    .write(".env", "A='a \"$x\"'")
+    .write(".env.local", "B=${A}-b")
+    .stdout("a \"$x\"-b\n")
+    .success();
+}
+
+#[test]
+fn dotenv_values_may_refer_to_earlier_values_in_same_file() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-load
+
+        @foo:
+          echo $C
+      ",
+    )
+    .write(".env", "A=a\nB=\"$A b\"\nC=${B}-c")
+    .stdout("a b-c\n")
+    .success();
+}
+
+#[test]
+fn evaluate_shows_dotenv_provenance() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-path := ['.env', '.env.local']
+
+        x := 'y'
+      ",
+    )
+    .write(".env", "A=a\nB=b")
+    .write(".env.local", "B=local")
+    .arg("--evaluate")
+    .stdout(
+      "
+        x := \"y\"
+        # `A` loaded from `.env`
+        # `B` loaded from `.env.local`
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn dotenv_path_argument_may_be_repeated() {
+  Test::new()
+    .justfile(
+      "
+        @foo:
+          echo $A $B
+      ",
+    )
+    .write("a.env", "A=a\nB=b")
+    .write("b.env", "B=c")
+    .args(["--dotenv-path", "a.env", "-E", "b.env"])
+    .stdout("a c\n")
+    .success();
+}
+
+#[test]
+fn module_dotenv() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-load
+
+        mod foo
+
+        @bar:
+          echo $A $B
+      ",
+    )
+    .write(".env", "A=a\nB=b")
+    .write("foo/mod.just", "set dotenv-load\n@baz:\n  echo $A $B")
+    .write("foo/.env", "B=foo")
+    .args(["foo::baz", "bar"])
+    .stdout("a foo\na b\n")
+    .success();
+}
+
+#[test]
+fn module_inherits_parent_dotenv() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-load
+
+        mod foo
+      ",
+    )
+    .write(".env", "A=a")
+    .write("foo/mod.just", "@baz:\n  echo $A")
+    .args(["foo::baz"])
+    .stdout("a\n")
+    .success();
+}
+
+#[test]
+fn dotenv_path_argument_does_not_apply_to_modules() {
+  Test::new()
+    .justfile("mod foo")
+    .write("a.env", "A=a")
+    .write("foo/b.env", "A=b")
+    .write(
+      "foo/mod.just",
+      "set dotenv-path := 'b.env'\n@baz:\n  echo $A",
+    )
+    .args(["--dotenv-path", "a.env", "foo::baz"])
+    .stdout("b\n")
+    .success();
+}
+
+#[test]
+fn verbose_output_includes_provenance() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-path := ['.env', '.env.local']
+
+        @foo:
+      ",
+    )
+    .write(".env", "A=a\nB=b")
+    .write(".env.local", "B=local")
+    .arg("--verbose")
+    .stderr(
+      "
+        ===> Loaded `A` from `.env`
+        ===> Loaded `B` from `.env`
+        ===> Loaded `B` from `.env.local`
+        ===> Running recipe `foo`...
+      ",
+    )
+    .success();
+}
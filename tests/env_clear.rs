@@ -0,0 +1,251 @@
+use super::*;
+
+#[test]
+fn env_clear_removes_inherited_variables() {
+  Test::new()
+    .justfile(
+      "
+        set env-clear
+
+        foo:
+          @echo ${SECRET:-unset}
+      ",
+    )
+    .env("SECRET", "hunter2")
+    .stdout("unset\n")
+    .success();
+}
+
+#[test]
+fn env_clear_keeps_exported_variables() {
+  Test::new()
+    .justfile(
+      "
+        set env-clear
+
+        export FOO := 'bar'
+
+        baz:
+          @echo $FOO
+      ",
+    )
+    .stdout("bar\n")
+    .success();
+}
+
+#[test]
+fn env_clear_keeps_env_attribute_variables() {
+  Test::new()
+    .justfile(
+      "
+        set env-clear
+
+        [env('FOO', 'bar')]
+        baz:
+          @echo $FOO
+      ",
+    )
+    .stdout("bar\n")
+    .success();
+}
+
+#[test]
+fn env_clear_keeps_exported_parameters() {
+  Test::new()
+    .justfile(
+      "
+        set env-clear
+
+        baz $FOO:
+          @echo $FOO
+      ",
+    )
+    .args(["baz", "bar"])
+    .stdout("bar\n")
+    .success();
+}
+
+#[test]
+fn env_clear_keeps_dotenv_variables() {
+  Test::new()
+    .justfile(
+      "
+        set env-clear
+        set dotenv-load
+
+        baz:
+          @echo $FOO
+      ",
+    )
+    .write(".env", "FOO=bar")
+    .stdout("bar\n")
+    .success();
+}
+
+#[test]
+fn env_clear_with_env_pass() {
+  Test::new()
+    .justfile(
+      "
+        set env-clear
+
+        [env-pass('PASSED')]
+        foo:
+          @echo ${PASSED:-unset} ${SECRET:-unset}
+      ",
+    )
+    .env("PASSED", "passed")
+    .env("SECRET", "hunter2")
+    .stdout("passed unset\n")
+    .success();
+}
+
+#[test]
+fn env_pass_without_env_clear_clears_recipe_environment() {
+  Test::new()
+    .justfile(
+      "
+        [env-pass('PASSED')]
+        foo:
+          @echo ${PASSED:-unset} ${SECRET:-unset}
+
+        bar:
+          @echo ${PASSED:-unset} ${SECRET:-unset}
+      ",
+    )
+    .env("PASSED", "passed")
+    .env("SECRET", "hunter2")
+    .args(["foo", "bar"])
+    .stdout("passed unset\npassed hunter2\n")
+    .success();
+}
+
+#[test]
+fn env_pass_multiple_arguments_and_attributes() {
+  Test::new()
+    .justfile(
+      "
+        [env-pass('A', 'B')]
+        [env-pass('C')]
+        foo:
+          @echo ${A:-unset} ${B:-unset} ${C:-unset} ${D:-unset}
+      ",
+    )
+    .env("A", "a")
+    .env("B", "b")
+    .env("C", "c")
+    .env("D", "d")
+    .stdout("a b c unset\n")
+    .success();
+}
+
+#[test]
+fn env_pass_missing_variable_is_ignored() {
+  Test::new()
+    .justfile(
+      "
+        [env-pass('MISSING')]
+        foo:
+          @echo ${MISSING:-unset}
+      ",
+    )
+    .stdout("unset\n")
+    .success();
+}
+
+#[test]
+fn env_clear_applies_to_script_recipes() {
+  Test::new()
+    .justfile(
+      "
+        set env-clear
+
+        [env-pass('PASSED')]
+        foo:
+          #!/bin/sh
+          echo ${PASSED:-unset} ${SECRET:-unset}
+      ",
+    )
+    .env("PASSED", "passed")
+    .env("SECRET", "hunter2")
+    .stdout("passed unset\n")
+    .success();
+}
+
+#[test]
+fn env_clear_applies_to_backticks() {
+  Test::new()
+    .justfile(
+      "
+        set env-clear
+
+        x := `echo ${SECRET:-unset}`
+
+        foo:
+          @echo {{ x }}
+      ",
+    )
+    .env("SECRET", "hunter2")
+    .stdout("unset\n")
+    .success();
+}
+
+#[test]
+fn env_clear_applies_to_shell_function() {
+  Test::new()
+    .justfile(
+      "
+        set env-clear
+
+        x := shell('echo ${SECRET:-unset}')
+
+        foo:
+          @echo {{ x }}
+      ",
+    )
+    .env("SECRET", "hunter2")
+    .stdout("unset\n")
+    .success();
+}
+
+#[test]
+fn env_clear_applies_to_service_probes() {
+  Test::new()
+    .justfile(
+      "
+        set env-clear
+
+        [service(command='test -z \"${SECRET:-}\"', timeout='5')]
+        server:
+          @sleep 60
+
+        test: server
+          @echo tested
+      ",
+    )
+    .env("SECRET", "hunter2")
+    .arg("test")
+    .stdout("tested\n")
+    .success();
+}
+
+#[test]
+fn env_pass_requires_argument() {
+  Test::new()
+    .justfile(
+      "
+        [env-pass]
+        foo:
+      ",
+    )
+    .stderr(
+      "
+        error: Attribute `env-pass` got 0 arguments but takes at least 1 argument
+         ——▶ justfile:1:2
+          │
+        1 │ [env-pass]
+          │  ^^^^^^^^
+      ",
+    )
+    .failure();
+}
@@ -0,0 +1,396 @@
+use super::*;
+
+#[test]
+fn finally_recipe_runs_after_success() {
+  Test::new()
+    .justfile(
+      "
+        [finally('cleanup')]
+        foo:
+          @echo foo
+
+        cleanup:
+          @echo cleanup
+      ",
+    )
+    .stdout("foo\ncleanup\n")
+    .success();
+}
+
+#[test]
+fn finally_recipe_runs_after_failure() {
+  Test::new()
+    .justfile(
+      "
+        [finally('cleanup')]
+        foo:
+          @echo foo
+          @exit 2
+          @echo unreachable
+
+        cleanup:
+          @echo cleanup
+      ",
+    )
+    .stdout("foo\ncleanup\n")
+    .stderr("error: Recipe `foo` failed on line 4 with exit code 2\n")
+    .status(2);
+}
+
+#[test]
+fn finally_recipe_runs_after_failed_dependency() {
+  Test::new()
+    .justfile(
+      "
+        [finally('cleanup')]
+        foo: setup
+          @echo unreachable
+
+        setup:
+          @exit 2
+
+        cleanup:
+          @echo cleanup
+      ",
+    )
+    .stdout("cleanup\n")
+    .stderr("error: Recipe `setup` failed on line 6 with exit code 2\n")
+    .status(2);
+}
+
+#[test]
+fn finally_recipe_runs_after_subsequents() {
+  Test::new()
+    .justfile(
+      "
+        [finally('cleanup')]
+        foo: && bar
+          @echo foo
+
+        bar:
+          @echo bar
+
+        cleanup:
+          @echo cleanup
+      ",
+    )
+    .stdout("foo\nbar\ncleanup\n")
+    .success();
+}
+
+#[test]
+fn finally_recipe_failure_is_reported() {
+  Test::new()
+    .justfile(
+      "
+        [finally('cleanup')]
+        foo:
+          @echo foo
+
+        cleanup:
+          @exit 3
+      ",
+    )
+    .stdout("foo\n")
+    .stderr("error: Recipe `cleanup` failed on line 6 with exit code 3\n")
+    .status(3);
+}
+
+#[test]
+fn recipe_failure_takes_precedence_over_finally_failure() {
+  Test::new()
+    .justfile(
+      "
+        [finally('cleanup')]
+        foo:
+          @exit 2
+
+        cleanup:
+          @exit 3
+      ",
+    )
+    .stderr("error: Recipe `foo` failed on line 3 with exit code 2\n")
+    .status(2);
+}
+
+#[test]
+fn multiple_finally_recipes_all_run() {
+  Test::new()
+    .justfile(
+      "
+        [finally('a', 'b')]
+        [finally('c')]
+        foo:
+          @echo foo
+
+        a:
+          @exit 1
+
+        b:
+          @echo b
+
+        c:
+          @echo c
+      ",
+    )
+    .stdout("foo\nb\nc\n")
+    .stderr("error: Recipe `a` failed on line 7 with exit code 1\n")
+    .failure();
+}
+
+#[test]
+fn finally_recipe_runs_after_each_recipe() {
+  Test::new()
+    .justfile(
+      "
+        [finally('cleanup')]
+        foo:
+          @echo foo
+
+        [finally('cleanup')]
+        bar:
+          @echo bar
+
+        cleanup:
+          @echo cleanup
+      ",
+    )
+    .args(["foo", "bar"])
+    .stdout("foo\ncleanup\nbar\ncleanup\n")
+    .success();
+}
+
+#[test]
+fn finally_recipe_runs_after_running_as_dependency() {
+  Test::new()
+    .justfile(
+      "
+        [finally('cleanup')]
+        foo: cleanup
+          @echo foo
+
+        cleanup:
+          @echo cleanup
+      ",
+    )
+    .arg("foo")
+    .stdout("cleanup\nfoo\ncleanup\n")
+    .success();
+}
+
+#[test]
+fn finally_recipe_runs_with_no_dependencies() {
+  Test::new()
+    .justfile(
+      "
+        [finally('cleanup')]
+        foo: setup
+          @echo foo
+
+        setup:
+          @echo setup
+
+        cleanup:
+          @echo cleanup
+      ",
+    )
+    .arg("--no-deps")
+    .stdout("foo\ncleanup\n")
+    .success();
+}
+
+#[test]
+fn finally_colon_shorthand() {
+  Test::new()
+    .justfile(
+      "
+        [finally: 'cleanup']
+        foo:
+          @echo foo
+
+        cleanup:
+          @echo cleanup
+      ",
+    )
+    .stdout("foo\ncleanup\n")
+    .success();
+}
+
+#[test]
+fn finally_recipe_tap_test_point() {
+  Test::new()
+    .justfile(
+      "
+        [finally('cleanup')]
+        foo:
+          @exit 1
+
+        cleanup:
+          @echo cleanup
+      ",
+    )
+    .env("LC_ALL", "C")
+    .output_format(Some("tap"))
+    .stdout(
+      "
+        TAP version 14
+        1..2
+        not ok 1 - foo
+          ---
+          message: \"error: Recipe `foo` failed on line 3 with exit code 1\"
+          severity: fail
+          exitcode: 1
+          ...
+        ok 2 - cleanup
+          ---
+          output: \"cleanup\"
+          ...
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn shared_finally_recipe_tap_test_points() {
+  Test::new()
+    .justfile(
+      "
+        [finally('cleanup')]
+        foo:
+
+        [finally('cleanup')]
+        bar:
+
+        cleanup:
+      ",
+    )
+    .args(["foo", "bar"])
+    .env("LC_ALL", "C")
+    .output_format(Some("tap"))
+    .stdout(
+      "
+        TAP version 14
+        1..4
+        ok 1 - foo
+        ok 2 - cleanup
+        ok 3 - bar
+        ok 4 - cleanup
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn finally_dump() {
+  Test::new()
+    .justfile(
+      "
+        [finally('cleanup')]
+        foo:
+
+        cleanup:
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+        [finally('cleanup')]
+        foo:
+
+        cleanup:
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn unknown_finally_recipe() {
+  Test::new()
+    .justfile(
+      "
+        [finally('cleanup')]
+        foo:
+      ",
+    )
+    .stderr(
+      "
+        error: Recipe `foo` has unknown finally recipe `cleanup`
+         ——▶ justfile:1:10
+          │
+        1 │ [finally('cleanup')]
+          │          ^^^^^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn finally_recipe_with_required_parameters() {
+  Test::new()
+    .justfile(
+      "
+        [finally('cleanup')]
+        foo:
+
+        cleanup name:
+      ",
+    )
+    .stderr(
+      "
+        error: Recipe `foo` has finally recipe `cleanup`, which takes required parameters
+         ——▶ justfile:1:10
+          │
+        1 │ [finally('cleanup')]
+          │          ^^^^^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[cfg(unix)]
+#[test]
+fn finally_recipe_runs_after_interrupt() {
+  use nix::{sys::signal::Signal, unistd::Pid};
+
+  let tmp = tempdir();
+
+  fs::write(
+    tmp.path().join("justfile"),
+    unindent(
+      "
+        [finally('cleanup')]
+        foo:
+          @sleep 10
+
+        cleanup:
+          @echo cleanup > cleaned
+      ",
+    ),
+  )
+  .unwrap();
+
+  let child = Command::new(JUST)
+    .current_dir(&tmp)
+    .args(["--output-format", "default"])
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()
+    .expect("just invocation failed");
+
+  thread::sleep(Duration::from_millis(500));
+
+  nix::sys::signal::kill(
+    Pid::from_raw(child.id().try_into().unwrap()),
+    Signal::SIGTERM,
+  )
+  .unwrap();
+
+  let output = child.wait_with_output().unwrap();
+
+  assert!(!output.status.success());
+
+  assert_eq!(
+    fs::read_to_string(tmp.path().join("cleaned")).unwrap(),
+    "cleanup\n"
+  );
+}
@@ -0,0 +1,426 @@
+use super::*;
+
+#[test]
+fn loop_generates_line_per_element() {
+  Test::new()
+    .justfile(
+      "
+        packages := ['core', 'cli']
+
+        [loops]
+        build:
+          for pkg in packages {
+            echo {{ pkg }}
+          }
+      ",
+    )
+    .stdout("core\ncli\n")
+    .stderr("echo core\necho cli\n")
+    .success();
+}
+
+#[test]
+fn loop_body_may_contain_multiple_lines() {
+  Test::new()
+    .justfile(
+      "
+        [loops]
+        build:
+          @echo start
+          for pkg in ['a', 'b'] {
+            @echo {{ pkg }}-1
+            @echo {{ pkg }}-2
+          }
+          @echo end
+      ",
+    )
+    .stdout("start\na-1\na-2\nb-1\nb-2\nend\n")
+    .success();
+}
+
+#[test]
+fn nested_loops() {
+  Test::new()
+    .justfile(
+      "
+        [loops]
+        build:
+          for pkg in ['a', 'b'] {
+            for target in ['x', 'y'] {
+              @echo {{ pkg }}-{{ target }}
+            }
+          }
+      ",
+    )
+    .stdout("a-x\na-y\nb-x\nb-y\n")
+    .success();
+}
+
+#[test]
+fn loop_over_variadic_parameter() {
+  Test::new()
+    .justfile(
+      "
+        [loops]
+        build +packages:
+          for pkg in packages {
+            @echo {{ pkg }}
+          }
+      ",
+    )
+    .args(["build", "a", "b c"])
+    .stdout("a\nb c\n")
+    .success();
+}
+
+#[test]
+fn loop_over_string_runs_once() {
+  Test::new()
+    .justfile(
+      "
+        [loops]
+        build:
+          for pkg in 'a b' {
+            @echo {{ pkg }}
+          }
+      ",
+    )
+    .stdout("a b\n")
+    .success();
+}
+
+#[test]
+fn loop_over_empty_list_runs_nothing() {
+  Test::new()
+    .justfile(
+      "
+        [loops]
+        build:
+          for pkg in [] {
+            @echo {{ pkg }}
+          }
+          @echo done
+      ",
+    )
+    .stdout("done\n")
+    .success();
+}
+
+#[test]
+fn failing_line_reports_its_line_number() {
+  Test::new()
+    .justfile(
+      "
+        [loops]
+        build:
+          for code in ['0', '3'] {
+            @echo {{ code }}
+            @exit {{ code }}
+          }
+      ",
+    )
+    .stdout("0\n3\n")
+    .stderr("error: Recipe `build` failed on line 5 with exit code 3\n")
+    .status(3);
+}
+
+#[test]
+fn infallible_lines_in_loop() {
+  Test::new()
+    .justfile(
+      "
+        [loops]
+        build:
+          for code in ['1', '2'] {
+            @-exit {{ code }}
+          }
+          @echo done
+      ",
+    )
+    .stdout("done\n")
+    .success();
+}
+
+#[test]
+fn loop_variable_shadows_assignment() {
+  Test::new()
+    .justfile(
+      "
+        pkg := 'outer'
+
+        [loops]
+        build:
+          for pkg in ['inner'] {
+            @echo {{ pkg }}
+          }
+          @echo {{ pkg }}
+      ",
+    )
+    .stdout("inner\nouter\n")
+    .success();
+}
+
+#[test]
+fn loop_variable_is_undefined_outside_loop() {
+  Test::new()
+    .justfile(
+      "
+        [loops]
+        build:
+          for pkg in ['a'] {
+            echo {{ pkg }}
+          }
+          echo {{ pkg }}
+      ",
+    )
+    .stderr(
+      "
+        error: Variable `pkg` not defined
+         ——▶ justfile:6:11
+          │
+        6 │   echo {{ pkg }}
+          │           ^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn undefined_variable_in_loop_list() {
+  Test::new()
+    .justfile(
+      "
+        [loops]
+        build:
+          for pkg in packages {
+            echo {{ pkg }}
+          }
+      ",
+    )
+    .stderr(
+      "
+        error: Variable `packages` not defined
+         ——▶ justfile:3:14
+          │
+        3 │   for pkg in packages {
+          │              ^^^^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn unterminated_loop() {
+  Test::new()
+    .justfile(
+      "
+        [loops]
+        build:
+          for pkg in ['a'] {
+            echo {{ pkg }}
+      ",
+    )
+    .stderr(
+      "
+        error: Unterminated `for` loop
+         ——▶ justfile:3:3
+          │
+        3 │   for pkg in ['a'] {
+          │   ^^^^^^^^^^^^^^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn unmatched_closing_brace_is_a_command() {
+  Test::new()
+    .justfile(
+      "
+        [loops]
+        build:
+          echo }
+      ",
+    )
+    .stdout("}\n")
+    .stderr("echo }\n")
+    .success();
+}
+
+#[test]
+fn shell_for_loops_are_not_just_loops() {
+  Test::new()
+    .justfile(
+      "
+        build:
+          @for x in a b; do echo $x; done
+      ",
+    )
+    .stdout("a\nb\n")
+    .success();
+}
+
+#[test]
+fn loops_are_only_parsed_with_loops_attribute() {
+  Test::new()
+    .justfile(
+      "
+        set shell := ['echo']
+
+        build:
+          for x in $list {
+          print $x
+          }
+      ",
+    )
+    .shell(false)
+    .stdout("for x in $list {\nprint $x\n}\n")
+    .stderr("for x in $list {\nprint $x\n}\n")
+    .success();
+}
+
+#[test]
+fn loops_attribute_on_shebang_recipe() {
+  Test::new()
+    .justfile(
+      "
+        [loops]
+        build:
+          #!/usr/bin/env sh
+          for pkg in a b; do
+            echo $pkg
+          done
+      ",
+    )
+    .stderr(
+      "
+        error: Recipe `build` has a `[loops]` attribute, but loops are not supported in script recipes
+         ——▶ justfile:2:1
+          │
+        2 │ build:
+          │ ^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn dry_run() {
+  Test::new()
+    .justfile(
+      "
+        [loops]
+        build:
+          for pkg in ['a', 'b'] {
+            @echo {{ pkg }}
+          }
+      ",
+    )
+    .arg("--dry-run")
+    .stderr("echo a\necho b\n")
+    .success();
+}
+
+#[test]
+fn tap_output() {
+  Test::new()
+    .justfile(
+      "
+        [loops]
+        build:
+          for pkg in ['a', 'b'] {
+            @echo {{ pkg }}
+          }
+      ",
+    )
+    .env("LC_ALL", "C")
+    .output_format(Some("tap"))
+    .stdout(
+      "
+        TAP version 14
+        1..1
+        ok 1 - build
+          ---
+          output: \"a\\nb\"
+          ...
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(
+      "
+        [loops]
+        build:
+          for pkg in ['a', 'b'] {
+          for target in ['x'] {
+          echo {{ pkg }}
+          }
+          }
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+        [loops]
+        build:
+            for pkg in ['a', 'b'] {
+              for target in ['x'] {
+                echo {{ pkg }}
+              }
+            }
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn for_expression() {
+  Test::new()
+    .justfile(
+      "
+        binaries := for pkg in ['core', 'cli'] { 'target/' + pkg }
+
+        build:
+          @echo {{ binaries }}
+      ",
+    )
+    .stdout("target/core target/cli\n")
+    .success();
+}
+
+#[test]
+fn for_expression_dump() {
+  Test::new()
+    .justfile(
+      "
+        binaries := for pkg in ['core', 'cli'] { 'target/' + pkg }
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+        binaries := for pkg in ['core', 'cli'] { 'target/' + pkg }
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn variable_named_for() {
+  Test::new()
+    .justfile(
+      "
+        for := 'bar'
+        foo := for
+
+        build:
+          @echo {{ foo }}
+      ",
+    )
+    .stdout("bar\n")
+    .success();
+}
@@ -149,7 +149,7 @@ fn unclosed() {
     .justfile("foo := f'FOO{{")
     .stderr(
       "
-        error: Expected backtick, identifier, '(', '/', or string, but found end of file
+        error: Expected backtick, '[', identifier, integer, '(', '/', or string, but found end of file
          ——▶ justfile:1:15
           │
         1 │ foo := f'FOO{{
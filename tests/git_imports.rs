@@ -0,0 +1,360 @@
+use super::*;
+
+fn git(directory: &Path, arguments: &[&str]) -> String {
+  let output = Command::new("git")
+    .current_dir(directory)
+    .args(["-c", "user.name=just", "-c", "user.email=just@example.com"])
+    .args(arguments)
+    .output()
+    .unwrap();
+  assert!(output.status.success(), "{output:?}");
+  String::from_utf8(output.stdout).unwrap().trim().into()
+}
+
+/// Commit the files in `shared` to a repository there, tagging the commit
+/// with `tag`, and return the commit hash
+fn commit(test: &Test, tag: &str) -> String {
+  let repository = test.tempdir.path().join("shared");
+  if !repository.join(".git").is_dir() {
+    git(&repository, &["init", "--quiet", "--initial-branch=main"]);
+  }
+  git(&repository, &["add", "--all"]);
+  git(&repository, &["commit", "--quiet", "--message", tag]);
+  git(&repository, &["tag", tag]);
+  git(&repository, &["rev-parse", "HEAD"])
+}
+
+fn lockfile(entries: &[(&str, &str)]) -> String {
+  let entries = entries
+    .iter()
+    .map(|(rev, commit)| format!("      \"{rev}\": \"{commit}\""))
+    .collect::<Vec<String>>()
+    .join(",\n");
+  format!("{{\n  \"git\": {{\n    \"shared\": {{\n{entries}\n    }}\n  }}\n}}\n")
+}
+
+#[test]
+fn import() {
+  let test = Test::new()
+    .write("shared/rust.just", "build:\n @echo build")
+    .env("JUST_CACHE_DIRECTORY", "cache");
+
+  let commit = commit(&test, "v1");
+
+  test
+    .justfile(
+      "
+        import git('shared', rev = 'v1', path = 'rust.just')
+      ",
+    )
+    .arg("build")
+    .stdout("build\n")
+    .expect_file("justfile.lock", lockfile(&[("v1", &commit)]))
+    .success();
+}
+
+#[test]
+fn import_defaults_to_justfile() {
+  let test = Test::new()
+    .write("shared/justfile", "build:\n @echo build")
+    .env("JUST_CACHE_DIRECTORY", "cache");
+
+  commit(&test, "v1");
+
+  test
+    .justfile("import git('shared', rev = 'v1')")
+    .arg("build")
+    .stdout("build\n")
+    .success();
+}
+
+#[test]
+fn import_at_branch() {
+  let test = Test::new()
+    .write("shared/justfile", "build:\n @echo build")
+    .env("JUST_CACHE_DIRECTORY", "cache");
+
+  let commit = commit(&test, "v1");
+
+  test
+    .justfile("import git('shared', rev = 'main')")
+    .arg("build")
+    .stdout("build\n")
+    .expect_file("justfile.lock", lockfile(&[("main", &commit)]))
+    .success();
+}
+
+#[test]
+fn module() {
+  let test = Test::new()
+    .write("shared/mod.just", "build:\n @basename $PWD")
+    .create_dir("project")
+    .env("JUST_CACHE_DIRECTORY", "../cache");
+
+  commit(&test, "v1");
+
+  test
+    .write("project/justfile", "mod tools git('../shared', rev = 'v1')")
+    .current_dir("project")
+    .arg("tools::build")
+    .stdout("project\n")
+    .success();
+}
+
+#[test]
+fn module_with_path() {
+  let test = Test::new()
+    .write("shared/tools/mod.just", "build:\n @echo build")
+    .env("JUST_CACHE_DIRECTORY", "cache");
+
+  commit(&test, "v1");
+
+  test
+    .justfile("mod tools git('shared', rev = 'v1', path = 'tools')")
+    .arg("tools::build")
+    .stdout("build\n")
+    .success();
+}
+
+#[test]
+fn lockfile_pins_revision() {
+  let test = Test::new()
+    .write("shared/justfile", "build:\n @echo v1")
+    .justfile("import git('shared', rev = 'main')")
+    .env("JUST_CACHE_DIRECTORY", "cache");
+
+  let v1 = commit(&test, "v1");
+
+  let tempdir = test.arg("build").stdout("v1\n").success().tempdir;
+
+  let test = Test::with_tempdir(tempdir)
+    .write("shared/justfile", "build:\n @echo v2")
+    .justfile("import git('shared', rev = 'main')")
+    .env("JUST_CACHE_DIRECTORY", "cache");
+
+  let v2 = commit(&test, "v2");
+
+  let tempdir = test
+    .arg("build")
+    .stdout("v1\n")
+    .expect_file("justfile.lock", lockfile(&[("main", &v1)]))
+    .success()
+    .tempdir;
+
+  fs::remove_file(tempdir.path().join("justfile.lock")).unwrap();
+
+  Test::with_tempdir(tempdir)
+    .justfile("import git('shared', rev = 'main')")
+    .env("JUST_CACHE_DIRECTORY", "cache")
+    .arg("build")
+    .stdout("v2\n")
+    .expect_file("justfile.lock", lockfile(&[("main", &v2)]))
+    .success();
+}
+
+#[test]
+fn locked_checkouts_do_not_need_repository() {
+  let test = Test::new()
+    .write("shared/justfile", "build:\n @echo build")
+    .justfile("import git('shared', rev = 'v1')")
+    .env("JUST_CACHE_DIRECTORY", "cache");
+
+  commit(&test, "v1");
+
+  let tempdir = test.arg("build").stdout("build\n").success().tempdir;
+
+  fs::rename(tempdir.path().join("shared"), tempdir.path().join("moved")).unwrap();
+  fs::create_dir(tempdir.path().join("shared")).unwrap();
+
+  Test::with_tempdir(tempdir)
+    .justfile("import git('shared', rev = 'v1')")
+    .env("JUST_CACHE_DIRECTORY", "cache")
+    .arg("build")
+    .stdout("build\n")
+    .success();
+}
+
+#[test]
+fn unknown_revision() {
+  let test = Test::new()
+    .write("shared/justfile", "build:\n @echo build")
+    .env("JUST_CACHE_DIRECTORY", "cache");
+
+  commit(&test, "v1");
+
+  test
+    .justfile("import git('shared', rev = 'v2')")
+    .arg("build")
+    .stderr(
+      "
+        error: Failed to import revision `v2` of git repository `shared`: revision `v2` not found
+         ——▶ justfile:1:12
+          │
+        1 │ import git('shared', rev = 'v2')
+          │            ^^^^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn missing_file() {
+  let test = Test::new()
+    .write("shared/justfile", "build:\n @echo build")
+    .env("JUST_CACHE_DIRECTORY", "cache");
+
+  commit(&test, "v1");
+
+  test
+    .justfile("import git('shared', rev = 'v1', path = 'rust.just')")
+    .arg("build")
+    .stderr(
+      "
+        error: Could not find source file for import.
+         ——▶ justfile:1:41
+          │
+        1 │ import git('shared', rev = 'v1', path = 'rust.just')
+          │                                         ^^^^^^^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn optional_missing_file() {
+  let test = Test::new()
+    .write("shared/justfile", "build:\n @echo build")
+    .env("JUST_CACHE_DIRECTORY", "cache");
+
+  commit(&test, "v1");
+
+  test
+    .justfile(
+      "
+        import? git('shared', rev = 'v1', path = 'rust.just')
+
+        foo:
+          @echo foo
+      ",
+    )
+    .stdout("foo\n")
+    .success();
+}
+
+#[test]
+fn lockfile_is_only_written_when_running_recipes() {
+  let test = Test::new()
+    .write("shared/justfile", "build:\n @echo build")
+    .justfile("import git('shared', rev = 'v1')")
+    .env("JUST_CACHE_DIRECTORY", "cache");
+
+  let commit = commit(&test, "v1");
+
+  let tempdir = test
+    .arg("--list")
+    .stdout("Available recipes:\n    build\n")
+    .success()
+    .tempdir;
+
+  assert!(!tempdir.path().join("justfile.lock").exists());
+
+  Test::with_tempdir(tempdir)
+    .justfile("import git('shared', rev = 'v1')")
+    .env("JUST_CACHE_DIRECTORY", "cache")
+    .arg("build")
+    .stdout("build\n")
+    .expect_file("justfile.lock", lockfile(&[("v1", &commit)]))
+    .success();
+}
+
+#[test]
+fn stale_lockfile_is_removed() {
+  let test = Test::new()
+    .write("shared/justfile", "build:\n @echo build")
+    .justfile("import git('shared', rev = 'v1')")
+    .env("JUST_CACHE_DIRECTORY", "cache");
+
+  commit(&test, "v1");
+
+  let tempdir = test.arg("build").stdout("build\n").success().tempdir;
+
+  assert!(tempdir.path().join("justfile.lock").is_file());
+
+  let tempdir = Test::with_tempdir(tempdir)
+    .justfile("build:\n @echo local")
+    .arg("build")
+    .stdout("local\n")
+    .success()
+    .tempdir;
+
+  assert!(!tempdir.path().join("justfile.lock").exists());
+}
+
+#[test]
+fn repository_beginning_with_dash() {
+  let tempdir = Test::new()
+    .justfile("import git('--upload-pack=touch pwned', rev = 'v1')")
+    .env("JUST_CACHE_DIRECTORY", "cache")
+    .arg("--list")
+    .stderr(
+      "
+        error: Failed to import revision `v1` of git repository `--upload-pack=touch pwned`: repository may not begin with `-`
+         ——▶ justfile:1:12
+          │
+        1 │ import git('--upload-pack=touch pwned', rev = 'v1')
+          │            ^^^^^^^^^^^^^^^^^^^^^^^^^^^
+      ",
+    )
+    .failure()
+    .tempdir;
+
+  assert!(!tempdir.path().join("pwned").exists());
+}
+
+#[test]
+fn locked_commit_must_be_commit_hash() {
+  let test = Test::new()
+    .write("shared/justfile", "build:\n @echo build")
+    .write("justfile.lock", lockfile(&[("v1", "../..")]))
+    .env("JUST_CACHE_DIRECTORY", "cache");
+
+  commit(&test, "v1");
+
+  test
+    .justfile("import git('shared', rev = 'v1')")
+    .arg("build")
+    .stderr(
+      "
+        error: Failed to import revision `v1` of git repository `shared`: locked commit `../..` is not a commit hash
+         ——▶ justfile:1:12
+          │
+        1 │ import git('shared', rev = 'v1')
+          │            ^^^^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn path_outside_of_repository() {
+  let test = Test::new()
+    .write("shared/justfile", "build:\n @echo build")
+    .write("secret.just", "secret:\n @echo secret")
+    .env("JUST_CACHE_DIRECTORY", "cache");
+
+  commit(&test, "v1");
+
+  test
+    .justfile("import git('shared', rev = 'v1', path = '../../../secret.just')")
+    .arg("secret")
+    .stderr(
+      "
+        error: Failed to import revision `v1` of git repository `shared`: import path is outside of the repository
+         ——▶ justfile:1:12
+          │
+        1 │ import git('shared', rev = 'v1', path = '../../../secret.just')
+          │            ^^^^^^^^
+      ",
+    )
+    .failure();
+}
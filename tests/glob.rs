@@ -0,0 +1,205 @@
+use super::*;
+
+#[test]
+fn glob() {
+  Test::new()
+    .write("src/main.rs", "")
+    .write("src/a/b.rs", "")
+    .write("src/a/c.md", "")
+    .write("build.rs", "")
+    .justfile("x := json_encode(glob('src/**/*.rs'))")
+    .args(["--evaluate", "x"])
+    .stdout(r#"["src/a/b.rs","src/main.rs"]"#)
+    .success();
+}
+
+#[test]
+fn glob_no_matches() {
+  Test::new()
+    .justfile("x := json_encode(glob('src/**/*.rs'))")
+    .args(["--evaluate", "x"])
+    .stdout("[]")
+    .success();
+}
+
+#[test]
+fn glob_is_relative_to_working_directory() {
+  Test::new()
+    .write("foo/a.rs", "")
+    .write("b.rs", "")
+    .justfile(
+      "
+        set working-directory := 'foo'
+
+        x := json_encode(glob('*.rs'))
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout(r#"["a.rs"]"#)
+    .success();
+}
+
+#[test]
+fn glob_skips_git_directory() {
+  Test::new()
+    .create_dir(".git")
+    .write(".git/config", "")
+    .write("a", "")
+    .justfile("x := json_encode(glob('**'))")
+    .args(["--evaluate", "x"])
+    .stdout(r#"["a","justfile"]"#)
+    .success();
+}
+
+#[test]
+fn glob_gitignore() {
+  Test::new()
+    .create_dir(".git")
+    .write(".gitignore", "target/\n*.log\n")
+    .write("src/.gitignore", "generated.rs\n")
+    .write("src/main.rs", "")
+    .write("src/generated.rs", "")
+    .write("src/debug.log", "")
+    .write("target/out.rs", "")
+    .justfile(
+      "
+        foo:
+          @echo '{{ json_encode(glob('**/*.{rs,log}')) }}'
+          @echo '{{ json_encode(glob('**/*.{rs,log}', 'true')) }}'
+      ",
+    )
+    .stdout(
+      r#"
+        ["src/debug.log","src/generated.rs","src/main.rs","target/out.rs"]
+        ["src/main.rs"]
+      "#,
+    )
+    .success();
+}
+
+#[test]
+fn glob_gitignore_patterns() {
+  Test::new()
+    .create_dir(".git")
+    .write(".gitignore", "src/**/gen/\n*.[ao]\n")
+    .write("src/a/gen/x.rs", "")
+    .write("src/gen/y.rs", "")
+    .write("src/lib.rs", "")
+    .write("src/lib.a", "")
+    .write("src/lib.o", "")
+    .write("src/lib.s", "")
+    .justfile("x := json_encode(glob('src/**/[lx]*', 'true'))")
+    .args(["--evaluate", "x"])
+    .stdout(r#"["src/lib.rs","src/lib.s"]"#)
+    .success();
+}
+
+#[test]
+fn glob_gitignore_outside_repository() {
+  Test::new()
+    .write(".gitignore", "*.log\n")
+    .write("a.log", "")
+    .justfile("x := json_encode(glob('*.log', 'true'))")
+    .args(["--evaluate", "x"])
+    .stdout(r#"["a.log"]"#)
+    .success();
+}
+
+#[test]
+fn glob_gitignore_invalid() {
+  Test::new()
+    .justfile("x := glob('*', 'yes')")
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: Call to function `glob` failed: `yes` is not a boolean
+         ——▶ justfile:1:6
+          │
+        1 │ x := glob('*', 'yes')
+          │      ^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn glob_invalid_pattern() {
+  Test::new()
+    .justfile("x := glob('src/{a,b')")
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: Call to function `glob` failed: error parsing glob 'src/{a,b': unclosed alternate group; missing '}' (maybe escape '{' with '[{]'?)
+         ——▶ justfile:1:6
+          │
+        1 │ x := glob('src/{a,b')
+          │      ^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn walk() {
+  Test::new()
+    .write("src/main.rs", "")
+    .write("src/a/b.md", "")
+    .write("c.rs", "")
+    .justfile("x := json_encode(walk('src'))")
+    .args(["--evaluate", "x"])
+    .stdout(r#"["src/a/b.md","src/main.rs"]"#)
+    .success();
+}
+
+#[test]
+fn walk_gitignore() {
+  Test::new()
+    .create_dir(".git")
+    .write(".gitignore", "*.md\n")
+    .write("src/main.rs", "")
+    .write("src/a/b.md", "")
+    .justfile("x := json_encode(walk('src', 'true'))")
+    .args(["--evaluate", "x"])
+    .stdout(r#"["src/main.rs"]"#)
+    .success();
+}
+
+#[test]
+fn walk_not_a_directory() {
+  Test::new()
+    .justfile("x := walk('src')")
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: Call to function `walk` failed: `src` is not a directory
+         ——▶ justfile:1:6
+          │
+        1 │ x := walk('src')
+          │      ^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn blake3_glob() {
+  let hash = |files: &[(&str, &str)]| {
+    let mut test = Test::new();
+    for (path, contents) in files {
+      test = test.write(path, contents);
+    }
+    let output = test
+      .justfile("x := blake3_glob('src/**')")
+      .args(["--evaluate", "x"])
+      .stdout_regex("[0-9a-f]{64}")
+      .success();
+    output.stdout
+  };
+
+  let a = hash(&[("src/a", "a"), ("src/b", "b")]);
+
+  assert_eq!(a, hash(&[("src/a", "a"), ("src/b", "b"), ("c", "c")]));
+  assert_ne!(a, hash(&[("src/a", "a"), ("src/b", "c")]));
+  assert_ne!(a, hash(&[("src/a", "a"), ("src/c", "b")]));
+  assert_ne!(a, hash(&[("src/a", "a")]));
+}
@@ -406,3 +406,170 @@ x := 'y'
     .stdout("hello\n")
     .success();
 }
+
+#[test]
+fn override_replaces_imported_recipe() {
+  Test::new()
+    .write("import.just", "foo:\n @echo import")
+    .justfile(
+      "
+        import 'import.just'
+
+        [override]
+        foo:
+          @echo override
+      ",
+    )
+    .stdout("override\n")
+    .success();
+}
+
+#[test]
+fn override_replaces_imported_recipe_as_dependency() {
+  Test::new()
+    .write("import.just", "foo:\n @echo import\n\nbar: foo\n @echo bar")
+    .justfile(
+      "
+        import 'import.just'
+
+        [override]
+        foo:
+          @echo override
+      ",
+    )
+    .arg("bar")
+    .stdout("override\nbar\n")
+    .success();
+}
+
+#[test]
+fn override_super_dependency_runs_overridden_recipe() {
+  Test::new()
+    .write("import.just", "foo:\n @echo import")
+    .justfile(
+      "
+        import 'import.just'
+
+        [override]
+        foo: super::foo
+          @echo override
+      ",
+    )
+    .stdout("import\noverride\n")
+    .success();
+}
+
+#[test]
+fn override_in_import_replaces_deeper_recipe() {
+  Test::new()
+    .write(
+      "a.just",
+      "import 'b.just'\n\n[override]\nfoo: super::foo\n @echo a",
+    )
+    .write("b.just", "foo:\n @echo b")
+    .justfile("import 'a.just'")
+    .arg("foo")
+    .stdout("b\na\n")
+    .success();
+}
+
+#[test]
+fn override_without_imported_recipe() {
+  Test::new()
+    .write("import.just", "bar:\n @echo import")
+    .justfile(
+      "
+        import 'import.just'
+
+        [override]
+        foo:
+          @echo override
+      ",
+    )
+    .stderr(
+      "
+        error: Recipe `foo` has `[override]` attribute but does not override an imported recipe
+         ——▶ justfile:4:1
+          │
+        4 │ foo:
+          │ ^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn override_of_recipe_in_same_file() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo foo
+
+        [override]
+        foo:
+          @echo override
+      ",
+    )
+    .stderr(
+      "
+        error: Recipe `foo` has `[override]` attribute but does not override an imported recipe
+         ——▶ justfile:5:1
+          │
+        5 │ foo:
+          │ ^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn duplicate_overrides() {
+  Test::new()
+    .write("import.just", "foo:\n @echo import")
+    .justfile(
+      "
+        import 'import.just'
+
+        [override]
+        foo:
+          @echo a
+
+        [override]
+        foo:
+          @echo b
+      ",
+    )
+    .stderr(
+      "
+        error: Recipe `foo` first defined on line 4 is redefined on line 8
+         ——▶ justfile:8:1
+          │
+        8 │ foo:
+          │ ^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn super_dependency_without_override() {
+  Test::new()
+    .justfile(
+      "
+        foo: super::bar
+
+        bar:
+      ",
+    )
+    .stderr(
+      "
+        error: Recipe `foo` has unknown dependency `super::bar`
+         ——▶ justfile:1:13
+          │
+        1 │ foo: super::bar
+          │             ^^^
+      ",
+    )
+    .failure();
+}
@@ -14,6 +14,8 @@ struct Assignment<'a> {
   export: bool,
   name: &'a str,
   private: bool,
+  public: bool,
+  secret: bool,
   value: serde_json::Value,
 }
 
@@ -24,6 +26,14 @@ struct Dependency<'a> {
   recipe: &'a str,
 }
 
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+struct Function<'a> {
+  body: Value,
+  name: &'a str,
+  parameters: Vec<&'a str>,
+}
+
 #[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields)]
 struct Interpreter<'a> {
@@ -38,6 +48,7 @@ struct Module<'a> {
   assignments: BTreeMap<&'a str, Assignment<'a>>,
   doc: Option<&'a str>,
   first: Option<&'a str>,
+  functions: BTreeMap<&'a str, Function<'a>>,
   groups: Vec<&'a str>,
   modules: BTreeMap<&'a str, Module<'a>>,
   recipes: BTreeMap<&'a str, Recipe<'a>>,
@@ -57,7 +68,10 @@ struct Parameter<'a> {
   long: Option<&'a str>,
   name: &'a str,
   pattern: Option<&'a str>,
+  secret: bool,
   short: Option<char>,
+  #[serde(rename = "type")]
+  ty: Option<Value>,
   value: Option<&'a str>,
 }
 
@@ -85,13 +99,18 @@ struct Settings<'a> {
   dotenv_filename: Option<&'a str>,
   dotenv_load: bool,
   dotenv_override: bool,
-  dotenv_path: Option<&'a str>,
+  dotenv_path: Vec<&'a str>,
   dotenv_required: bool,
+  env_clear: bool,
+  explicit_exports: bool,
   export: bool,
   fallback: bool,
   ignore_comments: bool,
+  inherit_settings: bool,
+  lazy: bool,
   no_exit_message: bool,
   output_format: Option<&'a str>,
+  parallel_assignments: bool,
   positional_arguments: bool,
   quiet: bool,
   shell: Option<Interpreter<'a>>,
@@ -196,6 +215,29 @@ fn assignment() {
   );
 }
 
+#[test]
+fn function() {
+  case(
+    "fn slug(s, sep) := lowercase(s) + sep",
+    Module {
+      functions: [(
+        "slug",
+        Function {
+          body: json!([
+            "concatenate",
+            ["call", "lowercase", ["variable", "s"]],
+            ["variable", "sep"]
+          ]),
+          name: "slug",
+          parameters: vec!["s", "sep"],
+        },
+      )]
+      .into(),
+      ..default()
+    },
+  );
+}
+
 #[test]
 fn private_assignment() {
   case(
@@ -231,6 +273,57 @@ fn private_assignment() {
   );
 }
 
+#[test]
+fn secret_assignment() {
+  case(
+    "
+      [secret]
+      foo := 'bar'
+    ",
+    Module {
+      assignments: [(
+        "foo",
+        Assignment {
+          name: "foo",
+          value: "***".into(),
+          secret: true,
+          ..default()
+        },
+      )]
+      .into(),
+      ..default()
+    },
+  );
+}
+
+#[test]
+fn public_assignment() {
+  case(
+    "
+      set explicit-exports
+
+      pub foo := 'bar'
+    ",
+    Module {
+      assignments: [(
+        "foo",
+        Assignment {
+          name: "foo",
+          value: "bar".into(),
+          public: true,
+          ..default()
+        },
+      )]
+      .into(),
+      settings: Settings {
+        explicit_exports: true,
+        ..default()
+      },
+      ..default()
+    },
+  );
+}
+
 #[test]
 fn body() {
   case(
@@ -573,6 +666,55 @@ fn parameters() {
   );
 }
 
+#[test]
+fn parameter_types() {
+  case(
+    "
+      a x: bool y: enum('b', 'c') z: file n: int:
+    ",
+    Module {
+      first: Some("a"),
+      recipes: [(
+        "a",
+        Recipe {
+          name: "a",
+          namepath: "a",
+          parameters: [
+            Parameter {
+              kind: "singular",
+              name: "x",
+              ty: Some("bool".into()),
+              ..default()
+            },
+            Parameter {
+              kind: "singular",
+              name: "y",
+              ty: Some(serde_json::json!({"enum": ["b", "c"]})),
+              ..default()
+            },
+            Parameter {
+              kind: "singular",
+              name: "z",
+              ty: Some("file".into()),
+              ..default()
+            },
+            Parameter {
+              kind: "singular",
+              name: "n",
+              ty: Some("int".into()),
+              ..default()
+            },
+          ]
+          .into(),
+          ..default()
+        },
+      )]
+      .into(),
+      ..default()
+    },
+  );
+}
+
 #[test]
 fn priors() {
   case(
@@ -702,7 +844,7 @@ fn settings() {
       settings: Settings {
         allow_duplicate_recipes: true,
         dotenv_filename: Some("filename"),
-        dotenv_path: Some("path"),
+        dotenv_path: vec!["path"],
         dotenv_load: true,
         export: true,
         fallback: true,
@@ -1067,6 +1209,7 @@ fn arg_pattern() {
               "long": null,
               "name": "bar",
               "pattern": "BAR",
+              "secret": false,
               "short": null,
               "value": null,
             }
@@ -1105,6 +1248,7 @@ fn arg_long() {
               "long": "BAR",
               "name": "bar",
               "pattern": null,
+              "secret": false,
               "short": null,
               "value": null,
             }
@@ -1143,6 +1287,7 @@ fn arg_short() {
               "long": null,
               "name": "bar",
               "pattern": null,
+              "secret": false,
               "short": "B",
               "value": null,
             }
@@ -1181,6 +1326,7 @@ fn arg_value() {
               "long": null,
               "name": "bar",
               "pattern": null,
+              "secret": false,
               "short": "B",
               "value": "hello",
             }
@@ -1220,6 +1366,7 @@ fn arg_help() {
               "long": null,
               "name": "bar",
               "pattern": null,
+              "secret": false,
               "short": null,
               "value": null,
             }
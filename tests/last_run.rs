@@ -0,0 +1,274 @@
+use super::*;
+
+const JUSTFILE: &str = "
+  foo:
+    @echo foo
+
+  bar x:
+    @echo bar {{x}}
+    @exit 1
+
+  baz *args:
+    @echo baz {{args}}
+    @exit 2
+";
+
+/// The runs recorded in the cache directory of `tempdir`
+fn recorded(tempdir: &TempDir) -> Vec<String> {
+  fs::read_dir(tempdir.path().join(".cache/last-run"))
+    .unwrap()
+    .map(|entry| fs::read_to_string(entry.unwrap().path()).unwrap())
+    .collect()
+}
+
+#[test]
+fn run_is_recorded() {
+  let tempdir = Test::new()
+    .justfile(JUSTFILE)
+    .args(["foo", "bar", "a"])
+    .stdout("foo\nbar a\n")
+    .stderr("error: Recipe `bar` failed on line 6 with exit code 1\n")
+    .failure()
+    .tempdir;
+
+  assert_eq!(
+    recorded(&tempdir),
+    [r#"{
+  "invocations": [
+    {
+      "command_line": [
+        "foo"
+      ],
+      "ok": true,
+      "recipe": "foo"
+    },
+    {
+      "command_line": [
+        "bar",
+        "a"
+      ],
+      "ok": false,
+      "recipe": "bar"
+    }
+  ]
+}
+"#],
+  );
+
+  assert!(!tempdir.path().join(".just").exists());
+}
+
+#[test]
+fn dry_run_is_not_recorded() {
+  let tempdir = Test::new()
+    .justfile(JUSTFILE)
+    .arg("foo")
+    .stdout("foo\n")
+    .success()
+    .tempdir;
+
+  let tempdir = Test::with_tempdir(tempdir)
+    .justfile(JUSTFILE)
+    .args(["--dry-run", "baz"])
+    .stderr("echo baz \nexit 2\n")
+    .success()
+    .tempdir;
+
+  Test::with_tempdir(tempdir)
+    .justfile(JUSTFILE)
+    .arg("--last")
+    .stdout("foo\n")
+    .success();
+}
+
+#[test]
+fn failure_to_record_run_is_a_warning() {
+  let tempdir = Test::new()
+    .justfile(JUSTFILE)
+    .write("cache", "")
+    .env("JUST_CACHE_DIRECTORY", "cache")
+    .arg("foo")
+    .stdout("foo\n")
+    .stderr_regex("warning: Failed to record run: .*\n")
+    .success()
+    .tempdir;
+
+  Test::with_tempdir(tempdir)
+    .justfile(JUSTFILE)
+    .env("JUST_CACHE_DIRECTORY", "cache")
+    .args(["--quiet", "foo"])
+    .success();
+}
+
+#[test]
+fn rerun_failed() {
+  let tempdir = Test::new()
+    .justfile(JUSTFILE)
+    .output_format(Some("tap"))
+    .env("LC_ALL", "C")
+    .args(["foo", "bar", "a", "baz", "b", "c"])
+    .stdout_regex("TAP version 14\n1..3\n(?s).*")
+    .failure()
+    .tempdir;
+
+  Test::with_tempdir(tempdir)
+    .justfile(JUSTFILE)
+    .arg("--rerun-failed")
+    .stdout("bar a\n")
+    .stderr("error: Recipe `bar` failed on line 6 with exit code 1\n")
+    .failure();
+}
+
+#[test]
+fn rerun_failed_includes_recipes_which_did_not_run() {
+  let tempdir = Test::new()
+    .justfile(JUSTFILE)
+    .args(["bar", "a", "foo"])
+    .stdout("bar a\n")
+    .stderr("error: Recipe `bar` failed on line 6 with exit code 1\n")
+    .failure()
+    .tempdir;
+
+  Test::with_tempdir(tempdir)
+    .justfile(JUSTFILE.replace("@exit 1", "@exit 0"))
+    .arg("--rerun-failed")
+    .stdout("bar a\nfoo\n")
+    .success();
+}
+
+#[test]
+fn rerun_failed_records_remaining_failures() {
+  let tempdir = Test::new()
+    .justfile(JUSTFILE)
+    .output_format(Some("tap"))
+    .env("LC_ALL", "C")
+    .args(["bar", "a", "baz"])
+    .stdout_regex("TAP version 14\n1..2\n(?s).*")
+    .failure()
+    .tempdir;
+
+  let tempdir = Test::with_tempdir(tempdir)
+    .justfile(JUSTFILE.replace("@exit 1", "@exit 0"))
+    .output_format(Some("tap"))
+    .env("LC_ALL", "C")
+    .arg("--rerun-failed")
+    .stdout_regex("TAP version 14\n1..2\n(?s).*")
+    .failure()
+    .tempdir;
+
+  Test::with_tempdir(tempdir)
+    .justfile(JUSTFILE.replace("@exit 1", "@exit 0"))
+    .arg("--rerun-failed")
+    .stdout("baz\n")
+    .stderr("error: Recipe `baz` failed on line 10 with exit code 2\n")
+    .status(2);
+}
+
+#[test]
+fn rerun_failed_without_failures() {
+  let tempdir = Test::new()
+    .justfile(JUSTFILE)
+    .arg("foo")
+    .stdout("foo\n")
+    .success()
+    .tempdir;
+
+  Test::with_tempdir(tempdir)
+    .justfile(JUSTFILE)
+    .arg("--rerun-failed")
+    .stderr("No recipes failed in the last run.\n")
+    .success();
+}
+
+#[test]
+fn last() {
+  let tempdir = Test::new()
+    .justfile(JUSTFILE)
+    .args(["foo", "baz", "-x", "y"])
+    .stdout("foo\nbaz -x y\n")
+    .stderr("error: Recipe `baz` failed on line 10 with exit code 2\n")
+    .status(2)
+    .tempdir;
+
+  Test::with_tempdir(tempdir)
+    .justfile(JUSTFILE)
+    .arg("--last")
+    .stdout("foo\nbaz -x y\n")
+    .stderr("error: Recipe `baz` failed on line 10 with exit code 2\n")
+    .status(2);
+}
+
+#[test]
+fn last_default_recipe() {
+  let tempdir = Test::new()
+    .justfile(JUSTFILE)
+    .stdout("foo\n")
+    .success()
+    .tempdir;
+
+  Test::with_tempdir(tempdir)
+    .justfile(JUSTFILE)
+    .arg("--last")
+    .stdout("foo\n")
+    .success();
+}
+
+#[test]
+fn last_without_previous_run() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("--last")
+    .stderr_regex("error: No previous run recorded at `.*last-run.*\\.json`\n")
+    .failure();
+}
+
+#[test]
+fn last_with_arguments() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--last", "foo"])
+    .stderr("error: `--last` used with unexpected argument: `foo`\n")
+    .failure();
+}
+
+#[test]
+fn last_and_rerun_failed_conflict() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--last", "--rerun-failed"])
+    .stderr_regex("error: the argument '--last' cannot be used with '--rerun-failed'.*")
+    .status(2);
+}
+
+#[test]
+fn runs_are_recorded_per_justfile() {
+  let tempdir = Test::new()
+    .no_justfile()
+    .test_round_trip(false)
+    .write("a/justfile", "foo:\n @echo a")
+    .write("b/justfile", "foo:\n @echo b")
+    .current_dir("a")
+    .arg("foo")
+    .stdout("a\n")
+    .success()
+    .tempdir;
+
+  let tempdir = Test::with_tempdir(tempdir)
+    .no_justfile()
+    .test_round_trip(false)
+    .current_dir("b")
+    .arg("foo")
+    .stdout("b\n")
+    .success()
+    .tempdir;
+
+  assert_eq!(recorded(&tempdir).len(), 2);
+
+  Test::with_tempdir(tempdir)
+    .no_justfile()
+    .test_round_trip(false)
+    .current_dir("a")
+    .arg("--last")
+    .stdout("a\n")
+    .success();
+}
@@ -0,0 +1,266 @@
+use super::*;
+
+#[test]
+fn unused_variables_are_not_evaluated() {
+  Test::new()
+    .justfile(
+      "
+        set lazy
+
+        x := `echo x >&2`
+
+        foo:
+          @echo foo
+      ",
+    )
+    .stdout("foo\n")
+    .success();
+}
+
+#[test]
+fn variables_are_evaluated_without_lazy() {
+  Test::new()
+    .justfile(
+      "
+        x := `echo x >&2`
+
+        foo:
+          @echo foo
+      ",
+    )
+    .stdout("foo\n")
+    .stderr("x\n")
+    .success();
+}
+
+#[test]
+fn used_variables_are_evaluated_once() {
+  Test::new()
+    .justfile(
+      "
+        set lazy
+
+        x := `echo x >&2; echo a`
+        y := x + 'b'
+
+        foo: bar
+          @echo {{ x }} {{ y }}
+
+        bar:
+          @echo {{ y }}
+      ",
+    )
+    .stdout("ab\na ab\n")
+    .stderr("x\n")
+    .success();
+}
+
+#[test]
+fn lazy_false() {
+  Test::new()
+    .justfile(
+      "
+        set lazy := false
+
+        x := `echo x >&2`
+
+        foo:
+      ",
+    )
+    .stderr("x\n")
+    .success();
+}
+
+#[test]
+fn parameters_do_not_shadow_dependencies() {
+  Test::new()
+    .justfile(
+      "
+        set lazy
+
+        x := 'a'
+        y := x + 'b'
+
+        foo x:
+          @echo {{ x }} {{ y }}
+      ",
+    )
+    .args(["foo", "c"])
+    .stdout("c ab\n")
+    .success();
+}
+
+#[test]
+fn overrides() {
+  Test::new()
+    .justfile(
+      "
+        set lazy
+
+        x := `echo x >&2`
+        y := x + 'b'
+
+        foo:
+          @echo {{ y }}
+      ",
+    )
+    .args(["x=a", "foo"])
+    .stdout("ab\n")
+    .success();
+}
+
+#[test]
+fn exported_variables_are_evaluated() {
+  Test::new()
+    .justfile(
+      "
+        set lazy
+
+        export X := `echo x >&2; echo a`
+
+        foo:
+          @echo $X
+      ",
+    )
+    .stdout("a\n")
+    .stderr("x\n")
+    .success();
+}
+
+#[test]
+fn export_setting_evaluates_all_variables() {
+  Test::new()
+    .justfile(
+      "
+        set lazy
+        set export
+
+        X := `echo x >&2; echo a`
+
+        foo:
+          @echo $X
+      ",
+    )
+    .stdout("a\n")
+    .stderr("x\n")
+    .success();
+}
+
+#[test]
+fn dependency_arguments() {
+  Test::new()
+    .justfile(
+      "
+        set lazy
+
+        x := `echo x >&2; echo a`
+
+        foo: (bar x)
+
+        bar y:
+          @echo {{ y }}
+      ",
+    )
+    .stdout("a\n")
+    .stderr("x\n")
+    .success();
+}
+
+#[test]
+fn errors_are_reported_when_used() {
+  Test::new()
+    .justfile(
+      "
+        set lazy
+
+        x := `exit 1`
+
+        foo:
+          @echo foo
+
+        bar:
+          @echo {{ x }}
+      ",
+    )
+    .arg("bar")
+    .stderr(
+      "
+        error: Backtick failed with exit code 1
+         ——▶ justfile:3:6
+          │
+        3 │ x := `exit 1`
+          │      ^^^^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn evaluate() {
+  Test::new()
+    .justfile(
+      "
+        set lazy
+
+        x := `echo x >&2; echo a`
+        y := 'b'
+      ",
+    )
+    .arg("--evaluate")
+    .stdout(
+      "
+        x := \"a\"
+        y := \"b\"
+      ",
+    )
+    .stderr("x\n")
+    .success();
+}
+
+#[test]
+fn list() {
+  Test::new()
+    .justfile(
+      "
+        set lazy
+
+        x := `echo x >&2`
+
+        foo:
+      ",
+    )
+    .arg("--list")
+    .stdout(
+      "
+        Available recipes:
+            foo
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn modules() {
+  Test::new()
+    .write(
+      "foo.just",
+      "
+set lazy
+
+x := `echo x >&2; echo a`
+y := `echo y >&2`
+
+bar:
+  @echo {{ x }}
+",
+    )
+    .justfile(
+      "
+        mod foo
+      ",
+    )
+    .args(["foo::bar"])
+    .stdout("a\n")
+    .stderr("x\n")
+    .success();
+}
@@ -48,6 +48,7 @@ mod allow_duplicate_recipes;
 mod allow_duplicate_variables;
 mod allow_missing;
 mod arg_attribute;
+mod arithmetic;
 mod assert_stdout;
 mod assert_success;
 mod assertions;
@@ -70,6 +71,7 @@ mod dependencies;
 mod directories;
 mod dotenv;
 mod edit;
+mod env_clear;
 mod equals;
 mod error_messages;
 mod evaluate;
@@ -77,10 +79,15 @@ mod examples;
 mod explain;
 mod export;
 mod fallback;
+mod finally;
+mod for_loops;
 mod format;
 mod format_string;
 mod functions;
 #[cfg(unix)]
+mod git_imports;
+#[cfg(unix)]
+mod glob;
 mod global;
 mod groups;
 mod ignore_comments;
@@ -89,10 +96,15 @@ mod init;
 mod interpolation;
 mod invocation_directory;
 mod json;
+mod last_run;
+mod lazy;
 mod line_prefixes;
 mod list;
+mod lists;
 mod logical_operators;
 mod man;
+mod maps;
+mod match_expressions;
 mod misc;
 mod modules;
 mod multibyte_char;
@@ -119,6 +131,9 @@ mod scope;
 mod script;
 mod search;
 mod search_arguments;
+mod secrets;
+#[cfg(unix)]
+mod services;
 mod settings;
 mod shadowing_parameters;
 mod shebang;
@@ -133,17 +148,23 @@ mod subsequents;
 mod summary;
 mod tap;
 mod tempdir;
+mod templates;
 mod timestamps;
+mod typed_parameters;
 mod undefined_variables;
 mod unexport;
 mod unstable;
 mod usage;
+mod user_functions;
+#[cfg(unix)]
+mod watch;
 mod which_function;
 #[cfg(windows)]
 mod windows;
 #[cfg(target_family = "windows")]
 mod windows_shell;
 mod working_directory;
+mod workspace;
 
 fn path(s: &str) -> String {
   if cfg!(windows) {
@@ -0,0 +1,406 @@
+use super::*;
+
+#[test]
+fn list_literal_is_space_separated_when_interpolated() {
+  Test::new()
+    .justfile(
+      "
+        xs := ['a', 'b', 'c']
+
+        foo:
+          @echo {{ xs }}
+      ",
+    )
+    .stdout("a b c\n")
+    .success();
+}
+
+#[test]
+fn empty_list() {
+  Test::new()
+    .justfile(
+      "
+        xs := []
+
+        foo:
+          @echo '[{{ xs }}]' {{ len(xs) }}
+      ",
+    )
+    .stdout("[] 0\n")
+    .success();
+}
+
+#[test]
+fn trailing_comma() {
+  Test::new()
+    .justfile(
+      "
+        xs := [
+          'a',
+          'b',
+        ]
+
+        foo:
+          @echo {{ len(xs) }}
+      ",
+    )
+    .stdout("2\n")
+    .success();
+}
+
+#[test]
+fn list_concatenation() {
+  Test::new()
+    .justfile(
+      "
+        xs := ['a', 'b'] + ['c']
+
+        foo:
+          @echo {{ len(xs) }} {{ xs }}
+      ",
+    )
+    .stdout("3 a b c\n")
+    .success();
+}
+
+#[test]
+fn list_and_string_concatenation_is_a_string() {
+  Test::new()
+    .justfile(
+      "
+        xs := ['a', 'b'] + 'c'
+
+        foo:
+          @echo {{ len(xs) }}
+      ",
+    )
+    .stdout("4\n")
+    .success();
+}
+
+#[test]
+fn len_of_string_counts_characters() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{ len('héllo') }}
+      ",
+    )
+    .stdout("5\n")
+    .success();
+}
+
+#[test]
+fn variadic_parameter_is_a_list() {
+  Test::new()
+    .justfile(
+      "
+        foo *args:
+          @echo {{ len(args) }} {{ quote(args) }}
+      ",
+    )
+    .args(["foo", "a", "b c"])
+    .stdout("2 a b c\n")
+    .success();
+}
+
+#[test]
+fn empty_variadic_parameter_is_an_empty_list() {
+  Test::new()
+    .justfile(
+      "
+        foo *args:
+          @echo {{ len(args) }}
+      ",
+    )
+    .stdout("0\n")
+    .success();
+}
+
+#[test]
+fn quote_list_quotes_each_element() {
+  Test::new()
+    .justfile(
+      r#"
+        xs := ['a', 'b c', "it's"]
+
+        foo:
+          @printf '%s\n' {{ quote(xs) }}
+      "#,
+    )
+    .stdout("a\nb c\nit's\n")
+    .success();
+}
+
+#[test]
+fn quote_string_is_unchanged() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{ quote('a b') }}
+      ",
+    )
+    .stderr("")
+    .stdout("a b\n")
+    .success();
+}
+
+#[test]
+fn map() {
+  Test::new()
+    .justfile(
+      "
+        xs := map(['a', 'b'], x => uppercase(x) + '!')
+
+        foo:
+          @echo {{ xs }}
+      ",
+    )
+    .stdout("A! B!\n")
+    .success();
+}
+
+#[test]
+fn filter() {
+  Test::new()
+    .justfile(
+      "
+        xs := filter(['foo.rs', 'bar.txt', 'baz.rs'], x => x =~ '\\.rs$')
+
+        foo:
+          @echo {{ xs }}
+      ",
+    )
+    .stdout("foo.rs baz.rs\n")
+    .success();
+}
+
+#[test]
+fn map_binding_shadows_variable() {
+  Test::new()
+    .justfile(
+      "
+        x := 'outer'
+        xs := map(['a'], x => x)
+
+        foo:
+          @echo {{ xs }} {{ x }}
+      ",
+    )
+    .stdout("a outer\n")
+    .success();
+}
+
+#[test]
+fn map_binding_is_not_visible_to_assignments() {
+  Test::new()
+    .justfile(
+      "
+        x := 'global'
+        ys := map(['a'], x => y)
+        y := x
+
+        foo:
+          @echo {{ ys }} {{ y }}
+      ",
+    )
+    .stdout("global global\n")
+    .success();
+}
+
+#[test]
+fn map_binding_is_not_visible_to_lazy_assignments() {
+  Test::new()
+    .justfile(
+      "
+        set lazy
+
+        x := 'global'
+        ys := map(['a'], x => y)
+        y := x
+
+        foo:
+          @echo {{ ys }} {{ y }}
+      ",
+    )
+    .stdout("global global\n")
+    .success();
+}
+
+#[test]
+fn map_over_variadic_parameter() {
+  Test::new()
+    .justfile(
+      "
+        foo +files:
+          @echo {{ map(files, f => f + '.bak') }}
+      ",
+    )
+    .args(["foo", "a", "b"])
+    .stdout("a.bak b.bak\n")
+    .success();
+}
+
+#[test]
+fn split() {
+  Test::new()
+    .justfile(
+      "
+        xs := split('a,b,,c', ',')
+
+        foo:
+          @echo {{ len(xs) }} {{ join_list(xs, '-') }}
+      ",
+    )
+    .stdout("4 a-b--c\n")
+    .success();
+}
+
+#[test]
+fn split_empty_string() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{ len(split('', ',')) }}
+      ",
+    )
+    .stdout("0\n")
+    .success();
+}
+
+#[test]
+fn split_empty_separator() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{ split('abc', '') }}
+      ",
+    )
+    .stderr(
+      "
+        error: Call to function `split` failed: Separator may not be empty
+         ——▶ justfile:2:12
+          │
+        2 │   @echo {{ split('abc', '') }}
+          │            ^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn join_list() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{ join_list(['a', 'b', 'c'], ', ') }}
+      ",
+    )
+    .stdout("a, b, c\n")
+    .success();
+}
+
+#[test]
+fn empty_list_is_falsy() {
+  Test::new()
+    .justfile(
+      "
+        set unstable
+
+        xs := [] || ['default']
+
+        foo:
+          @echo {{ xs }}
+      ",
+    )
+    .stdout("default\n")
+    .success();
+}
+
+#[test]
+fn evaluate() {
+  Test::new()
+    .justfile(
+      "
+        xs := ['a', 'b']
+      ",
+    )
+    .arg("--evaluate")
+    .stdout("xs := \"a b\"\n")
+    .success();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(
+      "
+        xs := ['a', 'b']
+        ys := map(xs, x => uppercase(x))
+        zs := filter(xs, x => x != 'a')
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+        xs := ['a', 'b']
+        ys := map(xs, x => uppercase(x))
+        zs := filter(xs, x => x != 'a')
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn dump_json() {
+  Test::new()
+    .justfile(
+      "
+        xs := map(['a'], x => x)
+      ",
+    )
+    .args(["--dump", "--dump-format", "json"])
+    .stdout_regex(r#".*"value":\["map",\["list","a"\],"x",\["variable","x"\]\].*"#)
+    .success();
+}
+
+#[test]
+fn map_name_is_not_an_undefined_variable() {
+  Test::new()
+    .justfile(
+      "
+        xs := map(['a'], y => z)
+      ",
+    )
+    .stderr(
+      "
+        error: Variable `z` not defined
+         ——▶ justfile:1:23
+          │
+        1 │ xs := map(['a'], y => z)
+          │                       ^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn unclosed_list() {
+  Test::new()
+    .justfile("xs := ['a'")
+    .stderr(
+      "
+        error: Expected '&&', '*', '||', ']', ',', '-', '%', '+', or '/', but found end of file
+         ——▶ justfile:1:11
+          │
+        1 │ xs := ['a'
+          │           ^
+      ",
+    )
+    .failure();
+}
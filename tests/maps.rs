@@ -0,0 +1,332 @@
+use super::*;
+
+#[test]
+fn json_parse_field_access() {
+  Test::new()
+    .write("package.json", r#"{"name": "foo", "version": "1.2.3"}"#)
+    .justfile(
+      "
+        package := json_parse(read('package.json'))
+
+        foo:
+          @echo {{ package.name }} {{ package.version }}
+      ",
+    )
+    .stdout("foo 1.2.3\n")
+    .success();
+}
+
+#[test]
+fn toml_parse_field_access() {
+  Test::new()
+    .write(
+      "Cargo.toml",
+      "[package]\nname = \"bar\"\nversion = \"0.1.0\"\n\n[dependencies]\nserde = \"1\"\n",
+    )
+    .justfile(
+      "
+        manifest := toml_parse(read('Cargo.toml'))
+
+        foo:
+          @echo {{ manifest.package.version }} {{ manifest.dependencies.serde }}
+      ",
+    )
+    .stdout("0.1.0 1\n")
+    .success();
+}
+
+#[test]
+fn bracket_access() {
+  Test::new()
+    .justfile(
+      r#"
+        scripts := json_parse('{"build:release": "cargo build --release"}')
+        key := 'build:release'
+
+        x := scripts["build:release"] + ' / ' + scripts[key]
+      "#,
+    )
+    .args(["--evaluate", "x"])
+    .stdout("cargo build --release / cargo build --release")
+    .success();
+}
+
+#[test]
+fn nested_access() {
+  Test::new()
+    .justfile(
+      r#"
+        x := json_parse('{"a": {"b": [{"c": "d"}]}}').a.b[0].c
+      "#,
+    )
+    .args(["--evaluate", "x"])
+    .stdout("d")
+    .success();
+}
+
+#[test]
+fn list_index() {
+  Test::new()
+    .justfile(
+      "
+        x := ['a', 'b', 'c'][2] + split('d e', ' ')[0]
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout("cd")
+    .success();
+}
+
+#[test]
+fn json_scalars() {
+  Test::new()
+    .justfile(
+      r#"
+        value := json_parse('{"integer": 7, "float": 1.5, "bool": true, "null": null}')
+
+        foo:
+          @echo {{ value.integer }} {{ value.float }} {{ value.bool }} '{{ value.null }}'
+      "#,
+    )
+    .stdout("7 1.5 true \n")
+    .success();
+}
+
+#[test]
+fn maps_display_as_json() {
+  Test::new()
+    .justfile(
+      r#"
+        x := json_parse('{"b": [1, "2"], "a": {}}')
+      "#,
+    )
+    .args(["--evaluate", "x"])
+    .stdout(r#"{"a":{},"b":[1,"2"]}"#)
+    .success();
+}
+
+#[test]
+fn json_encode() {
+  Test::new()
+    .justfile(
+      r#"
+        foo:
+          @echo {{ quote(json_encode(['a', "b'c"])) }} {{ quote(json_encode('d')) }}
+      "#,
+    )
+    .stdout("[\"a\",\"b'c\"] \"d\"\n")
+    .success();
+}
+
+#[test]
+fn json_round_trip() {
+  Test::new()
+    .justfile(
+      r#"
+        x := json_encode(json_parse('{ "a" : [ 1, 2 ] }'))
+      "#,
+    )
+    .args(["--evaluate", "x"])
+    .stdout(r#"{"a":[1,2]}"#)
+    .success();
+}
+
+#[test]
+fn iterate_over_keys() {
+  Test::new()
+    .justfile(
+      r#"
+        x := join_list(for key in json_parse('{"b": 1, "a": 2}') { key }, ',')
+      "#,
+    )
+    .args(["--evaluate", "x"])
+    .stdout("a,b")
+    .success();
+}
+
+#[test]
+fn len() {
+  Test::new()
+    .justfile(
+      r#"
+        x := len(json_parse('{"a": 1, "b": 2}'))
+      "#,
+    )
+    .args(["--evaluate", "x"])
+    .stdout("2")
+    .success();
+}
+
+#[test]
+fn missing_key() {
+  Test::new()
+    .justfile(
+      r#"
+        package := json_parse('{"version": "1.0.0"}')
+
+        x := package.versoin
+      "#,
+    )
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: Key `versoin` not found
+         ——▶ justfile:3:14
+          │
+        3 │ x := package.versoin
+          │              ^^^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn missing_bracket_key() {
+  Test::new()
+    .justfile(
+      "
+        x := json_parse('{}')['a' + 'b']
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: Key `ab` not found
+         ——▶ justfile:1:23
+          │
+        1 │ x := json_parse('{}')['a' + 'b']
+          │                       ^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn index_out_of_range() {
+  Test::new()
+    .justfile(
+      "
+        x := ['a'][1]
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: Index 1 out of range for list of length 1
+         ——▶ justfile:1:12
+          │
+        1 │ x := ['a'][1]
+          │            ^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn non_integer_list_index() {
+  Test::new()
+    .justfile(
+      "
+        x := ['a']['b']
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: List index `b` is not an integer
+         ——▶ justfile:1:12
+          │
+        1 │ x := ['a']['b']
+          │            ^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn index_string() {
+  Test::new()
+    .justfile(
+      "
+        foo := 'bar'
+
+        x := foo.baz
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: Cannot index `bar`, which is not a list or map
+         ——▶ justfile:3:10
+          │
+        3 │ x := foo.baz
+          │          ^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn invalid_json() {
+  Test::new()
+    .justfile(
+      "
+        x := json_parse('{')
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: Call to function `json_parse` failed: Invalid JSON: EOF while parsing an object at \
+       line 1 column 1
+         ——▶ justfile:1:6
+          │
+        1 │ x := json_parse('{')
+          │      ^^^^^^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn invalid_toml() {
+  Test::new()
+    .justfile(
+      "
+        x := toml_parse('a =')
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stderr_regex("error: Call to function `toml_parse` failed: Invalid TOML: .*")
+    .failure();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(
+      r#"
+        x := json_parse('{}').a["b"][0]
+      "#,
+    )
+    .arg("--dump")
+    .stdout(
+      r#"
+        x := json_parse('{}').a["b"][0]
+      "#,
+    )
+    .success();
+}
+
+#[test]
+fn dump_json() {
+  Test::new()
+    .justfile(
+      "
+        x := json_parse('{}').a['b']
+      ",
+    )
+    .args(["--dump", "--dump-format", "json"])
+    .stdout_regex(r#".*"value":\["index",\["index",\["call","json_parse","\{\}"\],"a"\],"b"\].*"#)
+    .success();
+}
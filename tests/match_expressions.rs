@@ -0,0 +1,355 @@
+use super::*;
+
+#[test]
+fn string_arm() {
+  Test::new()
+    .justfile(
+      "
+        os := 'linux'
+
+        foo:
+          @echo {{ match os { 'macos' => 'apple', 'linux' => 'tux', _ => 'other' } }}
+      ",
+    )
+    .stdout("tux\n")
+    .success();
+}
+
+#[test]
+fn wildcard_arm() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{ match 'windows' { 'linux' => 'tux', _ => 'other' } }}
+      ",
+    )
+    .stdout("other\n")
+    .success();
+}
+
+#[test]
+fn first_matching_arm_wins() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{ match 'a' { 'a' => 'first', 'a' => 'second', _ => 'other' } }}
+      ",
+    )
+    .stdout("first\n")
+    .success();
+}
+
+#[test]
+fn string_arm_is_glob() {
+  Test::new()
+    .justfile(
+      "
+        kind := match 'src/main.rs' {
+          'src/*.rs' => 'rust',
+          '*.md' => 'docs',
+          _ => 'other',
+        }
+
+        foo:
+          @echo {{ kind }}
+      ",
+    )
+    .stdout("rust\n")
+    .success();
+}
+
+#[test]
+fn glob_star_does_not_match_slash() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{ match 'src/main.rs' { '*.rs' => 'rust', _ => 'other' } }}
+      ",
+    )
+    .stdout("other\n")
+    .success();
+}
+
+#[test]
+fn regex_arm() {
+  Test::new()
+    .justfile(
+      "
+        branch := 'feat-login'
+
+        foo:
+          @echo {{ match branch { 'main' => 'release', /^feat-/ => 'feature', _ => 'other' } }}
+      ",
+    )
+    .stdout("feature\n")
+    .success();
+}
+
+#[test]
+fn regex_arm_is_not_anchored() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{ match 'xfeat-' { /feat-/ => 'feature', _ => 'other' } }}
+      ",
+    )
+    .stdout("feature\n")
+    .success();
+}
+
+#[test]
+fn regex_capture_groups_are_bound() {
+  Test::new()
+    .justfile(
+      "
+        version := 'v1.2.3'
+
+        major := match version {
+          /^v(?<major>\\d+)\\.(?<minor>\\d+)/ => major + '-' + minor,
+          _ => 'unknown',
+        }
+
+        foo:
+          @echo {{ major }}
+      ",
+    )
+    .stdout("1-2\n")
+    .success();
+}
+
+#[test]
+fn unmatched_optional_capture_group_is_empty() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo '[{{ match 'a' { /^a(?<rest>b)?$/ => rest, _ => 'other' } }}]'
+      ",
+    )
+    .stdout("[]\n")
+    .success();
+}
+
+#[test]
+fn capture_group_shadows_variable() {
+  Test::new()
+    .justfile(
+      "
+        name := 'outer'
+
+        foo:
+          @echo {{ match 'x-inner' { /^x-(?<name>.*)$/ => name, _ => '' } }} {{ name }}
+      ",
+    )
+    .stdout("inner outer\n")
+    .success();
+}
+
+#[test]
+fn escaped_slash_in_regex() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{ match 'a/b' { /^a\\/b$/ => 'slash', _ => 'other' } }}
+      ",
+    )
+    .stdout("slash\n")
+    .success();
+}
+
+#[test]
+fn capture_group_is_undefined_in_other_arms() {
+  Test::new()
+    .justfile(
+      "
+        x := match 'a' { /(?<y>a)/ => y, _ => y }
+      ",
+    )
+    .stderr(
+      "
+        error: Variable `y` not defined
+         ——▶ justfile:1:39
+          │
+        1 │ x := match 'a' { /(?<y>a)/ => y, _ => y }
+          │                                       ^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn match_without_wildcard() {
+  Test::new()
+    .justfile(
+      "
+        x := match 'a' { 'a' => 'b' }
+      ",
+    )
+    .stderr(
+      "
+        error: Match expression must have a `_` arm
+         ——▶ justfile:1:6
+          │
+        1 │ x := match 'a' { 'a' => 'b' }
+          │      ^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn arm_after_wildcard_is_unreachable() {
+  Test::new()
+    .justfile(
+      "
+        x := match 'a' { _ => 'b', 'a' => 'c' }
+      ",
+    )
+    .stderr(
+      "
+        error: Match arm is unreachable because it follows `_`
+         ——▶ justfile:1:28
+          │
+        1 │ x := match 'a' { _ => 'b', 'a' => 'c' }
+          │                            ^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn invalid_regex() {
+  Test::new()
+    .justfile(
+      "
+        x := match 'a' { /(/ => 'b', _ => 'c' }
+      ",
+    )
+    .stderr(
+      "
+        error: Failed to parse match arm regular expression
+         ——▶ justfile:1:18
+          │
+        1 │ x := match 'a' { /(/ => 'b', _ => 'c' }
+          │                  ^^^
+        caused by: regex parse error:
+            (
+            ^
+        error: unclosed group
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn invalid_glob() {
+  Test::new()
+    .justfile(
+      "
+        x := match 'a' { '[a' => 'b', _ => 'c' }
+      ",
+    )
+    .stderr(
+      "
+        error: Failed to parse glob pattern
+         ——▶ justfile:1:18
+          │
+        1 │ x := match 'a' { '[a' => 'b', _ => 'c' }
+          │                  ^^^^
+        caused by: error parsing glob '[a': unclosed character class; missing ']'
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn invalid_pattern() {
+  Test::new()
+    .justfile(
+      "
+        x := match 'a' { b => 'b', _ => 'c' }
+      ",
+    )
+    .stderr(
+      "
+        error: Expected keyword `_` but found identifier `b`
+         ——▶ justfile:1:18
+          │
+        1 │ x := match 'a' { b => 'b', _ => 'c' }
+          │                  ^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn variable_named_match() {
+  Test::new()
+    .justfile(
+      "
+        match := 'bar'
+        foo := match + '!'
+
+        baz:
+          @echo {{ foo }} {{ match }}
+      ",
+    )
+    .stdout("bar! bar\n")
+    .success();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(
+      "
+        kind := match branch {
+          'main' => 'release',
+          /^feat-(?<topic>.*)$/ => 'feature ' + topic,
+          _ => 'other',
+        }
+        branch := 'main'
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+        kind := match branch { 'main' => 'release', /^feat-(?<topic>.*)$/ => 'feature ' + topic, _ => 'other' }
+        branch := 'main'
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn dump_json() {
+  Test::new()
+    .justfile(
+      "
+        x := match 'a' { 'a*' => 'b', /c/ => 'd', _ => 'e' }
+      ",
+    )
+    .args(["--dump", "--dump-format", "json"])
+    .stdout_regex(
+      r#".*"value":\["match","a",\[\["glob","a\*"\],"b"\],\[\["regex","c"\],"d"\],\["_","e"\]\].*"#,
+    )
+    .success();
+}
+
+#[test]
+fn evaluate() {
+  Test::new()
+    .justfile(
+      "
+        x := match 'linux' { 'linux' => 'tux', _ => 'other' }
+      ",
+    )
+    .arg("--evaluate")
+    .stdout("x := \"tux\"\n")
+    .success();
+}
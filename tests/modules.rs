@@ -1110,3 +1110,686 @@ fn exported_variables_can_be_overridden_in_submodules() {
     .stdout("b\n")
     .success();
 }
+
+#[test]
+fn module_settings_only_apply_to_module_recipes() {
+  Test::new()
+    .write(
+      "foo.just",
+      "set export\nset shell := ['bash', '-c']\nx := 'foo'\nbar:\n @echo $x ${BASH_VERSINFO:-sh}",
+    )
+    .justfile(
+      "
+        mod foo
+
+        x := 'root'
+
+        bar:
+          @echo ${x:-unset}
+      ",
+    )
+    .args(["foo::bar", "bar"])
+    .stdout("foo 5\nunset\n")
+    .success();
+}
+
+#[test]
+fn parent_settings_do_not_apply_to_modules() {
+  Test::new()
+    .write("foo.just", "x := 'foo'\nbar:\n @echo ${x:-unset}")
+    .justfile(
+      "
+        set export
+
+        mod foo
+      ",
+    )
+    .args(["foo::bar"])
+    .stdout("unset\n")
+    .success();
+}
+
+#[test]
+fn module_dotenv_only_applies_to_module_recipes() {
+  Test::new()
+    .write("foo/mod.just", "set dotenv-load\nbar:\n @echo $DOTENV_KEY")
+    .write("foo/.env", "DOTENV_KEY=foo")
+    .justfile(
+      "
+        mod foo
+
+        bar:
+          @echo ${DOTENV_KEY:-unset}
+      ",
+    )
+    .args(["foo::bar", "bar"])
+    .stdout("foo\nunset\n")
+    .success();
+}
+
+#[test]
+fn inherit_settings() {
+  Test::new()
+    .write(
+      "foo.just",
+      "set inherit-settings\nx := 'foo'\nbar:\n @echo $x ${BASH_VERSINFO:-sh}",
+    )
+    .justfile(
+      "
+        set export
+        set shell := ['bash', '-c']
+
+        mod foo
+      ",
+    )
+    .args(["foo::bar"])
+    .stdout("foo 5\n")
+    .success();
+}
+
+#[test]
+fn inherit_settings_module_settings_take_precedence() {
+  Test::new()
+    .write(
+      "foo.just",
+      "set inherit-settings\nset export := false\nx := 'foo'\nbar:\n @echo ${x:-unset} ${BASH_VERSINFO:-sh}",
+    )
+    .justfile(
+      "
+        set export
+        set shell := ['bash', '-c']
+
+        mod foo
+      ",
+    )
+    .args(["foo::bar"])
+    .stdout("unset 5\n")
+    .success();
+}
+
+#[test]
+fn inherit_settings_only_inherits_recipe_settings() {
+  Test::new()
+    .create_dir("bar")
+    .write(
+      "foo/mod.just",
+      "set inherit-settings\nx := 'foo'\nbaz:\n @echo $x $(basename $PWD)",
+    )
+    .justfile(
+      "
+        set export
+        set working-directory := 'bar'
+
+        mod foo
+      ",
+    )
+    .args(["foo::baz"])
+    .stdout("foo foo\n")
+    .success();
+}
+
+#[test]
+fn inherit_settings_is_transitive() {
+  Test::new()
+    .write("foo/mod.just", "set inherit-settings\nmod bar")
+    .write(
+      "foo/bar.just",
+      "set inherit-settings\nx := 'bar'\nbaz:\n @echo ${x:-unset}",
+    )
+    .justfile(
+      "
+        set export
+
+        mod foo
+      ",
+    )
+    .args(["foo::bar::baz"])
+    .stdout("bar\n")
+    .success();
+}
+
+#[test]
+fn inherit_settings_requires_parent_to_inherit() {
+  Test::new()
+    .write("foo/mod.just", "mod bar")
+    .write(
+      "foo/bar.just",
+      "set inherit-settings\nx := 'bar'\nbaz:\n @echo ${x:-unset}",
+    )
+    .justfile(
+      "
+        set export
+
+        mod foo
+      ",
+    )
+    .args(["foo::bar::baz"])
+    .stdout("unset\n")
+    .success();
+}
+
+#[test]
+fn module_arguments_override_assignments() {
+  Test::new()
+    .write(
+      "web.just",
+      "port := '8080'\ntarget := 'dev'\nurl := 'localhost:' + port\nserve:\n @echo {{ url }} {{ target }}",
+    )
+    .justfile(
+      "
+        mod frontend 'web.just' (port := '3000')
+      ",
+    )
+    .args(["frontend::serve"])
+    .stdout("localhost:3000 dev\n")
+    .success();
+}
+
+#[test]
+fn module_arguments_instantiate_file_more_than_once() {
+  Test::new()
+    .write("web.just", "port := '8080'\nserve:\n @echo {{ port }}")
+    .justfile(
+      "
+        mod frontend 'web.just' (port := '3000')
+        mod backend 'web.just' (port := '4000')
+      ",
+    )
+    .args(["frontend::serve", "backend::serve"])
+    .stdout("3000\n4000\n")
+    .success();
+}
+
+#[test]
+fn module_arguments_without_path() {
+  Test::new()
+    .write("foo.just", "x := 'a'\nbar:\n @echo {{ x }}")
+    .justfile(
+      "
+        mod foo (x := 'b',)
+      ",
+    )
+    .args(["foo::bar"])
+    .stdout("b\n")
+    .success();
+}
+
+#[test]
+fn module_arguments_apply_to_settings() {
+  Test::new()
+    .write(
+      "foo.just",
+      "dir := 'a'\nset working-directory := dir\nbar:\n @pwd",
+    )
+    .create_dir("a")
+    .create_dir("b")
+    .justfile(
+      "
+        mod foo (dir := 'b')
+      ",
+    )
+    .args(["foo::bar"])
+    .stdout_regex(".*/b\n")
+    .success();
+}
+
+#[test]
+fn module_arguments_must_name_module_variables() {
+  Test::new()
+    .write("foo.just", "x := 'a'")
+    .justfile(
+      "
+        mod foo (y := 'b')
+      ",
+    )
+    .stderr(
+      "
+        error: Module `foo` has no variable `y` to set with an argument
+         ——▶ justfile:1:10
+          │
+        1 │ mod foo (y := 'b')
+          │          ^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn explicit_exports_allow_exported_dependencies() {
+  Test::new()
+    .write(
+      "foo.just",
+      "set explicit-exports\n[export]\nbar: baz\n @echo bar\nbaz:\n @echo baz",
+    )
+    .justfile(
+      "
+        mod foo
+
+        qux: foo::bar
+      ",
+    )
+    .stdout("baz\nbar\n")
+    .success();
+}
+
+#[test]
+fn explicit_exports_forbid_private_dependencies() {
+  Test::new()
+    .write("foo.just", "set explicit-exports\nbar:\n @echo bar")
+    .justfile(
+      "
+        mod foo
+
+        qux: foo::bar
+      ",
+    )
+    .stderr(
+      "
+        error: Recipe `bar` is private to module `foo`
+         ——▶ justfile:3:11
+          │
+        3 │ qux: foo::bar
+          │           ^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn explicit_exports_forbid_private_alias_targets() {
+  Test::new()
+    .write("foo.just", "set explicit-exports\nbar:\n @echo bar")
+    .justfile(
+      "
+        mod foo
+
+        alias qux := foo::bar
+      ",
+    )
+    .stderr(
+      "
+        error: Recipe `bar` is private to module `foo`
+         ——▶ justfile:3:19
+          │
+        3 │ alias qux := foo::bar
+          │                   ^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn explicit_exports_use_module_path_in_errors() {
+  Test::new()
+    .write("foo.just", "mod bar")
+    .write("bar.just", "set explicit-exports\nbaz:\n @echo baz")
+    .justfile(
+      "
+        mod foo
+
+        qux: foo::bar::baz
+      ",
+    )
+    .stderr(
+      "
+        error: Recipe `baz` is private to module `foo::bar`
+         ——▶ justfile:3:16
+          │
+        3 │ qux: foo::bar::baz
+          │                ^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn explicit_exports_forbid_private_submodules() {
+  Test::new()
+    .write("foo.just", "set explicit-exports\nmod bar")
+    .write("bar.just", "baz:\n @echo baz")
+    .justfile(
+      "
+        mod foo
+
+        qux: foo::bar::baz
+      ",
+    )
+    .stderr(
+      "
+        error: Module `bar` is private to module `foo`
+         ——▶ justfile:3:11
+          │
+        3 │ qux: foo::bar::baz
+          │           ^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn explicit_exports_forbid_variables_in_private_submodules() {
+  Test::new()
+    .write("foo.just", "set explicit-exports\nmod bar")
+    .write("bar.just", "baz := 'a'")
+    .justfile(
+      "
+        mod foo
+
+        x := foo::bar::baz
+      ",
+    )
+    .stderr(
+      "
+        error: Module `bar` is private to module `foo`
+         ——▶ justfile:3:11
+          │
+        3 │ x := foo::bar::baz
+          │           ^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn explicit_exports_allow_exported_submodules() {
+  Test::new()
+    .write("foo.just", "set explicit-exports\n[export]\nmod bar")
+    .write("bar.just", "baz:\n @echo baz")
+    .justfile(
+      "
+        mod foo
+
+        qux: foo::bar::baz
+      ",
+    )
+    .arg("qux")
+    .stdout("baz\n")
+    .success();
+}
+
+#[test]
+fn explicit_exports_hide_private_submodules_from_list() {
+  Test::new()
+    .write(
+      "foo.just",
+      "set explicit-exports\n[export]\nmod bar\nmod baz",
+    )
+    .write("bar.just", "")
+    .write("baz.just", "")
+    .justfile("mod foo")
+    .args(["--list", "foo"])
+    .stdout(
+      "
+        Available recipes:
+            bar ...
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn explicit_exports_hide_private_recipes_from_list() {
+  Test::new()
+    .write(
+      "foo.just",
+      "set explicit-exports\n[export]\nbar:\n @echo bar\nbaz:\n @echo baz",
+    )
+    .justfile("mod foo")
+    .args(["--list", "foo"])
+    .stdout(
+      "
+        Available recipes:
+            bar
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn explicit_exports_allow_running_private_recipes() {
+  Test::new()
+    .write("foo.just", "set explicit-exports\nbar:\n @echo bar")
+    .justfile("mod foo")
+    .arg("foo::bar")
+    .stdout("bar\n")
+    .success();
+}
+
+#[test]
+fn explicit_exports_do_not_hide_root_recipes() {
+  Test::new()
+    .justfile(
+      "
+        set explicit-exports
+
+        foo:
+      ",
+    )
+    .arg("--list")
+    .stdout(
+      "
+        Available recipes:
+            foo
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn public_variables_are_formatted() {
+  Test::new()
+    .justfile(
+      "
+        pub export foo := 'bar'
+        pub baz := 'qux'
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+        pub export foo := 'bar'
+        pub baz := 'qux'
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn module_variables_in_assignments() {
+  Test::new()
+    .write("foo.just", "bar := 'baz'")
+    .justfile(
+      "
+        mod foo
+
+        x := 'v' + foo::bar
+
+        qux:
+          @echo {{ x }}
+      ",
+    )
+    .stdout("vbaz\n")
+    .success();
+}
+
+#[test]
+fn module_variables_in_recipes() {
+  Test::new()
+    .write("foo.just", "mod bar")
+    .write("bar.just", "baz := 'a'\nqux := baz + 'b'")
+    .justfile(
+      "
+        mod foo
+
+        qux:
+          @echo {{ foo::bar::qux }}
+      ",
+    )
+    .stdout("ab\n")
+    .success();
+}
+
+#[test]
+fn module_variables_use_module_arguments() {
+  Test::new()
+    .write("web.just", "port := '8080'\nurl := 'localhost:' + port")
+    .justfile(
+      "
+        mod frontend 'web.just' (port := '3000')
+
+        qux:
+          @echo {{ frontend::url }}
+      ",
+    )
+    .stdout("localhost:3000\n")
+    .success();
+}
+
+#[test]
+fn module_variables_are_evaluated_once() {
+  Test::new()
+    .write("foo.just", "bar := `echo x >> log && echo baz`\nqux := bar")
+    .justfile(
+      "
+        mod foo
+
+        x := foo::bar
+        y := foo::qux
+
+        qux:
+          @echo {{ x }} {{ y }} {{ foo::bar }}
+      ",
+    )
+    .stdout("baz baz baz\n")
+    .expect_file("log", "x\n")
+    .success();
+}
+
+#[test]
+fn module_variables_are_shared_with_lazy_modules() {
+  Test::new()
+    .write(
+      "foo.just",
+      "set lazy\nbar := `echo x >> log && echo baz`\nqux:\n @echo {{ bar }}",
+    )
+    .justfile(
+      "
+        mod foo
+
+        x := foo::bar
+
+        qux: foo::qux
+          @echo {{ x }}
+      ",
+    )
+    .stdout("baz\nbaz\n")
+    .expect_file("log", "x\n")
+    .success();
+}
+
+#[test]
+fn module_variables_use_module_dotenv() {
+  Test::new()
+    .write("foo/mod.just", "set dotenv-load\nbar := env('DOTENV_KEY')")
+    .write("foo/.env", "DOTENV_KEY=foo")
+    .justfile(
+      "
+        mod foo
+
+        qux:
+          @echo {{ foo::bar }}
+      ",
+    )
+    .stdout("foo\n")
+    .success();
+}
+
+#[test]
+fn undefined_module_variable() {
+  Test::new()
+    .write("foo.just", "bar := 'baz'")
+    .justfile(
+      "
+        mod foo
+
+        x := foo::qux
+      ",
+    )
+    .stderr(
+      "
+        error: Variable `foo::qux` not defined
+         ——▶ justfile:3:11
+          │
+        3 │ x := foo::qux
+          │           ^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn module_variable_in_undefined_module() {
+  Test::new()
+    .justfile(
+      "
+        qux:
+          @echo {{ foo::bar }}
+      ",
+    )
+    .stderr(
+      "
+        error: Variable `foo::bar` not defined
+         ——▶ justfile:2:17
+          │
+        2 │   @echo {{ foo::bar }}
+          │                 ^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn explicit_exports_forbid_private_module_variables() {
+  Test::new()
+    .write(
+      "foo.just",
+      "set explicit-exports\npub bar := 'a'\nbaz := 'b'",
+    )
+    .justfile(
+      "
+        mod foo
+
+        x := foo::bar + foo::baz
+      ",
+    )
+    .stderr(
+      "
+        error: Variable `baz` is private to module `foo`
+         ——▶ justfile:3:22
+          │
+        3 │ x := foo::bar + foo::baz
+          │                      ^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn evaluate_module_variable() {
+  Test::new()
+    .write("foo.just", "bar := 'baz'")
+    .justfile("mod foo")
+    .args(["--evaluate", "foo::bar"])
+    .stdout("baz")
+    .success();
+}
+
+#[test]
+fn evaluate_unknown_module_variable() {
+  Test::new()
+    .write("foo.just", "bar := 'baz'")
+    .justfile("mod foo")
+    .args(["--evaluate", "foo::HEX"])
+    .stderr("error: Justfile does not contain variable `foo::HEX`.\n")
+    .failure();
+}
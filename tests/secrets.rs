@@ -0,0 +1,311 @@
+use super::*;
+
+#[test]
+fn secret_assignment_is_masked_in_echoed_commands() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        token := 'hunter2'
+
+        foo:
+          echo {{ token }}
+      ",
+    )
+    .stdout("hunter2\n")
+    .stderr("echo ***\n")
+    .success();
+}
+
+#[test]
+fn secret_assignment_is_masked_in_dry_run() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        token := 'hunter2'
+
+        foo:
+          @echo {{ token }}
+      ",
+    )
+    .arg("--dry-run")
+    .stderr("echo ***\n")
+    .success();
+}
+
+#[test]
+fn secret_assignment_is_masked_in_evaluate() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        token := 'hunter2'
+        user := 'bob'
+      ",
+    )
+    .arg("--evaluate")
+    .stdout(
+      "
+        token := \"***\"
+        user  := \"bob\"
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn secret_assignment_is_masked_in_evaluate_variable() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        token := 'hunter2'
+      ",
+    )
+    .args(["--evaluate", "token"])
+    .stdout("***")
+    .success();
+}
+
+#[test]
+fn secret_assignment_from_backtick() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        token := `echo hunter2`
+
+        foo:
+          echo {{ token }}
+      ",
+    )
+    .stdout("hunter2\n")
+    .stderr("echo ***\n")
+    .success();
+}
+
+#[test]
+fn secret_assignment_override() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        token := 'hunter2'
+
+        foo:
+          echo {{ token }}
+      ",
+    )
+    .args(["token=swordfish", "foo"])
+    .stdout("swordfish\n")
+    .stderr("echo ***\n")
+    .success();
+}
+
+#[test]
+fn secret_parameter_is_masked() {
+  Test::new()
+    .justfile(
+      "
+        [arg('token', secret)]
+        foo token:
+          echo {{ token }}
+      ",
+    )
+    .args(["foo", "hunter2"])
+    .stdout("hunter2\n")
+    .stderr("echo ***\n")
+    .success();
+}
+
+#[test]
+fn secret_variadic_parameter_masks_each_value() {
+  Test::new()
+    .justfile(
+      "
+        [arg('tokens', secret)]
+        foo +tokens:
+          echo {{ tokens }}
+      ",
+    )
+    .args(["foo", "hunter2", "swordfish"])
+    .stdout("hunter2 swordfish\n")
+    .stderr("echo *** ***\n")
+    .success();
+}
+
+#[test]
+fn secret_function_is_masked() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          echo {{ secret('hunter2') }}
+      ",
+    )
+    .stdout("hunter2\n")
+    .stderr("echo ***\n")
+    .success();
+}
+
+#[test]
+fn secret_is_masked_in_script_dry_run() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        token := 'hunter2'
+
+        foo:
+          #!/bin/sh
+          echo {{ token }}
+      ",
+    )
+    .arg("--dry-run")
+    .stderr(
+      "
+        #!/bin/sh
+        echo ***
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn secret_is_masked_in_error_messages() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        token := 'hunter2'
+
+        foo:
+          echo {{ error(token) }}
+      ",
+    )
+    .stderr(
+      "
+        error: Call to function `error` failed: ***
+         ——▶ justfile:5:11
+          │
+        5 │   echo {{ error(token) }}
+          │           ^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn secret_is_masked_in_tap_output() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        token := 'hunter2'
+
+        foo:
+          echo {{ token }}
+      ",
+    )
+    .env("LC_ALL", "C")
+    .output_format(Some("tap"))
+    .arg("foo")
+    .stdout_regex("TAP version 14\n1..1\nok 1 - foo\n  ---\n  output: \"\\*\\*\\*\"\n  \\.\\.\\.\n")
+    .stderr("")
+    .success();
+}
+
+#[test]
+fn secret_is_masked_in_tap_stderr_output() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        token := 'hunter2'
+
+        foo:
+          @echo {{ token }}
+      ",
+    )
+    .env("LC_ALL", "C")
+    .output_format(Some("tap+stderr"))
+    .arg("foo")
+    .stdout_regex("TAP version 14\n1..1\nok 1 - foo\n  ---\n  output: \"\\*\\*\\*\"\n  \\.\\.\\.\n")
+    .stderr("***\n")
+    .success();
+}
+
+#[test]
+fn short_secret_is_not_masked() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        token := 'ab'
+
+        foo:
+          echo {{ token }}
+      ",
+    )
+    .arg("foo")
+    .stdout("ab\n")
+    .stderr("echo ab\n")
+    .success();
+}
+
+#[test]
+fn secret_is_masked_in_json_dump_parameter_default() {
+  Test::new()
+    .justfile(
+      "
+        [arg('token', secret)]
+        foo token='hunter2':
+      ",
+    )
+    .args(["--dump", "--dump-format", "json"])
+    .stdout_regex(r#".*"default":"\*\*\*".*"#)
+    .success();
+}
+
+#[test]
+fn secret_attribute_on_recipe_is_error() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        foo:
+      ",
+    )
+    .stderr(
+      "
+        error: Recipe `foo` has invalid attribute `secret`
+         ——▶ justfile:2:1
+          │
+        2 │ foo:
+          │ ^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn secret_arg_key_does_not_take_value() {
+  Test::new()
+    .justfile(
+      "
+        [arg('token', secret='yes')]
+        foo token:
+      ",
+    )
+    .stderr(
+      "
+        error: Attribute key `secret` does not take a value
+         ——▶ justfile:1:15
+          │
+        1 │ [arg('token', secret='yes')]
+          │               ^^^^^^
+      ",
+    )
+    .failure();
+}
@@ -0,0 +1,319 @@
+use super::*;
+
+#[test]
+fn service_is_stopped_after_dependents_finish() {
+  Test::new()
+    .justfile(
+      "
+        [service(file='ready')]
+        server:
+          @touch ready && sleep 60
+
+        test: server
+          @test -f ready
+          @echo tested
+      ",
+    )
+    .arg("test")
+    .stdout("tested\n")
+    .success();
+}
+
+#[test]
+fn service_command_probe() {
+  Test::new()
+    .justfile(
+      "
+        [service(command='test -f ready')]
+        server:
+          @sleep 0.5 && touch ready && sleep 60
+
+        test: server
+          @test -f ready
+          @echo tested
+      ",
+    )
+    .arg("test")
+    .stdout("tested\n")
+    .success();
+}
+
+#[test]
+fn service_port_probe() {
+  let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+  let port = listener.local_addr().unwrap().port();
+
+  Test::new()
+    .justfile(format!(
+      "
+        [service(port='127.0.0.1:{port}')]
+        server:
+          @sleep 60
+
+        test: server
+          @echo tested
+      ",
+    ))
+    .arg("test")
+    .stdout("tested\n")
+    .success();
+}
+
+#[test]
+fn service_without_probes_is_ready_immediately() {
+  Test::new()
+    .justfile(
+      "
+        [service]
+        server:
+          @sleep 60
+
+        test: server
+          @echo tested
+      ",
+    )
+    .arg("test")
+    .stdout("tested\n")
+    .success();
+}
+
+#[test]
+fn service_is_started_once() {
+  Test::new()
+    .justfile(
+      "
+        [service(file='ready')]
+        server:
+          @echo started >> log && touch ready && sleep 60
+
+        a: server
+        b: server
+
+        test: a b
+          @cat log
+      ",
+    )
+    .arg("test")
+    .stdout("started\n")
+    .success();
+}
+
+#[test]
+fn service_timeout() {
+  Test::new()
+    .justfile(
+      "
+        [service(command='false', timeout='1')]
+        server:
+          @sleep 60
+
+        test: server
+          @echo unreachable
+      ",
+    )
+    .arg("test")
+    .stderr("error: Service `server` was not ready after 1 second\n")
+    .failure();
+}
+
+#[test]
+fn service_exits_before_ready() {
+  Test::new()
+    .justfile(
+      "
+        [service(file='ready')]
+        server:
+          @true
+
+        test: server
+          @echo unreachable
+      ",
+    )
+    .arg("test")
+    .stderr("error: Service `server` exited before becoming ready\n")
+    .failure();
+}
+
+#[test]
+fn service_fails_before_ready() {
+  Test::new()
+    .justfile(
+      "
+        [service(file='ready')]
+        server:
+          @exit 3
+
+        test: server
+          @echo unreachable
+      ",
+    )
+    .arg("test")
+    .stderr("error: Recipe `server` failed on line 3 with exit code 3\n")
+    .status(3);
+}
+
+#[test]
+fn service_dry_run() {
+  Test::new()
+    .justfile(
+      "
+        [service(file='ready')]
+        server:
+          sleep 60
+
+        test: server
+          echo tested
+      ",
+    )
+    .args(["--dry-run", "test"])
+    .stderr(
+      "
+        sleep 60
+        echo tested
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn service_tap_output() {
+  Test::new()
+    .justfile(
+      "
+        [service(file='ready')]
+        server:
+          @touch ready && sleep 60
+
+        test: server
+          @echo tested
+      ",
+    )
+    .env("LC_ALL", "C")
+    .output_format(Some("tap"))
+    .arg("test")
+    .stdout(
+      "
+        TAP version 14
+        1..2
+        ok 1 - test
+          ---
+          output: \"tested\"
+          ...
+        ok 2 - server
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn service_failure_after_ready_tap_output() {
+  Test::new()
+    .justfile(
+      "
+        [service(file='ready')]
+        server:
+          @touch ready && sleep 0.2 && exit 3
+
+        test: server
+          @sleep 1
+          @echo tested
+      ",
+    )
+    .env("LC_ALL", "C")
+    .output_format(Some("tap"))
+    .arg("test")
+    .stdout(
+      "
+        TAP version 14
+        1..2
+        not ok 1 - server
+          ---
+          message: \"error: Recipe `server` failed on line 3 with exit code 3\"
+          severity: fail
+          exitcode: 3
+          ...
+        ok 2 - test
+          ---
+          output: \"tested\"
+          ...
+      ",
+    )
+    .stderr("error: Recipe `server` failed on line 3 with exit code 3\n")
+    .status(3);
+}
+
+#[test]
+fn service_attribute_dump() {
+  Test::new()
+    .justfile(
+      "
+        [service(command='true', port='5432', timeout='10')]
+        server:
+          sleep 60
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+        [service(command='true', port='5432', timeout='10')]
+        server:
+            sleep 60
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn service_port_invalid() {
+  Test::new()
+    .justfile(
+      "
+        [service(port='localhost:http')]
+        server:
+      ",
+    )
+    .stderr(
+      "
+        error: Service port `localhost:http` is not a port number or `HOST:PORT` address
+         ——▶ justfile:1:15
+          │
+        1 │ [service(port='localhost:http')]
+          │               ^^^^^^^^^^^^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn service_timeout_invalid() {
+  Test::new()
+    .justfile(
+      "
+        [service(timeout='1.5')]
+        server:
+      ",
+    )
+    .stderr(
+      "
+        error: Service timeout `1.5` is not a whole number of seconds
+         ——▶ justfile:1:18
+          │
+        1 │ [service(timeout='1.5')]
+          │                  ^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn service_attribute_takes_no_positional_arguments() {
+  Test::new()
+    .justfile(
+      "
+        [service('ready')]
+        server:
+      ",
+    )
+    .stderr_regex("error: Attribute `service` got 1 argument but takes 0 arguments\n.*")
+    .failure();
+}
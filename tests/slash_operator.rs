@@ -41,13 +41,22 @@ fn no_lhs_twice() {
     .success();
 }
 
+#[test]
+fn no_lhs_rhs() {
+  Test::new()
+    .justfile("x := 'a' / /'b'")
+    .args(["--evaluate", "x"])
+    .stdout("a//b")
+    .success();
+}
+
 #[test]
 fn no_rhs_once() {
   Test::new()
     .justfile("x := 'a' /")
     .stderr(
       "
-      error: Expected backtick, identifier, '(', '/', or string, but found end of file
+      error: Expected backtick, '[', identifier, integer, '(', '/', or string, but found end of file
        ——▶ justfile:1:11
         │
       1 │ x := 'a' /
@@ -89,7 +98,7 @@ fn no_lhs_un_parenthesized() {
     )
     .stderr(
       "
-      error: Expected backtick, identifier, '(', or string, but found '/'
+      error: Expected backtick, '[', identifier, integer, '(', or string, but found '/'
        ——▶ justfile:1:7
         │
       1 │ foo x=/ 'a' / 'b':
@@ -0,0 +1,306 @@
+use super::*;
+
+#[test]
+fn render() {
+  Test::new()
+    .justfile(
+      "
+        name := 'foo'
+
+        x := render('a {{ uppercase(name) }} b')
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout("a FOO b")
+    .success();
+}
+
+#[test]
+fn render_escape() {
+  Test::new()
+    .justfile(
+      "
+        x := render('{{{{ a }}')
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout("{{ a }}")
+    .success();
+}
+
+#[test]
+fn template() {
+  Test::new()
+    .write("in.conf.tmpl", "name = {{ name }}\nport = {{ port }}\n")
+    .justfile(
+      "
+        name := 'web'
+
+        foo port:
+          @echo -n '{{ template('in.conf.tmpl') }}'
+      ",
+    )
+    .args(["foo", "8080"])
+    .stdout("name = web\nport = 8080\n")
+    .success();
+}
+
+#[test]
+fn template_with_lazy_variable() {
+  Test::new()
+    .write("in.tmpl", "{{ version }}")
+    .justfile(
+      "
+        set lazy
+
+        version := `echo 1.0`
+
+        x := template('in.tmpl')
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout("1.0")
+    .success();
+}
+
+#[test]
+fn template_not_found() {
+  Test::new()
+    .justfile("x := template('in.tmpl')")
+    .args(["--evaluate", "x"])
+    .stderr_regex(r"error: Call to function `template` failed: I/O error reading `in.tmpl`: .*")
+    .failure();
+}
+
+#[test]
+fn undefined_variable() {
+  Test::new()
+    .write("in.tmpl", "a\nb {{ nme }}\n")
+    .justfile(
+      "
+        name := 'foo'
+
+        x := template('in.tmpl')
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: Call to function `template` failed: Variable `nme` not defined
+         ——▶ in.tmpl:2:6
+          │
+        2 │ b {{ nme }}
+          │      ^^^
+         ——▶ justfile:3:6
+          │
+        3 │ x := template('in.tmpl')
+          │      ^^^^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn undefined_function() {
+  Test::new()
+    .justfile(
+      "
+        x := render('{{ foo() }}')
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: Call to function `render` failed: Call to unknown function `foo`
+         ——▶ render:1:4
+          │
+        1 │ {{ foo() }}
+          │    ^^^
+         ——▶ justfile:1:6
+          │
+        1 │ x := render('{{ foo() }}')
+          │      ^^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn user_function() {
+  Test::new()
+    .justfile(
+      "
+        fn greet(name) := 'hello ' + name
+
+        x := render('{{ greet(\"world\") }}')
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout("hello world")
+    .success();
+}
+
+#[test]
+fn unterminated_interpolation() {
+  Test::new()
+    .justfile(
+      "
+        x := render('{{ a')
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: Call to function `render` failed: Unterminated interpolation
+         ——▶ render:1:1
+          │
+        1 │ {{ a
+          │ ^^
+         ——▶ justfile:1:6
+          │
+        1 │ x := render('{{ a')
+          │      ^^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn render_attribute() {
+  Test::new()
+    .write("in.conf.tmpl", "name = {{ name }}\nport = {{ port }}\n")
+    .justfile(
+      "
+        name := 'web'
+
+        [render('out.conf', 'in.conf.tmpl')]
+        foo port:
+          @cat out.conf
+      ",
+    )
+    .args(["foo", "8080"])
+    .stdout("name = web\nport = 8080\n")
+    .success();
+}
+
+#[test]
+fn render_attribute_is_relative_to_working_directory() {
+  Test::new()
+    .write("bar/in.tmpl", "{{ 'a' }}")
+    .justfile(
+      "
+        [working-directory('bar')]
+        [render('out', 'in.tmpl')]
+        foo:
+          @cat out
+      ",
+    )
+    .stdout("a")
+    .success();
+}
+
+#[test]
+fn render_attribute_with_no_cd_is_relative_to_justfile_directory() {
+  Test::new()
+    .write("in.tmpl", "{{ 'a' }}")
+    .write("bar/baz", "")
+    .justfile(
+      "
+        [no-cd]
+        [render('out', 'in.tmpl')]
+        foo:
+          @cat ../out
+      ",
+    )
+    .current_dir("bar")
+    .stdout("a")
+    .success();
+}
+
+#[test]
+fn render_attribute_is_repeatable() {
+  Test::new()
+    .write("a.tmpl", "a")
+    .write("b.tmpl", "b")
+    .justfile(
+      "
+        [render('a', 'a.tmpl')]
+        [render('b', 'b.tmpl')]
+        foo:
+          @cat a b
+      ",
+    )
+    .stdout("ab")
+    .success();
+}
+
+#[test]
+fn render_attribute_dry_run() {
+  Test::new()
+    .write("in.tmpl", "a")
+    .justfile(
+      "
+        [render('out', 'in.tmpl')]
+        foo:
+      ",
+    )
+    .arg("--dry-run")
+    .stderr("[render('out', 'in.tmpl')]\n")
+    .success();
+}
+
+#[test]
+fn render_attribute_template_not_found() {
+  Test::new()
+    .justfile(
+      "
+        [render('out', 'in.tmpl')]
+        foo:
+      ",
+    )
+    .stderr_regex("error: Recipe `foo` could not render template, I/O error at `.*in.tmpl`: .*")
+    .failure();
+}
+
+#[test]
+fn render_attribute_undefined_variable() {
+  Test::new()
+    .write("in.tmpl", "{{ a }}")
+    .justfile(
+      "
+        [render('out', 'in.tmpl')]
+        foo:
+      ",
+    )
+    .stderr(
+      "
+        error: Variable `a` not defined
+         ——▶ in.tmpl:1:4
+          │
+        1 │ {{ a }}
+          │    ^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn render_attribute_argument_count() {
+  Test::new()
+    .justfile(
+      "
+        [render('out')]
+        foo:
+      ",
+    )
+    .stderr(
+      "
+        error: Attribute `render` got 1 argument but takes 2 arguments
+         ——▶ justfile:1:2
+          │
+        1 │ [render('out')]
+          │  ^^^^^^
+      ",
+    )
+    .failure();
+}
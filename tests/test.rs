@@ -253,6 +253,9 @@ impl Test {
       command.args(["--output-format", format]);
     }
 
+    // Keep records of runs out of the user's cache directory
+    command.env("JUST_CACHE_DIRECTORY", self.tempdir.path().join(".cache"));
+
     let mut child = command
       .args(&self.args)
       .envs(&self.env)
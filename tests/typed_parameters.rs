@@ -0,0 +1,297 @@
+use super::*;
+
+const JUSTFILE: &str = "
+  build mode: enum('debug', 'release') count: int='3' verbose: bool='false':
+    @echo {{ mode }} {{ count }} {{ verbose }}
+";
+
+#[test]
+fn valid_arguments() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["build", "release", "12", "true"])
+    .stdout("release 12 true\n")
+    .success();
+}
+
+#[test]
+fn defaults() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["build", "debug"])
+    .stdout("debug 3 false\n")
+    .success();
+}
+
+#[test]
+fn enum_mismatch() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["build", "fast"])
+    .stderr(
+      "error: Argument `fast` passed to recipe `build` parameter `mode` is not one of `debug` or \
+       `release`\n",
+    )
+    .failure();
+}
+
+#[test]
+fn int_mismatch() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["build", "debug", "three"])
+    .stderr(
+      "error: Argument `three` passed to recipe `build` parameter `count` is not an integer\n",
+    )
+    .failure();
+}
+
+#[test]
+fn bool_mismatch() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["build", "debug", "1", "yes"])
+    .stderr(
+      "error: Argument `yes` passed to recipe `build` parameter `verbose` is not `true` or \
+       `false`\n",
+    )
+    .failure();
+}
+
+#[test]
+fn default_is_checked() {
+  Test::new()
+    .justfile(
+      "
+        foo count: int='many':
+      ",
+    )
+    .stderr("error: Argument `many` passed to recipe `foo` parameter `count` is not an integer\n")
+    .failure();
+}
+
+#[test]
+fn file() {
+  Test::new()
+    .justfile(
+      "
+        foo path: file:
+          @cat {{ path }}
+      ",
+    )
+    .write("bar.txt", "baz")
+    .args(["foo", "bar.txt"])
+    .stdout("baz")
+    .success();
+}
+
+#[test]
+fn file_is_relative_to_invocation_directory() {
+  Test::new()
+    .justfile(
+      "
+        foo path: file:
+          @echo {{ path }}
+      ",
+    )
+    .write("sub/bar.txt", "")
+    .current_dir("sub")
+    .args(["foo", "bar.txt"])
+    .stdout("bar.txt\n")
+    .success();
+}
+
+#[test]
+fn file_mismatch() {
+  Test::new()
+    .justfile(
+      "
+        foo path: file:
+      ",
+    )
+    .create_dir("bar")
+    .args(["foo", "bar"])
+    .stderr(
+      "error: Argument `bar` passed to recipe `foo` parameter `path` is not an existing file\n",
+    )
+    .failure();
+}
+
+#[test]
+fn variadic_arguments_are_checked() {
+  Test::new()
+    .justfile(
+      "
+        sum +values: int:
+          @echo {{ values }}
+      ",
+    )
+    .args(["sum", "1", "2", "x"])
+    .stderr("error: Argument `x` passed to recipe `sum` parameter `values` is not an integer\n")
+    .failure();
+}
+
+#[test]
+fn dependency_arguments_are_checked() {
+  Test::new()
+    .justfile(
+      "
+        foo: (bar 'x')
+
+        bar n: int:
+      ",
+    )
+    .stderr("error: Argument `x` passed to recipe `bar` parameter `n` is not an integer\n")
+    .failure();
+}
+
+#[test]
+fn exported_parameter() {
+  Test::new()
+    .justfile(
+      "
+        foo $n: int:
+          @echo $n
+      ",
+    )
+    .args(["foo", "7"])
+    .stdout("7\n")
+    .success();
+}
+
+#[test]
+fn untyped_parameter_with_dependency() {
+  Test::new()
+    .justfile(
+      "
+        foo a: int
+          @echo {{ a }}
+
+        int:
+          @echo int
+      ",
+    )
+    .args(["foo", "x"])
+    .stdout("int\nx\n")
+    .success();
+}
+
+#[test]
+fn unknown_type() {
+  Test::new()
+    .justfile(
+      "
+        foo a: float:
+      ",
+    )
+    .stderr(
+      "
+        error: Unknown parameter type `float`, expected `bool`, `enum`, `file`, or `int`
+         ——▶ justfile:1:8
+          │
+        1 │ foo a: float:
+          │        ^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn usage() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--usage", "build"])
+    .stdout(
+      "
+        Usage: just build mode [count] [verbose]
+
+        Arguments:
+          mode [type: enum('debug', 'release')]
+          [count] [type: int] [default: '3']
+          [verbose] [type: bool] [default: 'false']
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn list() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("--list")
+    .stdout(
+      "
+        Available recipes:
+            build mode: enum('debug', 'release') count: int='3' verbose: bool='false'
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("--dump")
+    .stdout(
+      "
+        build mode: enum('debug', 'release') count: int='3' verbose: bool='false':
+            @echo {{ mode }} {{ count }} {{ verbose }}
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn complete_enum() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--complete-parameter", "build"])
+    .stdout("enum\ndebug\nrelease\n")
+    .success();
+}
+
+#[test]
+fn complete_bool() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--complete-parameter", "build", "debug", "3"])
+    .stdout("bool\nfalse\ntrue\n")
+    .success();
+}
+
+#[test]
+fn complete_file() {
+  Test::new()
+    .justfile(
+      "
+        foo a path: file:
+      ",
+    )
+    .args(["--complete-parameter", "foo", "x"])
+    .stdout("file\n")
+    .success();
+}
+
+#[test]
+fn complete_untyped() {
+  Test::new()
+    .justfile(
+      "
+        foo a:
+      ",
+    )
+    .args(["--complete-parameter", "foo"])
+    .stdout("")
+    .success();
+}
+
+#[test]
+fn complete_module_recipe() {
+  Test::new()
+    .justfile("mod bar")
+    .write("bar.just", "baz +values: enum('a', 'b'):")
+    .args(["--complete-parameter", "bar::baz", "a", "b"])
+    .stdout("enum\na\nb\n")
+    .success();
+}
@@ -0,0 +1,431 @@
+use super::*;
+
+#[test]
+fn call() {
+  Test::new()
+    .justfile(
+      "
+        fn slug(s) := lowercase(replace_regex(s, '[^a-zA-Z0-9]+', '-'))
+
+        foo:
+          @echo {{ slug('Hello, World') }}
+      ",
+    )
+    .stdout("hello-world\n")
+    .success();
+}
+
+#[test]
+fn multiple_parameters() {
+  Test::new()
+    .justfile(
+      "
+        fn wrap(s, left, right) := left + s + right
+
+        x := wrap('a', '<', '>')
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout("<a>")
+    .success();
+}
+
+#[test]
+fn no_parameters() {
+  Test::new()
+    .justfile(
+      "
+        fn greeting() := 'hello'
+
+        x := greeting() + '!'
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout("hello!")
+    .success();
+}
+
+#[test]
+fn call_before_definition() {
+  Test::new()
+    .justfile(
+      "
+        x := double('a')
+
+        fn double(s) := s + s
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout("aa")
+    .success();
+}
+
+#[test]
+fn functions_call_functions() {
+  Test::new()
+    .justfile(
+      "
+        fn slug(s) := lowercase(replace(s, ' ', '-'))
+        fn path(s) := 'docs' / slug(s) + '.md'
+
+        x := path('Getting Started')
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout("docs/getting-started.md")
+    .success();
+}
+
+#[test]
+fn arguments_are_expressions() {
+  Test::new()
+    .justfile(
+      "
+        fn upper(s) := uppercase(s)
+
+        name := 'foo'
+
+        foo bar:
+          @echo {{ upper(name + '-' + bar) }}
+      ",
+    )
+    .args(["foo", "baz"])
+    .stdout("FOO-BAZ\n")
+    .success();
+}
+
+#[test]
+fn parameter_shadows_variable() {
+  Test::new()
+    .justfile(
+      "
+        fn f(x) := x
+
+        x := 'outer'
+        y := f('inner') + ' ' + x
+      ",
+    )
+    .args(["--evaluate", "y"])
+    .stdout("inner outer")
+    .success();
+}
+
+#[test]
+fn body_cannot_access_variables() {
+  Test::new()
+    .justfile(
+      "
+        x := 'a'
+
+        fn f(y) := x + y
+      ",
+    )
+    .stderr(
+      "
+        error: Variable `x` not defined
+         ——▶ justfile:3:12
+          │
+        3 │ fn f(y) := x + y
+          │            ^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn body_can_access_constants() {
+  Test::new()
+    .justfile(
+      "
+        fn letters() := replace(HEX, '0123456789', '')
+
+        x := letters()
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout("abcdef")
+    .success();
+}
+
+#[test]
+fn module_function() {
+  Test::new()
+    .justfile(
+      "
+        mod text
+
+        foo:
+          @echo {{ text::slug('Hello World') }}
+      ",
+    )
+    .write(
+      "text.just",
+      "fn slug(s) := lowercase(dash(s))\nfn dash(s) := replace(s, ' ', '-')",
+    )
+    .stdout("hello-world\n")
+    .success();
+}
+
+#[test]
+fn nested_module_function() {
+  Test::new()
+    .justfile(
+      "
+        mod a
+
+        x := a::b::f('c')
+      ",
+    )
+    .write("a.just", "mod b")
+    .write("b.just", "fn f(s) := s + s")
+    .args(["--evaluate", "x"])
+    .stdout("cc")
+    .success();
+}
+
+#[test]
+fn unknown_function() {
+  Test::new()
+    .justfile(
+      "
+        x := slug('a')
+      ",
+    )
+    .stderr(
+      "
+        error: Call to unknown function `slug`
+         ——▶ justfile:1:6
+          │
+        1 │ x := slug('a')
+          │      ^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn unknown_module_function() {
+  Test::new()
+    .justfile(
+      "
+        mod text
+
+        x := text::slug('a')
+      ",
+    )
+    .write("text.just", "")
+    .stderr(
+      "
+        error: Call to unknown function `text::slug`
+         ——▶ justfile:3:12
+          │
+        3 │ x := text::slug('a')
+          │            ^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn argument_count_mismatch() {
+  Test::new()
+    .justfile(
+      "
+        fn f(a, b) := a + b
+
+        x := f('a')
+      ",
+    )
+    .stderr(
+      "
+        error: Function `f` called with 1 argument but takes 2
+         ——▶ justfile:3:6
+          │
+        3 │ x := f('a')
+          │      ^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn recursion() {
+  Test::new()
+    .justfile(
+      "
+        fn f(a) := g(a)
+        fn g(a) := if a == '' { '' } else { f(a) }
+      ",
+    )
+    .stderr(
+      "
+        error: Function `f` has circular call `f -> g -> f`
+         ——▶ justfile:1:4
+          │
+        1 │ fn f(a) := g(a)
+          │    ^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn shadows_builtin() {
+  Test::new()
+    .justfile(
+      "
+        fn uppercase(s) := s
+      ",
+    )
+    .stderr(
+      "
+        error: Function `uppercase` shadows built-in function
+         ——▶ justfile:1:4
+          │
+        1 │ fn uppercase(s) := s
+          │    ^^^^^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn duplicate_parameter() {
+  Test::new()
+    .justfile(
+      "
+        fn f(a, a) := a
+      ",
+    )
+    .stderr(
+      "
+        error: Function `f` has duplicate parameter `a`
+         ——▶ justfile:1:9
+          │
+        1 │ fn f(a, a) := a
+          │         ^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn body_cannot_contain_backticks() {
+  Test::new()
+    .justfile(
+      "
+        fn f(s) := s + `date`
+      ",
+    )
+    .stderr(
+      "
+        error: Function `f` may not run commands with backticks or `shell()`
+         ——▶ justfile:1:16
+          │
+        1 │ fn f(s) := s + `date`
+          │                ^^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn body_cannot_call_shell() {
+  Test::new()
+    .justfile(
+      "
+        fn f(s) := if s == '' { '' } else { shell('echo $1', s) }
+      ",
+    )
+    .stderr(
+      "
+        error: Function `f` may not run commands with backticks or `shell()`
+         ——▶ justfile:1:37
+          │
+        1 │ fn f(s) := if s == '' { '' } else { shell('echo $1', s) }
+          │                                     ^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn error_in_body() {
+  Test::new()
+    .justfile(
+      "
+        fn check(s) := if s == '' { error('empty') } else { s }
+
+        x := check('')
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+        error: Call to function `error` failed: empty
+         ——▶ justfile:1:29
+          │
+        1 │ fn check(s) := if s == '' { error('empty') } else { s }
+          │                             ^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn fn_is_still_a_valid_name() {
+  Test::new()
+    .justfile(
+      "
+        fn := 'a'
+
+        fn arg:
+          @echo {{ fn }} {{ arg }}
+      ",
+    )
+    .args(["fn", "b"])
+    .stdout("a b\n")
+    .success();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(
+      "
+        mod text
+
+        fn slug(s, sep) := lowercase(replace(s, ' ', sep))
+
+        x := slug('A B', '-') + text::f()
+      ",
+    )
+    .write("text.just", "fn f() := 'c'")
+    .arg("--dump")
+    .stdout(
+      "
+        mod text
+
+        fn slug(s, sep) := lowercase(replace(s, ' ', sep))
+
+        x := slug('A B', '-') + text::f()
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn dump_json_call() {
+  Test::new()
+    .justfile(
+      "
+        mod text
+
+        x := text::f('a')
+      ",
+    )
+    .write("text.just", "fn f(s) := s")
+    .args(["--dump", "--dump-format", "json"])
+    .stdout_regex(r#".*"value":\["call","text::f","a"\].*"#)
+    .success();
+}
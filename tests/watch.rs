@@ -0,0 +1,248 @@
+use {super::*, nix::sys::signal::Signal, nix::unistd::Pid, std::process::Child};
+
+struct Watch {
+  child: Child,
+  tmp: TempDir,
+}
+
+impl Watch {
+  fn new(justfile: &str, arguments: &[&str]) -> Self {
+    Self::spawn(justfile, "default", arguments)
+  }
+
+  fn read(&self, path: &str) -> String {
+    fs::read_to_string(self.tmp.path().join(path)).unwrap_or_default()
+  }
+
+  fn spawn(justfile: &str, output_format: &str, arguments: &[&str]) -> Self {
+    let tmp = tempdir();
+
+    fs::write(tmp.path().join("justfile"), unindent(justfile)).unwrap();
+    fs::create_dir(tmp.path().join("src")).unwrap();
+    fs::write(tmp.path().join("src/input"), "").unwrap();
+
+    let stdout = fs::File::create(tmp.path().join("stdout")).unwrap();
+
+    let child = Command::new(JUST)
+      .current_dir(&tmp)
+      .env("LC_ALL", "C")
+      .args(["--output-format", output_format, "--watch"])
+      .args(arguments)
+      .stdout(stdout)
+      .stderr(Stdio::null())
+      .spawn()
+      .expect("just invocation failed");
+
+    Self { child, tmp }
+  }
+
+  fn tap(justfile: &str, arguments: &[&str]) -> Self {
+    Self::spawn(justfile, "tap", arguments)
+  }
+
+  /// Wait for the log to contain `expected`. Watched files are snapshotted
+  /// before each run starts, so once a recipe has written to the log, changes
+  /// made by the test will be seen.
+  #[track_caller]
+  fn wait_for(&self, expected: &str) {
+    self.wait_for_file("log", expected);
+  }
+
+  #[track_caller]
+  fn wait_for_file(&self, path: &str, expected: &str) {
+    let start = Instant::now();
+
+    while self.read(path) != expected {
+      assert!(
+        start.elapsed() < Duration::from_secs(10),
+        "timed out waiting for {path} `{expected}`, got `{}`",
+        self.read(path),
+      );
+      thread::sleep(Duration::from_millis(50));
+    }
+  }
+
+  fn write(&self, path: &str, content: &str) {
+    fs::write(self.tmp.path().join(path), content).unwrap();
+  }
+}
+
+impl Drop for Watch {
+  fn drop(&mut self) {
+    nix::sys::signal::kill(
+      Pid::from_raw(self.child.id().try_into().unwrap()),
+      Signal::SIGINT,
+    )
+    .unwrap();
+    self.child.wait().unwrap();
+  }
+}
+
+#[test]
+fn reruns_when_watched_file_changes() {
+  let watch = Watch::new(
+    "
+      [watch('src/**')]
+      foo:
+        echo run >> log
+    ",
+    &["foo"],
+  );
+
+  watch.wait_for("run\n");
+
+  watch.write("src/input", "changed");
+
+  watch.wait_for("run\nrun\n");
+}
+
+#[test]
+fn ignores_unwatched_files() {
+  let watch = Watch::new(
+    "
+      [watch('src/*.rs')]
+      foo:
+        echo run $(ls src) >> log
+    ",
+    &["foo"],
+  );
+
+  watch.wait_for("run input\n");
+
+  watch.write("src/input", "changed");
+  watch.write("other.rs", "changed");
+  watch.write("src/main.rs", "");
+
+  // a run triggered by the unwatched files would log `run input`
+  watch.wait_for("run input\nrun input main.rs\n");
+}
+
+#[test]
+fn watches_dependency_patterns() {
+  let watch = Watch::new(
+    "
+      [watch('src/**')]
+      bar:
+        echo bar >> log
+
+      foo: bar
+        echo foo >> log
+    ",
+    &["foo"],
+  );
+
+  watch.wait_for("bar\nfoo\n");
+
+  watch.write("src/input", "changed");
+
+  watch.wait_for("bar\nfoo\nbar\nfoo\n");
+}
+
+#[test]
+fn reloads_justfile() {
+  let watch = Watch::new(
+    "
+      foo:
+        echo old >> log
+    ",
+    &["foo"],
+  );
+
+  watch.wait_for("old\n");
+
+  watch.write("justfile", "foo:\n  echo new >> log\n");
+
+  watch.wait_for("old\nnew\n");
+}
+
+#[test]
+fn restarts_running_recipe() {
+  let watch = Watch::new(
+    "
+      [watch('src/**')]
+      foo:
+        echo start >> log
+        exec sleep 10
+    ",
+    &["foo"],
+  );
+
+  watch.wait_for("start\n");
+
+  watch.write("src/input", "changed");
+
+  watch.wait_for("start\nstart\n");
+}
+
+#[test]
+fn restart_stops_services_after_recipes() {
+  let watch = Watch::new(
+    "
+      [service(file='ready')]
+      server:
+        trap 'echo stopped >> log; exit' TERM; touch ready; while true; do sleep 0.1; done
+
+      [watch('src/**')]
+      foo: server
+        echo start >> log
+        exec sleep 10
+    ",
+    &["foo"],
+  );
+
+  watch.wait_for("start\n");
+
+  watch.write("src/input", "changed");
+
+  watch.wait_for("start\nstopped\nstart\n");
+}
+
+#[test]
+fn writes_tap_document_per_run() {
+  let watch = Watch::tap(
+    "
+      [watch('src/**')]
+      foo:
+        echo run >> log
+    ",
+    &["foo"],
+  );
+
+  watch.wait_for_file("stdout", "TAP version 14\n1..1\nok 1 - foo\n");
+
+  watch.write("src/input", "changed");
+
+  watch.wait_for_file(
+    "stdout",
+    "TAP version 14\n1..1\nok 1 - foo\nTAP version 14\n1..1\nok 1 - foo\n",
+  );
+}
+
+#[test]
+fn watch_requires_argument() {
+  Test::new()
+    .justfile(
+      "
+        [watch]
+        foo:
+      ",
+    )
+    .stderr(
+      "
+        error: Attribute `watch` got 0 arguments but takes at least 1 argument
+         ——▶ justfile:1:2
+          │
+        1 │ [watch]
+          │  ^^^^^
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn watch_conflicts_with_subcommands() {
+  Test::new()
+    .args(["--watch", "--list"])
+    .stderr_regex("error: the argument '--watch' cannot be used with '--list.*")
+    .status(2);
+}
@@ -0,0 +1,333 @@
+use super::*;
+
+#[test]
+fn runs_recipe_in_each_justfile_that_defines_it() {
+  Test::new()
+    .create_dir(".git")
+    .justfile(
+      "
+      test:
+        @echo root
+      ",
+    )
+    .write("foo/justfile", "test:\n  @echo foo\n")
+    .write("foo/bar/justfile", "build:\n  @echo bar\n")
+    .write("baz/.justfile", "test:\n  @echo baz\n")
+    .env("LC_ALL", "C")
+    .args(["--workspace", "test"])
+    .stdout(
+      "
+      TAP version 14
+      1..3
+          # Subtest: .
+          TAP version 14
+          1..1
+          ok 1 - test
+            ---
+            output: \"root\"
+            ...
+      ok 1 - .
+          # Subtest: baz
+          TAP version 14
+          1..1
+          ok 1 - test
+            ---
+            output: \"baz\"
+            ...
+      ok 2 - baz
+          # Subtest: foo
+          TAP version 14
+          1..1
+          ok 1 - test
+            ---
+            output: \"foo\"
+            ...
+      ok 3 - foo
+      # 3 of 3 packages passed
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn failing_package() {
+  Test::new()
+    .create_dir(".git")
+    .justfile(
+      "
+      test:
+        @echo root
+      ",
+    )
+    .write("foo/justfile", "test:\n  @exit 3\n")
+    .env("LC_ALL", "C")
+    .args(["--workspace", "test"])
+    .stdout(
+      "
+      TAP version 14
+      1..2
+          # Subtest: .
+          TAP version 14
+          1..1
+          ok 1 - test
+            ---
+            output: \"root\"
+            ...
+      ok 1 - .
+          # Subtest: foo
+          TAP version 14
+          1..1
+          not ok 1 - test
+            ---
+            message: \"error: Recipe `test` failed on line 2 with exit code 3\"
+            severity: fail
+            exitcode: 3
+            ...
+      not ok 2 - foo
+        ---
+        message: \"Recipe `test` failed\"
+        severity: fail
+        exitcode: 1
+        ...
+      # 1 of 2 packages passed
+      ",
+    )
+    .failure();
+}
+
+#[test]
+fn package_with_compile_error() {
+  Test::new()
+    .create_dir(".git")
+    .justfile(
+      "
+      test:
+        @echo root
+      ",
+    )
+    .write("foo/justfile", "test: bar\n")
+    .env("LC_ALL", "C")
+    .args(["--workspace", "test"])
+    .stdout_regex(
+      "TAP version 14
+1..2
+    # Subtest: .
+    TAP version 14
+    1..1
+    ok 1 - test
+      ---
+      output: \"root\"
+      ...
+ok 1 - .
+not ok 2 - foo
+  ---
+  message: \"error: Recipe `test` has unknown dependency `bar`.*\"
+  severity: fail
+  ...
+# 1 of 2 packages passed
+",
+    )
+    .failure();
+}
+
+#[test]
+fn gitignored_directories_are_skipped() {
+  Test::new()
+    .create_dir(".git")
+    .write(".gitignore", "vendor\n")
+    .justfile(
+      "
+      test:
+        @echo root
+      ",
+    )
+    .write("vendor/foo/justfile", "test:\n  @echo foo\n")
+    .env("LC_ALL", "C")
+    .args(["--workspace", "test"])
+    .stdout(
+      "
+      TAP version 14
+      1..1
+          # Subtest: .
+          TAP version 14
+          1..1
+          ok 1 - test
+            ---
+            output: \"root\"
+            ...
+      ok 1 - .
+      # 1 of 1 package passed
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn filter() {
+  Test::new()
+    .create_dir(".git")
+    .justfile(
+      "
+      test:
+        @echo root
+      ",
+    )
+    .write("packages/foo/justfile", "test:\n  @echo foo\n")
+    .write("packages/bar/justfile", "test:\n  @echo bar\n")
+    .write("tools/justfile", "test:\n  @echo tools\n")
+    .env("LC_ALL", "C")
+    .args([
+      "--workspace",
+      "--workspace-filter",
+      "packages/*",
+      "--workspace-filter",
+      "tools/",
+      "test",
+    ])
+    .stdout(
+      "
+      TAP version 14
+      1..3
+          # Subtest: packages/bar
+          TAP version 14
+          1..1
+          ok 1 - test
+            ---
+            output: \"bar\"
+            ...
+      ok 1 - packages/bar
+          # Subtest: packages/foo
+          TAP version 14
+          1..1
+          ok 1 - test
+            ---
+            output: \"foo\"
+            ...
+      ok 2 - packages/foo
+          # Subtest: tools
+          TAP version 14
+          1..1
+          ok 1 - test
+            ---
+            output: \"tools\"
+            ...
+      ok 3 - tools
+      # 3 of 3 packages passed
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn parallel_jobs_are_reported_in_order() {
+  Test::new()
+    .create_dir(".git")
+    .write("a/justfile", "test:\n  @sleep 1\n  @echo a\n")
+    .write("b/justfile", "test:\n  @echo b\n")
+    .env("LC_ALL", "C")
+    .args(["--workspace", "--workspace-jobs", "2", "test"])
+    .stdout(
+      "
+      TAP version 14
+      1..2
+          # Subtest: a
+          TAP version 14
+          1..1
+          ok 1 - test
+            ---
+            output: \"a\"
+            ...
+      ok 1 - a
+          # Subtest: b
+          TAP version 14
+          1..1
+          ok 1 - test
+            ---
+            output: \"b\"
+            ...
+      ok 2 - b
+      # 2 of 2 packages passed
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn arguments_are_passed_to_recipe() {
+  Test::new()
+    .create_dir(".git")
+    .write("foo/justfile", "test target:\n  @echo {{target}}\n")
+    .env("LC_ALL", "C")
+    .args(["--workspace", "test", "release"])
+    .stdout(
+      "
+      TAP version 14
+      1..1
+          # Subtest: foo
+          TAP version 14
+          1..1
+          ok 1 - test
+            ---
+            output: \"release\"
+            ...
+      ok 1 - foo
+      # 1 of 1 package passed
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn runs_from_subdirectory() {
+  Test::new()
+    .create_dir(".git")
+    .write("foo/justfile", "test:\n  @echo foo\n")
+    .write("bar/justfile", "test:\n  @echo bar\n")
+    .current_dir("foo")
+    .env("LC_ALL", "C")
+    .args(["--workspace", "--workspace-filter", "bar", "test"])
+    .stdout(
+      "
+      TAP version 14
+      1..1
+          # Subtest: bar
+          TAP version 14
+          1..1
+          ok 1 - test
+            ---
+            output: \"bar\"
+            ...
+      ok 1 - bar
+      # 1 of 1 package passed
+      ",
+    )
+    .success();
+}
+
+#[test]
+fn unknown_recipe() {
+  Test::new()
+    .create_dir(".git")
+    .write("foo/justfile", "test:\n  @echo foo\n")
+    .args(["--workspace", "build"])
+    .stderr_regex("error: No justfile beneath `.*` contains recipe `build`\n")
+    .failure();
+}
+
+#[test]
+fn invalid_recipe_path() {
+  Test::new()
+    .create_dir(".git")
+    .write("foo/justfile", "test:\n  @echo foo\n")
+    .args(["--workspace", ":foo"])
+    .stderr("error: Invalid module path `:foo`\n")
+    .failure();
+}
+
+#[test]
+fn recipe_is_required() {
+  Test::new()
+    .arg("--workspace")
+    .stderr_regex("error: the following required arguments were not provided:.*")
+    .status(2);
+}